@@ -0,0 +1,250 @@
+//! `readable-regex` command line tool: explain, convert, test and search with regexes, or build
+//! them interactively with `readable-regex repl`.
+//!
+//! Available with feature `"cli"`
+
+mod output;
+mod repl;
+
+use clap::{Args, Parser, Subcommand};
+use output::Style;
+use readable_regex::{parse, presets, Dialect, ReadableRe};
+use std::io::{BufRead, BufReader, ErrorKind, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+#[derive(Parser)]
+#[command(name = "readable-regex", version, about = "Regex for human beings")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Describe the structure of a regex
+    Explain(Pattern),
+    /// Print the readable-regex builder code of a regex
+    Convert(Pattern),
+    /// Show the matches and captures of a regex in each input
+    Test(PatternAndInputs),
+    /// Print the lines of the files (or stdin) matching a regex
+    Grep(PatternAndInputs),
+    /// Render a regex for another regex engine
+    Render {
+        /// rust, pcre, js, python, postgres or go
+        #[arg(short, long, value_parser = parse_dialect)]
+        dialect: Dialect,
+        #[command(flatten)]
+        pattern: Pattern,
+    },
+    /// List the available presets
+    Presets,
+    /// Build expressions interactively and try them on sample text
+    Repl {
+        /// File whose lines are used as sample
+        #[arg(short, long)]
+        sample: Option<PathBuf>,
+    },
+}
+
+#[derive(Args)]
+struct Pattern {
+    /// The regex
+    #[arg(required_unless_present = "preset", conflicts_with = "preset")]
+    regex: Option<String>,
+    /// Use a preset instead of a regex, e.g. `network::EMAIL`
+    #[arg(short, long)]
+    preset: Option<String>,
+}
+
+#[derive(Args)]
+struct PatternAndInputs {
+    /// Use a preset instead of a regex, e.g. `network::EMAIL`
+    #[arg(short, long)]
+    preset: Option<String>,
+    /// The regex, unless `--preset` is used, followed by the inputs
+    #[arg(value_name = "REGEX] [INPUTS")]
+    args: Vec<String>,
+}
+
+fn parse_dialect(s: &str) -> Result<Dialect, String> {
+    match s.to_lowercase().as_str() {
+        "rust" | "regex" => Ok(Dialect::Rust),
+        "pcre" | "perl" | "fancy" => Ok(Dialect::Pcre),
+        "js" | "javascript" | "ecmascript" => Ok(Dialect::JavaScript),
+        "python" | "py" => Ok(Dialect::Python),
+        "postgres" | "postgresql" | "pg" => Ok(Dialect::PostgreSql),
+        "go" | "re2" => Ok(Dialect::Go),
+        _ => Err(format!("unknown dialect `{s}`")),
+    }
+}
+
+/// Resolve the expression from either a regex string or a preset name
+fn expression<'a>(regex: Option<&'a str>, preset: Option<&str>) -> Result<ReadableRe<'a>, String> {
+    match (regex, preset) {
+        (_, Some(name)) => presets::by_name(name).ok_or_else(|| {
+            format!("unknown preset `{name}`, check `readable-regex presets` for the list")
+        }),
+        (Some(regex), None) => parse::parse(regex).map_err(|e| e.to_string()),
+        (None, None) => Err("a regex or a preset is required".to_string()),
+    }
+}
+
+impl PatternAndInputs {
+    /// Split the arguments into the expression and the inputs
+    fn split(&self) -> Result<(ReadableRe<'_>, &[String]), String> {
+        match (&self.preset, self.args.split_first()) {
+            (Some(_), _) => Ok((expression(None, self.preset.as_deref())?, &self.args)),
+            (None, Some((regex, inputs))) => Ok((expression(Some(regex), None)?, inputs)),
+            (None, None) => Err("a regex or a preset is required".to_string()),
+        }
+    }
+}
+
+/// Why a command stopped before its end
+enum Error {
+    /// Reported on stderr
+    Message(String),
+    /// Already reported on stderr, as the unreadable inputs of `grep`
+    Reported,
+    /// The reader of the output went away, as `head` does once it has enough lines
+    BrokenPipe,
+}
+
+impl From<String> for Error {
+    fn from(message: String) -> Self {
+        Error::Message(message)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        match e.kind() {
+            ErrorKind::BrokenPipe => Error::BrokenPipe,
+            _ => Error::Message(e.to_string()),
+        }
+    }
+}
+
+fn run(cli: Cli) -> Result<bool, Error> {
+    let mut out = std::io::stdout().lock();
+    match cli.command {
+        Command::Explain(pattern) => {
+            let re = expression(pattern.regex.as_deref(), pattern.preset.as_deref())?;
+            writeln!(out, "{re}\n")?;
+            write!(out, "{}", re.explain())?;
+        }
+        Command::Convert(pattern) => {
+            let re = expression(pattern.regex.as_deref(), pattern.preset.as_deref())?;
+            writeln!(out, "{}", re.to_builder_code())?;
+        }
+        Command::Render { dialect, pattern } => {
+            let re = expression(pattern.regex.as_deref(), pattern.preset.as_deref())?;
+            writeln!(out, "{}", re.render(dialect).map_err(|e| e.to_string())?)?;
+        }
+        Command::Presets => {
+            for (name, re) in presets::all() {
+                writeln!(out, "{name:<32} {re}")?;
+            }
+        }
+        Command::Repl { sample } => {
+            // the REPL prints its prompts itself
+            drop(out);
+            return Ok(repl::run(sample.as_deref())?);
+        }
+        Command::Test(args) => {
+            let (re, inputs) = args.split()?;
+            let regex = re.compile().map_err(|e| e.to_string())?;
+            let style = Style::detect();
+            let mut matched = false;
+            for input in inputs {
+                writeln!(out, "{}", output::highlight(input, &regex, style))?;
+                for (i, captures) in regex.captures_iter(input).enumerate() {
+                    matched = true;
+                    let m = captures.get(0).unwrap();
+                    writeln!(
+                        out,
+                        "  match {i}: {:?} at {}..{}",
+                        m.as_str(),
+                        m.start(),
+                        m.end()
+                    )?;
+                    for line in output::captures(&regex, &captures) {
+                        writeln!(out, "    {line}")?;
+                    }
+                }
+            }
+            return Ok(matched);
+        }
+        Command::Grep(args) => {
+            let (re, files) = args.split()?;
+            let regex = re.compile().map_err(|e| e.to_string())?;
+            let style = Style::detect();
+            let mut matched = false;
+            let mut failed = false;
+            let prefix_files = files.len() > 1;
+            let sources: Vec<Option<&String>> = match files.is_empty() {
+                true => vec![None],
+                false => files.iter().map(Some).collect(),
+            };
+            // files are opened one at a time, an unreadable one is reported and skipped
+            for file in sources {
+                let mut reader: Box<dyn BufRead> = match file {
+                    None => Box::new(std::io::stdin().lock()),
+                    Some(file) => match std::fs::File::open(PathBuf::from(file)) {
+                        Ok(reader) => Box::new(BufReader::new(reader)),
+                        Err(e) => {
+                            eprintln!("error: {file}: {e}");
+                            failed = true;
+                            continue;
+                        }
+                    },
+                };
+                let mut buffer = Vec::new();
+                for number in 1.. {
+                    buffer.clear();
+                    match reader.read_until(b'\n', &mut buffer) {
+                        Ok(0) => break,
+                        Ok(_) => {}
+                        Err(e) => {
+                            eprintln!("error: {}: {e}", file.map_or("stdin", |f| f.as_str()));
+                            failed = true;
+                            break;
+                        }
+                    }
+                    let bytes = buffer.strip_suffix(b"\n").unwrap_or(&buffer);
+                    let bytes = bytes.strip_suffix(b"\r").unwrap_or(bytes);
+                    // invalid UTF-8 is replaced, so a stray byte doesn't stop the search
+                    let line = String::from_utf8_lossy(bytes);
+                    if !regex.is_match(&line) {
+                        continue;
+                    }
+                    matched = true;
+                    if let (true, Some(file)) = (prefix_files, file) {
+                        write!(out, "{file}:")?;
+                    }
+                    writeln!(out, "{number}:{}", output::highlight(&line, &regex, style))?;
+                }
+            }
+            if failed {
+                return Err(Error::Reported);
+            }
+            return Ok(matched);
+        }
+    }
+    Ok(true)
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(Error::BrokenPipe) => ExitCode::SUCCESS,
+        Err(Error::Message(e)) => {
+            eprintln!("error: {e}");
+            ExitCode::from(2)
+        }
+        Err(Error::Reported) => ExitCode::from(2),
+    }
+}
@@ -0,0 +1,60 @@
+use regex::{Captures, Regex};
+use std::io::IsTerminal;
+
+const HIGHLIGHT: &str = "\x1b[1;31m";
+const RESET: &str = "\x1b[0m";
+
+/// How matches are marked in the output
+#[derive(Clone, Copy)]
+pub enum Style {
+    /// ANSI colors, for terminals
+    Color,
+    /// `[` `]` around matches, for pipes and files
+    Brackets,
+}
+
+impl Style {
+    pub fn detect() -> Self {
+        if std::io::stdout().is_terminal() {
+            Style::Color
+        } else {
+            Style::Brackets
+        }
+    }
+
+    fn wrap(&self, text: &str) -> String {
+        match self {
+            Style::Color => format!("{HIGHLIGHT}{text}{RESET}"),
+            Style::Brackets => format!("[{text}]"),
+        }
+    }
+}
+
+/// The text with every match of `regex` highlighted
+pub fn highlight(text: &str, regex: &Regex, style: Style) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut last = 0;
+    for m in regex.find_iter(text) {
+        out.push_str(&text[last..m.start()]);
+        out.push_str(&style.wrap(m.as_str()));
+        last = m.end();
+    }
+    out.push_str(&text[last..]);
+    out
+}
+
+/// One line per capture group of the match, `#index name: "value"`
+pub fn captures(regex: &Regex, captures: &Captures) -> Vec<String> {
+    regex
+        .capture_names()
+        .enumerate()
+        .skip(1)
+        .map(|(i, name)| {
+            let name = name.map(|n| format!(" {n}")).unwrap_or_default();
+            match captures.get(i) {
+                Some(m) => format!("#{i}{name}: {:?} at {}..{}", m.as_str(), m.start(), m.end()),
+                None => format!("#{i}{name}: -"),
+            }
+        })
+        .collect()
+}
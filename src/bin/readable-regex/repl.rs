@@ -0,0 +1,643 @@
+//! Interactive session: evaluate builder expressions and try them on sample text.
+
+use crate::output::{self, Style};
+use readable_regex::*;
+use std::borrow::Cow;
+use std::io::{BufRead, IsTerminal, Write};
+use std::path::Path;
+
+const HELP: &str = r#"Type a builder expression to see its regex, explanation, groups and sample matches:
+  group(exactly(3, digit)) + "-" + named_group("number", one_or_more(digit))
+  either(["jpg", "png"]) + optional(Raw(r"\d"))
+  network::EMAIL
+
+Strings are literals (`"a.b"` matches a period), use `Raw("...")` for regex syntax.
+Ranges are written `2..=5`, `2..` or `..5`; `2..5` includes `5` too, as in `ranged`.
+
+Commands:
+  :load <file>    use the lines of the file as sample
+  :sample <text>  use the text as sample
+  :code           print the builder code of the last expression
+  :help           show this help
+  :quit           exit"#;
+
+/// Maximum number of matching sample lines shown
+const MAX_LINES: usize = 20;
+
+/// Constants, by function name and [`ReadableRe`] variant name
+const CONSTANTS: &[(&str, &str, ReadableRe<'static>)] = &[
+    ("digit", "Digit", ReadableRe::Digit),
+    ("word", "Word", ReadableRe::Word),
+    ("whitespace", "Whitespace", ReadableRe::Whitespace),
+    ("non_digit", "NonDigit", ReadableRe::NonDigit),
+    ("non_word", "NonWord", ReadableRe::NonWord),
+    ("non_whitespace", "NonWhitespace", ReadableRe::NonWhitespace),
+    ("boundary", "Boundary", ReadableRe::Boundary),
+    ("ascii_letter", "AsciiLetter", ReadableRe::AsciiLetter),
+    (
+        "ascii_non_letter",
+        "AsciiNonLetter",
+        ReadableRe::AsciiNonLetter,
+    ),
+    (
+        "ascii_uppercase",
+        "AsciiUppercase",
+        ReadableRe::AsciiUppercase,
+    ),
+    (
+        "ascii_non_uppercase",
+        "AsciiNonUppercase",
+        ReadableRe::AsciiNonUppercase,
+    ),
+    (
+        "ascii_lowercase",
+        "AsciiLowercase",
+        ReadableRe::AsciiLowercase,
+    ),
+    (
+        "ascii_non_lowercase",
+        "AsciiNonLowercase",
+        ReadableRe::AsciiNonLowercase,
+    ),
+    (
+        "ascii_alphanumeric",
+        "AsciiAlphanumeric",
+        ReadableRe::AsciiAlphanumeric,
+    ),
+    (
+        "ascii_non_alphanumeric",
+        "AsciiNonAlphanumeric",
+        ReadableRe::AsciiNonAlphanumeric,
+    ),
+    ("ascii_numeric", "AsciiNumeric", ReadableRe::AsciiNumeric),
+    (
+        "ascii_non_numeric",
+        "AsciiNonNumeric",
+        ReadableRe::AsciiNonNumeric,
+    ),
+    ("hexadecimal", "Hexadecimal", ReadableRe::Hexadecimal),
+    (
+        "non_hexadecimal",
+        "NonHexadecimal",
+        ReadableRe::NonHexadecimal,
+    ),
+    ("anything", "Anything", ReadableRe::Anything),
+    ("everything", "Everything", ReadableRe::Everything),
+    (
+        "something_greedy",
+        "SomethingGreedy",
+        ReadableRe::SomethingGreedy,
+    ),
+    ("something", "Something", ReadableRe::Something),
+    ("any_char", "AnyChar", ReadableRe::AnyChar),
+    ("period", "Period", ReadableRe::Period),
+    ("caret", "Caret", ReadableRe::Caret),
+    ("dollar", "Dollar", ReadableRe::Dollar),
+    ("asterisk", "Asterisk", ReadableRe::Asterisk),
+    ("plus_sign", "PlusSign", ReadableRe::PlusSign),
+    ("minus_sign", "MinusSign", ReadableRe::MinusSign),
+    ("question_mark", "QuestionMark", ReadableRe::QuestionMark),
+    ("open_brace", "OpenBrace", ReadableRe::OpenBrace),
+    ("close_brace", "CloseBrace", ReadableRe::CloseBrace),
+    ("open_bracket", "OpenBracket", ReadableRe::OpenBracket),
+    ("close_bracket", "CloseBracket", ReadableRe::CloseBracket),
+    (
+        "open_parenthesis",
+        "OpenParenthesis",
+        ReadableRe::OpenParenthesis,
+    ),
+    (
+        "close_parenthesis",
+        "CloseParenthesis",
+        ReadableRe::CloseParenthesis,
+    ),
+    ("back_slash", "BackSlash", ReadableRe::BackSlash),
+    ("pipe", "Pipe", ReadableRe::Pipe),
+    ("new_line", "Newline", ReadableRe::Newline),
+    ("tab", "Tab", ReadableRe::Tab),
+    ("quote", "Quote", ReadableRe::Quote),
+    ("double_quote", "DoubleQuote", ReadableRe::DoubleQuote),
+];
+
+/// Run the session until `:quit` or the end of the input
+pub fn run(sample: Option<&Path>) -> Result<bool, String> {
+    let mut session = Session {
+        sample: Vec::new(),
+        last: None,
+        style: Style::detect(),
+    };
+    if let Some(path) = sample {
+        session.load(path)?;
+    }
+    let interactive = std::io::stdin().is_terminal();
+    if interactive {
+        println!("readable-regex repl, `:help` for help");
+    }
+    let mut lines = std::io::stdin().lock().lines();
+    loop {
+        if interactive {
+            print!("> ");
+            let _ = std::io::stdout().flush();
+        }
+        let Some(line) = lines.next() else {
+            break;
+        };
+        let line = line.map_err(|e| e.to_string())?;
+        let line = line.trim();
+        let (command, argument) = line.split_once(' ').unwrap_or((line, ""));
+        let argument = argument.trim();
+        let result = match command {
+            "" => Ok(()),
+            ":q" | ":quit" | ":exit" => break,
+            ":h" | ":help" => {
+                println!("{HELP}");
+                Ok(())
+            }
+            ":load" => session.load(Path::new(argument)).map(|()| session.rerun()),
+            ":sample" => {
+                session.sample = vec![argument.to_string()];
+                session.rerun();
+                Ok(())
+            }
+            ":code" => session.code(),
+            _ if command.starts_with(':') => Err(format!("unknown command `{command}`")),
+            _ => session.show(line),
+        };
+        if let Err(e) = result {
+            println!("error: {e}");
+        }
+    }
+    Ok(true)
+}
+
+struct Session {
+    sample: Vec<String>,
+    /// Source of the last valid expression
+    last: Option<String>,
+    style: Style,
+}
+
+impl Session {
+    fn load(&mut self, path: &Path) -> Result<(), String> {
+        let text = std::fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+        self.sample = text.lines().map(str::to_string).collect();
+        println!("loaded {} lines from {}", self.sample.len(), path.display());
+        Ok(())
+    }
+
+    /// Show the last expression again, against the new sample
+    fn rerun(&self) {
+        if let Some(last) = &self.last {
+            // it was valid when stored
+            let _ = self.show_matches(&evaluate(last).unwrap());
+        }
+    }
+
+    fn code(&self) -> Result<(), String> {
+        let last = self.last.as_deref().ok_or("no expression yet")?;
+        println!("{}", evaluate(last)?.to_builder_code());
+        Ok(())
+    }
+
+    fn show(&mut self, source: &str) -> Result<(), String> {
+        let re = evaluate(source)?;
+        self.last = Some(source.to_string());
+        println!("regex: {re}");
+        print!("{}", re.explain());
+        self.show_matches(&re)
+    }
+
+    fn show_matches(&self, re: &ReadableRe) -> Result<(), String> {
+        let regex = re.compile().map_err(|e| e.to_string())?;
+        let groups: Vec<String> = regex
+            .capture_names()
+            .enumerate()
+            .skip(1)
+            .map(|(i, name)| match name {
+                Some(name) => format!("#{i} {name}"),
+                None => format!("#{i}"),
+            })
+            .collect();
+        match groups.is_empty() {
+            true => println!("groups: none"),
+            false => println!("groups: {}", groups.join(", ")),
+        }
+        if self.sample.is_empty() {
+            return Ok(());
+        }
+        let matching: Vec<(usize, &String)> = self
+            .sample
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| regex.is_match(line))
+            .collect();
+        println!(
+            "sample: {} of {} lines match",
+            matching.len(),
+            self.sample.len()
+        );
+        for (number, line) in matching.iter().take(MAX_LINES) {
+            println!(
+                "  {}: {}",
+                number + 1,
+                output::highlight(line, &regex, self.style)
+            );
+            for captures in regex.captures_iter(line) {
+                for capture in output::captures(&regex, &captures) {
+                    println!("    {capture}");
+                }
+            }
+        }
+        if matching.len() > MAX_LINES {
+            println!("  ... {} more", matching.len() - MAX_LINES);
+        }
+        Ok(())
+    }
+}
+
+/// Evaluate a builder expression, e.g. `group(exactly(3, digit)) + "-"`
+fn evaluate(source: &str) -> Result<ReadableRe<'_>, String> {
+    let mut parser = Parser { source, pos: 0 };
+    let value = parser.expression()?;
+    parser.skip_whitespace();
+    if parser.pos < source.len() {
+        return Err(parser.error("unexpected input"));
+    }
+    value.into_re()
+}
+
+enum Value<'a> {
+    Re(ReadableRe<'a>),
+    Str(Cow<'a, str>),
+    Int(usize),
+    /// Inclusive bounds
+    Range(usize, Option<usize>),
+    List(Vec<Value<'a>>),
+}
+
+impl<'a> Value<'a> {
+    fn into_re(self) -> Result<ReadableRe<'a>, String> {
+        match self {
+            Value::Re(re) => Ok(re),
+            Value::Str(Cow::Borrowed(s)) => Ok(escape_str(s)),
+            Value::Str(Cow::Owned(s)) => Ok(escape(string_regex(s))),
+            Value::Int(n) => Ok(string_regex(n.to_string())),
+            Value::Range(..) => Err("a range is not an expression".to_string()),
+            Value::List(_) => Err("a list is not an expression".to_string()),
+        }
+    }
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, message: &str) -> String {
+        format!("{message} at column {}", self.pos + 1)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.pos..].chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.source[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        let found = self.source[self.pos..].starts_with(token);
+        if found {
+            self.pos += token.len();
+        }
+        found
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), String> {
+        match self.eat(token) {
+            true => Ok(()),
+            false => Err(self.error(&format!("expected `{token}`"))),
+        }
+    }
+
+    fn take_while(&mut self, f: impl Fn(char) -> bool) -> &'a str {
+        let start = self.pos;
+        let rest = &self.source[start..];
+        self.pos += rest.find(|c| !f(c)).unwrap_or(rest.len());
+        &self.source[start..self.pos]
+    }
+
+    fn expression(&mut self) -> Result<Value<'a>, String> {
+        let first = self.term()?;
+        if !self.eat("+") {
+            return Ok(first);
+        }
+        let mut re = first.into_re()?;
+        loop {
+            re = re + self.term()?.into_re()?;
+            if !self.eat("+") {
+                return Ok(Value::Re(re));
+            }
+        }
+    }
+
+    fn term(&mut self) -> Result<Value<'a>, String> {
+        self.skip_whitespace();
+        let rest = &self.source[self.pos..];
+        match self.peek() {
+            Some('"') => self.string(),
+            Some('r') if rest.starts_with("r\"") || rest.starts_with("r#") => self.raw_string(),
+            Some('[') => {
+                self.pos += 1;
+                Ok(Value::List(self.arguments("]")?))
+            }
+            Some('(') => {
+                self.pos += 1;
+                let value = self.expression()?;
+                self.expect(")")?;
+                Ok(value)
+            }
+            Some(c) if c.is_ascii_digit() || c == '.' => self.number_or_range(),
+            Some(c) if c.is_alphabetic() || c == '_' => self.identifier(),
+            Some(_) => Err(self.error("unexpected character")),
+            None => Err(self.error("unexpected end of expression")),
+        }
+    }
+
+    /// Comma separated values, up to the closing token
+    fn arguments(&mut self, close: &str) -> Result<Vec<Value<'a>>, String> {
+        let mut values = Vec::new();
+        while !self.eat(close) {
+            values.push(self.expression()?);
+            if !self.eat(",") {
+                self.expect(close)?;
+                break;
+            }
+        }
+        Ok(values)
+    }
+
+    fn string(&mut self) -> Result<Value<'a>, String> {
+        let start = self.pos;
+        self.pos += 1;
+        let content = self.take_while(|c| c != '"' && c != '\\');
+        if self.eat("\"") {
+            return Ok(Value::Str(Cow::Borrowed(content)));
+        }
+        let mut owned = content.to_string();
+        let mut chars = self.source[self.pos..].char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.pos += i + 1;
+                    return Ok(Value::Str(Cow::Owned(owned)));
+                }
+                '\\' => owned.push(match chars.next() {
+                    Some((_, 'n')) => '\n',
+                    Some((_, 't')) => '\t',
+                    Some((_, c @ ('"' | '\\' | '\''))) => c,
+                    _ => {
+                        self.pos += i;
+                        return Err(self.error("unknown string escape"));
+                    }
+                }),
+                c => owned.push(c),
+            }
+        }
+        self.pos = start;
+        Err(self.error("unterminated string"))
+    }
+
+    fn raw_string(&mut self) -> Result<Value<'a>, String> {
+        let start = self.pos;
+        self.pos += 1;
+        let hashes = self.take_while(|c| c == '#').len();
+        self.expect("\"")?;
+        let close = format!("\"{}", "#".repeat(hashes));
+        match self.source[self.pos..].find(&close) {
+            Some(end) => {
+                let content = &self.source[self.pos..self.pos + end];
+                self.pos += end + close.len();
+                Ok(Value::Str(Cow::Borrowed(content)))
+            }
+            None => {
+                self.pos = start;
+                Err(self.error("unterminated raw string"))
+            }
+        }
+    }
+
+    fn number(&mut self) -> Result<Option<usize>, String> {
+        match self.take_while(|c| c.is_ascii_digit()) {
+            "" => Ok(None),
+            digits => digits
+                .parse()
+                .map(Some)
+                .map_err(|_| self.error("number too large")),
+        }
+    }
+
+    fn number_or_range(&mut self) -> Result<Value<'a>, String> {
+        let start = self.number()?;
+        if !self.source[self.pos..].starts_with("..") {
+            return start
+                .map(Value::Int)
+                .ok_or_else(|| self.error("expected a number"));
+        }
+        self.pos += 2;
+        let inclusive = self.source[self.pos..].starts_with('=');
+        self.pos += inclusive as usize;
+        // both ends are included, as in `ranged` which renders `2..5` as `{2,5}`
+        let end = match (self.number()?, inclusive) {
+            (None, true) => return Err(self.error("expected the range end")),
+            (end, _) => end,
+        };
+        Ok(Value::Range(start.unwrap_or(0), end))
+    }
+
+    fn identifier(&mut self) -> Result<Value<'a>, String> {
+        let start = self.pos;
+        let path = self.take_while(|c| c.is_alphanumeric() || c == '_' || c == ':');
+        let name = path
+            .trim_start_matches("readable_regex::")
+            .trim_start_matches("ReadableRe::");
+        let arguments = match self.eat("(") {
+            true => Some(self.arguments(")")?),
+            false => None,
+        };
+        let constant = CONSTANTS
+            .iter()
+            .find(|(function, variant, _)| name == *function || name == *variant);
+        let result = match (constant, arguments) {
+            (Some((_, _, re)), None) => Ok(Value::Re(re.clone())),
+            (Some((_, _, re)), Some(arguments)) if arguments.is_empty() => {
+                Ok(Value::Re(re.clone()))
+            }
+            (_, Some(arguments)) => call(name, arguments),
+            (None, None) if name.contains("::") => {
+                let preset = name.trim_start_matches("presets::");
+                presets::by_name(preset)
+                    .map(Value::Re)
+                    .ok_or_else(|| format!("unknown preset `{preset}`"))
+            }
+            (None, None) => Err(format!("unknown name `{name}`")),
+        };
+        result.map_err(|e| {
+            self.pos = start;
+            self.error(&e)
+        })
+    }
+}
+
+/// Call the builder function `name`
+fn call<'a>(name: &str, arguments: Vec<Value<'a>>) -> Result<Value<'a>, String> {
+    let mut arguments = Arguments {
+        name,
+        values: arguments.into_iter(),
+        index: 0,
+    };
+    let unary: Option<fn(ReadableRe<'a>) -> ReadableRe<'a>> = match name {
+        "escape" => Some(escape),
+        "group" => Some(group),
+        "non_capture_group" => Some(non_capture_group),
+        "optional" => Some(optional),
+        "optional_lazy" => Some(optional_lazy),
+        "zero_or_more" => Some(zero_or_more),
+        "zero_or_more_lazy" => Some(zero_or_more_lazy),
+        "one_or_more" => Some(one_or_more),
+        "one_or_more_lazy" => Some(one_or_more_lazy),
+        "starts_with" => Some(starts_with),
+        "ends_with" => Some(ends_with),
+        "starts_and_ends_with" => Some(starts_and_ends_with),
+        #[cfg(feature = "re-fancy")]
+        "positive_look_ahead" => Some(positive_look_ahead),
+        #[cfg(feature = "re-fancy")]
+        "negative_look_ahead" => Some(negative_look_ahead),
+        #[cfg(feature = "re-fancy")]
+        "positive_look_behind" => Some(positive_look_behind),
+        #[cfg(feature = "re-fancy")]
+        "negative_look_behind" => Some(negative_look_behind),
+        #[cfg(feature = "re-fancy")]
+        "atomic_group" => Some(atomic_group),
+        _ => None,
+    };
+    let re = if let Some(unary) = unary {
+        unary(arguments.re()?)
+    } else {
+        match name {
+            "exactly" => exactly(arguments.int()?, arguments.re()?),
+            "at_least" => at_least(arguments.int()?, arguments.re()?),
+            "at_most" => at_most(arguments.int()?, arguments.re()?),
+            "ranged" => match (arguments.range()?, arguments.re()?) {
+                ((min, Some(max)), re) => ranged(min..=max, re),
+                ((min, None), re) => ranged(min.., re),
+            },
+            "named_group" => match arguments.str()? {
+                Cow::Borrowed(group_name) => named_group(group_name, arguments.re()?),
+                Cow::Owned(_) => return Err("group names cannot contain escapes".to_string()),
+            },
+            "Raw" | "raw_regex" => match arguments.str()? {
+                Cow::Borrowed(s) => raw_regex(s),
+                Cow::Owned(s) => string_regex(s),
+            },
+            "escape_str" => Value::Str(arguments.str()?).into_re()?,
+            "chars" => ReadableRe::Chars(solvers::Chars::new(&arguments.str()?)),
+            "not_chars" => not_chars(&arguments.str()?),
+            "concat" => concat(arguments.rest()?),
+            "either" => either(arguments.rest()?),
+            "either_words" => either_words(arguments.rest_str()?),
+            #[cfg(feature = "re-fancy")]
+            "back_reference" => back_reference(arguments.int()?),
+            _ => return Err(format!("unknown function `{name}`")),
+        }
+    };
+    arguments.finish()?;
+    Ok(Value::Re(re))
+}
+
+/// Typed access to the arguments of a call
+struct Arguments<'a, 'n> {
+    name: &'n str,
+    values: std::vec::IntoIter<Value<'a>>,
+    index: usize,
+}
+
+impl<'a> Arguments<'a, '_> {
+    fn next(&mut self, expected: &str) -> Result<Value<'a>, String> {
+        self.index += 1;
+        self.values.next().ok_or_else(|| {
+            format!(
+                "`{}` expects {expected} as argument {}",
+                self.name, self.index
+            )
+        })
+    }
+
+    fn mismatch(&self, expected: &str) -> String {
+        format!(
+            "`{}` expects {expected} as argument {}",
+            self.name, self.index
+        )
+    }
+
+    fn re(&mut self) -> Result<ReadableRe<'a>, String> {
+        self.next("an expression")?.into_re()
+    }
+
+    fn int(&mut self) -> Result<usize, String> {
+        match self.next("a number")? {
+            Value::Int(n) => Ok(n),
+            _ => Err(self.mismatch("a number")),
+        }
+    }
+
+    fn range(&mut self) -> Result<(usize, Option<usize>), String> {
+        match self.next("a range")? {
+            Value::Range(min, max) => Ok((min, max)),
+            Value::Int(n) => Ok((n, Some(n))),
+            _ => Err(self.mismatch("a range")),
+        }
+    }
+
+    fn str(&mut self) -> Result<Cow<'a, str>, String> {
+        match self.next("a string")? {
+            Value::Str(s) => Ok(s),
+            _ => Err(self.mismatch("a string")),
+        }
+    }
+
+    /// The remaining values, a single list argument is flattened
+    fn values(&mut self) -> Vec<Value<'a>> {
+        let mut values: Vec<_> = self.values.by_ref().collect();
+        if let [Value::List(_)] = values.as_slice() {
+            if let Some(Value::List(list)) = values.pop() {
+                return list;
+            }
+        }
+        values
+    }
+
+    fn rest(&mut self) -> Result<Vec<ReadableRe<'a>>, String> {
+        self.values().into_iter().map(Value::into_re).collect()
+    }
+
+    fn rest_str(&mut self) -> Result<Vec<Cow<'a, str>>, String> {
+        self.values()
+            .into_iter()
+            .map(|value| match value {
+                Value::Str(s) => Ok(s),
+                _ => Err(format!("`{}` expects strings", self.name)),
+            })
+            .collect()
+    }
+
+    fn finish(mut self) -> Result<(), String> {
+        match self.values.next() {
+            None => Ok(()),
+            Some(_) => Err(format!("too many arguments for `{}`", self.name)),
+        }
+    }
+}
@@ -0,0 +1,410 @@
+//! Human friendly descriptions of [`ReadableRe`] trees.
+//!
+//! * [`ReadableRe::explain`] describes the expression as an indented tree.
+//! * [`ReadableRe::to_builder_code`] prints the Rust code that builds the expression.
+//!
+//! ```
+//! use readable_regex::{digit, exactly, group, raw_regex};
+//! let re = group(exactly(3, digit())) + raw_regex("-x");
+//! assert_eq!(
+//!     re.explain(),
+//!     "sequence\n  capture group #1\n    exactly 3 times\n      digit `\\d`\n  literal \"-x\"\n"
+//! );
+//! assert_eq!(re.to_builder_code(), r#"group(exactly(3, Digit)) + Raw("-x")"#);
+//! ```
+
+use crate::{solvers, ReadableRe};
+use std::fmt::Write;
+
+/// Constant (leaf) variants information
+pub(crate) struct Leaf {
+    /// [`ReadableRe`] variant name
+    pub variant: &'static str,
+    /// Human name
+    pub name: &'static str,
+    /// Whether it is an escaped literal char
+    pub escaped: bool,
+}
+
+pub(crate) fn leaf(re: &ReadableRe) -> Option<Leaf> {
+    let (variant, name) = match re {
+        ReadableRe::Digit => ("Digit", "digit"),
+        ReadableRe::Word => ("Word", "word char"),
+        ReadableRe::Whitespace => ("Whitespace", "whitespace"),
+        ReadableRe::NonDigit => ("NonDigit", "non digit"),
+        ReadableRe::NonWord => ("NonWord", "non word char"),
+        ReadableRe::NonWhitespace => ("NonWhitespace", "non whitespace"),
+        ReadableRe::Boundary => ("Boundary", "word boundary"),
+        ReadableRe::AsciiLetter => ("AsciiLetter", "ascii letter"),
+        ReadableRe::AsciiNonLetter => ("AsciiNonLetter", "ascii non letter"),
+        ReadableRe::AsciiUppercase => ("AsciiUppercase", "ascii uppercase"),
+        ReadableRe::AsciiNonUppercase => ("AsciiNonUppercase", "ascii non uppercase"),
+        ReadableRe::AsciiLowercase => ("AsciiLowercase", "ascii lowercase"),
+        ReadableRe::AsciiNonLowercase => ("AsciiNonLowercase", "ascii non lowercase"),
+        ReadableRe::AsciiAlphanumeric => ("AsciiAlphanumeric", "ascii alphanumeric"),
+        ReadableRe::AsciiNonAlphanumeric => ("AsciiNonAlphanumeric", "ascii non alphanumeric"),
+        ReadableRe::AsciiNumeric => ("AsciiNumeric", "ascii numeric"),
+        ReadableRe::AsciiNonNumeric => ("AsciiNonNumeric", "ascii non numeric"),
+        ReadableRe::Hexadecimal => ("Hexadecimal", "hexadecimal"),
+        ReadableRe::NonHexadecimal => ("NonHexadecimal", "non hexadecimal"),
+        ReadableRe::Anything => ("Anything", "anything (lazy)"),
+        ReadableRe::Everything => ("Everything", "everything"),
+        ReadableRe::SomethingGreedy => ("SomethingGreedy", "something"),
+        ReadableRe::Something => ("Something", "something (lazy)"),
+        ReadableRe::AnyChar => ("AnyChar", "any char"),
+        ReadableRe::Newline => ("Newline", "new line"),
+        ReadableRe::Tab => ("Tab", "tab"),
+        #[cfg(feature = "re-fancy")]
+        ReadableRe::Back1 => ("Back1", "back reference #1"),
+        #[cfg(feature = "re-fancy")]
+        ReadableRe::Back2 => ("Back2", "back reference #2"),
+        #[cfg(feature = "re-fancy")]
+        ReadableRe::Back3 => ("Back3", "back reference #3"),
+        #[cfg(feature = "re-fancy")]
+        ReadableRe::Back4 => ("Back4", "back reference #4"),
+        #[cfg(feature = "re-fancy")]
+        ReadableRe::Back5 => ("Back5", "back reference #5"),
+        #[cfg(feature = "re-fancy")]
+        ReadableRe::Back6 => ("Back6", "back reference #6"),
+        #[cfg(feature = "re-fancy")]
+        ReadableRe::Back7 => ("Back7", "back reference #7"),
+        #[cfg(feature = "re-fancy")]
+        ReadableRe::Back8 => ("Back8", "back reference #8"),
+        #[cfg(feature = "re-fancy")]
+        ReadableRe::Back9 => ("Back9", "back reference #9"),
+        _ => {
+            let variant = match re {
+                ReadableRe::Period => "Period",
+                ReadableRe::Caret => "Caret",
+                ReadableRe::Dollar => "Dollar",
+                ReadableRe::Asterisk => "Asterisk",
+                ReadableRe::PlusSign => "PlusSign",
+                ReadableRe::MinusSign => "MinusSign",
+                ReadableRe::QuestionMark => "QuestionMark",
+                ReadableRe::OpenBrace => "OpenBrace",
+                ReadableRe::CloseBrace => "CloseBrace",
+                ReadableRe::OpenBracket => "OpenBracket",
+                ReadableRe::CloseBracket => "CloseBracket",
+                ReadableRe::OpenParenthesis => "OpenParenthesis",
+                ReadableRe::CloseParenthesis => "CloseParenthesis",
+                ReadableRe::BackSlash => "BackSlash",
+                ReadableRe::Pipe => "Pipe",
+                ReadableRe::Quote => "Quote",
+                ReadableRe::DoubleQuote => "DoubleQuote",
+                _ => return None,
+            };
+            return Some(Leaf {
+                variant,
+                name: "escaped char",
+                escaped: true,
+            });
+        }
+    };
+    Some(Leaf {
+        variant,
+        name,
+        escaped: false,
+    })
+}
+
+impl<'a> ReadableRe<'a> {
+    /// Describe the expression as an indented tree, one node per line
+    pub fn explain(&self) -> String {
+        let mut out = String::new();
+        let mut groups = 0;
+        explain(self, 0, &mut groups, &mut out);
+        out
+    }
+
+    /// Rust code that builds the expression with this crate functions, expecting
+    /// `readable_regex::*` and `readable_regex::ReadableRe::*` to be in scope
+    pub fn to_builder_code(&self) -> String {
+        let mut out = String::new();
+        code(self, &mut out);
+        out
+    }
+}
+
+fn explain(re: &ReadableRe, depth: usize, groups: &mut usize, out: &mut String) {
+    let indent = "  ".repeat(depth);
+    let mut line = |text: String| {
+        let _ = writeln!(out, "{indent}{text}");
+    };
+    if let Some(leaf) = leaf(re) {
+        match leaf.escaped {
+            true => line(format!("literal {:?}", &re.to_string()[1..])),
+            false => line(format!("{} `{}`", leaf.name, re)),
+        }
+        return;
+    }
+    let (text, children): (String, Vec<&ReadableRe>) = match re {
+        ReadableRe::Raw(raw) => (raw_text(raw), vec![]),
+        ReadableRe::String(s) => (raw_text(s), vec![]),
+        ReadableRe::Concat(solvers::Concat(v)) => match v.as_slice() {
+            [] => ("empty".into(), vec![]),
+            [single] => return explain(single, depth, groups, out),
+            v => ("sequence".into(), v.iter().collect()),
+        },
+        #[cfg(feature = "re-fancy")]
+        ReadableRe::BackReference(solvers::BackReference(n)) => {
+            (format!("back reference #{n}"), vec![])
+        }
+        ReadableRe::Escape(escape) => (format!("literal {:?}", escape.0.to_string()), vec![]),
+        ReadableRe::Group(group) => {
+            *groups += 1;
+            (format!("capture group #{groups}"), vec![&group.0])
+        }
+        ReadableRe::NamedGroup(named) => {
+            *groups += 1;
+            (
+                format!("capture group #{groups} {:?}", named.name),
+                vec![&named.regexes],
+            )
+        }
+        #[cfg(feature = "re-fancy")]
+        ReadableRe::PositiveLookAhead(look) => ("followed by".into(), vec![&look.0]),
+        #[cfg(feature = "re-fancy")]
+        ReadableRe::NegativeLookAhead(look) => ("not followed by".into(), vec![&look.0]),
+        #[cfg(feature = "re-fancy")]
+        ReadableRe::PositiveLookBehind(look) => ("preceded by".into(), vec![&look.0]),
+        #[cfg(feature = "re-fancy")]
+        ReadableRe::NegativeLookBehind(look) => ("not preceded by".into(), vec![&look.0]),
+        #[cfg(feature = "re-fancy")]
+        ReadableRe::AtomicGroup(atomic) => ("atomic group".into(), vec![&atomic.0]),
+        ReadableRe::NonCaptureGroup(group) => ("non capture group".into(), vec![&group.0]),
+        ReadableRe::Optional(optional) => ("optional".into(), vec![&optional.0]),
+        ReadableRe::OptionalLazy(optional) => ("optional (lazy)".into(), vec![&optional.0]),
+        ReadableRe::Either(either) => ("either".into(), either.alternatives().collect()),
+        ReadableRe::Trie(trie) => (
+            format!(
+                "either word of {:?}{}",
+                trie.words,
+                if trie.case_insensitive {
+                    ", ignoring case"
+                } else {
+                    ""
+                }
+            ),
+            vec![],
+        ),
+        ReadableRe::Exactly(exactly) => (
+            format!("exactly {} times", exactly.quantity),
+            vec![&exactly.re],
+        ),
+        ReadableRe::Ranged(ranged) => {
+            let text = match ranged.min_max() {
+                (min, Some(max)) => format!("between {min} and {max} times"),
+                (min, None) => format!("at least {min} times"),
+            };
+            (text, vec![&ranged.re])
+        }
+        ReadableRe::ZeroOrMore(re) => ("zero or more times".into(), vec![&re.0]),
+        ReadableRe::ZeroOrMoreLazy(re) => ("zero or more times (lazy)".into(), vec![&re.0]),
+        ReadableRe::OneOrMore(re) => ("one or more times".into(), vec![&re.0]),
+        ReadableRe::OneOrMoreLazy(re) => ("one or more times (lazy)".into(), vec![&re.0]),
+        ReadableRe::StartsWith(re) => ("at the start".into(), vec![&re.0]),
+        ReadableRe::EndsWith(re) => ("at the end".into(), vec![&re.0]),
+        ReadableRe::StartsAndEndsWith(re) => ("the whole text".into(), vec![anchored(re)]),
+        ReadableRe::Chars(chars) => (format!("one of `[{}]`", chars.0), vec![]),
+        ReadableRe::NotChars(chars) => (format!("none of `[{}]`", chars.0), vec![]),
+        other => (format!("`{other}`"), vec![]),
+    };
+    line(text);
+    for child in children {
+        explain(child, depth + 1, groups, out);
+    }
+}
+
+fn raw_text(raw: &str) -> String {
+    if raw
+        .chars()
+        .all(|c| c.is_alphanumeric() || " -_,:;@#%&='\"/<>!~`".contains(c))
+    {
+        format!("literal {:?}", raw)
+    } else {
+        format!("regex `{}`", raw)
+    }
+}
+
+/// Inner expression of a [`solvers::StartsAndEndsWith`]
+fn anchored<'r, 'a>(re: &'r solvers::StartsAndEndsWith<'a>) -> &'r ReadableRe<'a> {
+    match re.0.as_ref() {
+        ReadableRe::StartsWith(starts_with) => match starts_with.0.as_ref() {
+            ReadableRe::EndsWith(ends_with) => &ends_with.0,
+            other => other,
+        },
+        other => other,
+    }
+}
+
+fn code(re: &ReadableRe, out: &mut String) {
+    if let Some(leaf) = leaf(re) {
+        out.push_str(leaf.variant);
+        return;
+    }
+    let call = |name: &str, inner: &ReadableRe, out: &mut String| {
+        out.push_str(name);
+        out.push('(');
+        code(inner, out);
+        out.push(')');
+    };
+    match re {
+        ReadableRe::Raw(raw) => {
+            let _ = write!(out, "Raw({})", string_literal(raw));
+        }
+        ReadableRe::String(s) => {
+            let _ = write!(out, "Raw({})", string_literal(s));
+        }
+        ReadableRe::Concat(solvers::Concat(v)) => match v.as_slice() {
+            [] => out.push_str("concat([])"),
+            [single] => code(single, out),
+            v => {
+                for (i, re) in v.iter().enumerate() {
+                    if i > 0 {
+                        out.push_str(" + ");
+                    }
+                    code(re, out);
+                }
+            }
+        },
+        #[cfg(feature = "re-fancy")]
+        ReadableRe::BackReference(solvers::BackReference(n)) => {
+            let _ = write!(out, "back_reference({n})");
+        }
+        ReadableRe::Escape(escape) => match escape.0.as_ref() {
+            ReadableRe::Raw(raw) => {
+                let _ = write!(out, "escape_str({})", string_literal(raw));
+            }
+            other => call("escape", other, out),
+        },
+        ReadableRe::Group(group) => call("group", &group.0, out),
+        #[cfg(feature = "re-fancy")]
+        ReadableRe::PositiveLookAhead(look) => call("positive_look_ahead", &look.0, out),
+        #[cfg(feature = "re-fancy")]
+        ReadableRe::NegativeLookAhead(look) => call("negative_look_ahead", &look.0, out),
+        #[cfg(feature = "re-fancy")]
+        ReadableRe::PositiveLookBehind(look) => call("positive_look_behind", &look.0, out),
+        #[cfg(feature = "re-fancy")]
+        ReadableRe::NegativeLookBehind(look) => call("negative_look_behind", &look.0, out),
+        #[cfg(feature = "re-fancy")]
+        ReadableRe::AtomicGroup(atomic) => call("atomic_group", &atomic.0, out),
+        ReadableRe::NamedGroup(named) => {
+            let _ = write!(out, "named_group({:?}, ", named.name);
+            code(&named.regexes, out);
+            out.push(')');
+        }
+        ReadableRe::NonCaptureGroup(group) => call("non_capture_group", &group.0, out),
+        ReadableRe::Optional(optional) => call("optional", &optional.0, out),
+        ReadableRe::OptionalLazy(optional) => call("optional_lazy", &optional.0, out),
+        ReadableRe::Either(either) => {
+            out.push_str("either([");
+            for (i, re) in either.alternatives().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                code(re, out);
+            }
+            out.push_str("])");
+        }
+        ReadableRe::Trie(trie) => {
+            let words: Vec<_> = trie.words.iter().map(|w| string_literal(w)).collect();
+            let words = format!("[{}]", words.join(", "));
+            if !trie.word_boundaries && !trie.case_insensitive && trie.longest_first {
+                let _ = write!(out, "either_words({words})");
+            } else {
+                let _ = write!(
+                    out,
+                    "ReadableRe::Trie(solvers::Trie::new({words}).word_boundaries({}).case_insensitive({}).longest_first({}))",
+                    trie.word_boundaries, trie.case_insensitive, trie.longest_first
+                );
+            }
+        }
+        ReadableRe::Exactly(exactly) => {
+            let _ = write!(out, "exactly({}, ", exactly.quantity);
+            code(&exactly.re, out);
+            out.push(')');
+        }
+        ReadableRe::Ranged(ranged) => {
+            let _ = match ranged.min_max() {
+                (min, Some(max)) => write!(out, "ranged({min}..={max}, "),
+                (min, None) => write!(out, "at_least({min}, "),
+            };
+            code(&ranged.re, out);
+            out.push(')');
+        }
+        ReadableRe::ZeroOrMore(re) => call("zero_or_more", &re.0, out),
+        ReadableRe::ZeroOrMoreLazy(re) => call("zero_or_more_lazy", &re.0, out),
+        ReadableRe::OneOrMore(re) => call("one_or_more", &re.0, out),
+        ReadableRe::OneOrMoreLazy(re) => call("one_or_more_lazy", &re.0, out),
+        ReadableRe::StartsWith(re) => call("starts_with", &re.0, out),
+        ReadableRe::EndsWith(re) => call("ends_with", &re.0, out),
+        ReadableRe::StartsAndEndsWith(re) => call("starts_and_ends_with", anchored(re), out),
+        ReadableRe::Chars(chars) => {
+            let _ = write!(out, "chars({})", string_literal(&chars.0));
+        }
+        ReadableRe::NotChars(chars) => {
+            let _ = write!(out, "not_chars({})", string_literal(&chars.0));
+        }
+        other => {
+            let _ = write!(out, "Raw({})", string_literal(&other.to_string()));
+        }
+    }
+}
+
+/// Shortest readable Rust string literal for `s`
+fn string_literal(s: &str) -> String {
+    if !s.contains(['\\', '"']) {
+        format!("\"{s}\"")
+    } else if !s.contains('"') {
+        format!("r\"{s}\"")
+    } else if !s.contains("\"#") {
+        format!("r#\"{s}\"#")
+    } else {
+        format!("{:?}", s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ReadableRe::*;
+    use crate::*;
+
+    #[test]
+    fn explain() {
+        let re = starts_and_ends_with(
+            named_group("area", exactly(3, Digit))
+                + optional(either([Raw("-"), Period]))
+                + group(one_or_more(chars("a-z"))),
+        );
+        assert_eq!(
+            re.explain(),
+            r#"the whole text
+  sequence
+    capture group #1 "area"
+      exactly 3 times
+        digit `\d`
+    optional
+      either
+        literal "-"
+        literal "."
+    capture group #2
+      one or more times
+        one of `[a-z]`
+"#
+        );
+    }
+
+    #[test]
+    fn builder_code() {
+        let re = starts_and_ends_with(
+            named_group("area", exactly(3, Digit))
+                + optional(either([Raw("-"), Period]))
+                + ranged(2..=5, chars(r"\w\\"))
+                + escape_str("a\"b"),
+        );
+        assert_eq!(
+            re.to_builder_code(),
+            r####"starts_and_ends_with(named_group("area", exactly(3, Digit)) + optional(either([Raw("-"), Period])) + ranged(2..=5, chars(r"\w\\")) + escape_str(r#"a"b"#))"####
+        );
+    }
+}
@@ -0,0 +1,495 @@
+//! Visualizations of [`ReadableRe`] trees.
+//!
+//! * [`ReadableRe::railroad_svg`] renders a railroad (syntax) diagram as a standalone SVG document.
+//! * [`ReadableRe::to_dot`] exports the expression tree in Graphviz DOT format.
+//!
+//! ```
+//! use readable_regex::{digit, either, exactly, group, raw_regex};
+//! let re = group(exactly(3, digit())) + either([raw_regex("-"), raw_regex(".")]);
+//! assert!(re.railroad_svg().starts_with("<svg"));
+//! assert!(re.to_dot().starts_with("digraph"));
+//! ```
+//!
+//! Available with feature `"diagram"`
+
+use crate::{describe, solvers, ReadableRe};
+use std::fmt::Write;
+
+const CHAR_WIDTH: usize = 8;
+const BOX_HEIGHT: usize = 22;
+const ARC: usize = 10;
+const GAP: usize = 10;
+const LABEL_HEIGHT: usize = 14;
+const PADDING: usize = 20;
+
+const STYLE: &str = "path{stroke-width:2;stroke:#333;fill:none}\
+rect{stroke-width:2;stroke:#333;fill:#eef}\
+rect.terminal{fill:#ffd}\
+rect.group{stroke-width:1;stroke-dasharray:4;fill:none}\
+text{font:12px monospace;text-anchor:middle;dominant-baseline:central}\
+text.label{font-size:10px;text-anchor:start}";
+
+/// Railroad diagram elements, laid out around a horizontal baseline
+enum Diagram {
+    /// Literal text, drawn in a rounded box
+    Terminal(String),
+    /// Class or assertion description, drawn in a square box
+    NonTerminal(String),
+    Sequence(Vec<Diagram>),
+    /// First branch runs over the baseline, others are stacked below it
+    Choice(Vec<Diagram>),
+    /// Item with a loop back below it
+    Repeat(Box<Diagram>, Option<String>),
+    /// Dashed box around the item
+    Group(Box<Diagram>, String),
+    Skip,
+}
+
+impl Diagram {
+    fn from_re(re: &ReadableRe) -> Self {
+        if let Some(leaf) = describe::leaf(re) {
+            return match leaf.escaped {
+                true => Diagram::Terminal(re.to_string()[1..].into()),
+                false => Diagram::NonTerminal(leaf.name.into()),
+            };
+        }
+        match re {
+            ReadableRe::Raw(raw) => Diagram::Terminal(raw.to_string()),
+            ReadableRe::String(s) => Diagram::Terminal(s.clone()),
+            ReadableRe::Concat(solvers::Concat(v)) => {
+                let mut items: Vec<_> = v.iter().map(Diagram::from_re).collect();
+                if items.len() == 1 {
+                    items.pop().unwrap()
+                } else {
+                    Diagram::Sequence(items)
+                }
+            }
+            #[cfg(feature = "re-fancy")]
+            ReadableRe::BackReference(solvers::BackReference(n)) => {
+                Diagram::NonTerminal(format!("back reference #{n}"))
+            }
+            ReadableRe::Escape(escape) => Diagram::Terminal(escape.0.to_string()),
+            ReadableRe::Group(group) => Diagram::group(&group.0, "group".into()),
+            #[cfg(feature = "re-fancy")]
+            ReadableRe::PositiveLookAhead(look) => Diagram::group(&look.0, "followed by".into()),
+            #[cfg(feature = "re-fancy")]
+            ReadableRe::NegativeLookAhead(look) => {
+                Diagram::group(&look.0, "not followed by".into())
+            }
+            #[cfg(feature = "re-fancy")]
+            ReadableRe::PositiveLookBehind(look) => Diagram::group(&look.0, "preceded by".into()),
+            #[cfg(feature = "re-fancy")]
+            ReadableRe::NegativeLookBehind(look) => {
+                Diagram::group(&look.0, "not preceded by".into())
+            }
+            #[cfg(feature = "re-fancy")]
+            ReadableRe::AtomicGroup(atomic) => Diagram::group(&atomic.0, "atomic".into()),
+            ReadableRe::NamedGroup(named) => {
+                Diagram::group(&named.regexes, format!("group <{}>", named.name))
+            }
+            ReadableRe::NonCaptureGroup(group) => Diagram::from_re(&group.0),
+            ReadableRe::Optional(optional) => Diagram::optional(Diagram::from_re(&optional.0)),
+            ReadableRe::OptionalLazy(optional) => Diagram::optional(Diagram::Group(
+                Box::new(Diagram::from_re(&optional.0)),
+                "lazy".into(),
+            )),
+            ReadableRe::Either(either) => {
+                Diagram::Choice(either.alternatives().map(Diagram::from_re).collect())
+            }
+            ReadableRe::Trie(trie) => {
+                let mut words: Vec<_> = trie.words.iter().filter(|w| !w.is_empty()).collect();
+                words.sort();
+                words.dedup();
+                let choice =
+                    Diagram::Choice(words.into_iter().cloned().map(Diagram::Terminal).collect());
+                if trie.case_insensitive {
+                    Diagram::Group(Box::new(choice), "ignoring case".into())
+                } else {
+                    choice
+                }
+            }
+            ReadableRe::Exactly(exactly) => Diagram::Repeat(
+                Box::new(Diagram::from_re(&exactly.re)),
+                Some(format!("{} times", exactly.quantity)),
+            ),
+            ReadableRe::Ranged(ranged) => {
+                let label = format!("{} times", range_text(ranged));
+                let repeat = Diagram::Repeat(Box::new(Diagram::from_re(&ranged.re)), Some(label));
+                if ranged.min_max().0 == 0 {
+                    Diagram::optional(repeat)
+                } else {
+                    repeat
+                }
+            }
+            ReadableRe::ZeroOrMore(zero_or_more) => Diagram::optional(Diagram::Repeat(
+                Box::new(Diagram::from_re(&zero_or_more.0)),
+                None,
+            )),
+            ReadableRe::ZeroOrMoreLazy(zero_or_more) => Diagram::optional(Diagram::Repeat(
+                Box::new(Diagram::from_re(&zero_or_more.0)),
+                Some("lazy".into()),
+            )),
+            ReadableRe::OneOrMore(one_or_more) => {
+                Diagram::Repeat(Box::new(Diagram::from_re(&one_or_more.0)), None)
+            }
+            ReadableRe::OneOrMoreLazy(one_or_more) => Diagram::Repeat(
+                Box::new(Diagram::from_re(&one_or_more.0)),
+                Some("lazy".into()),
+            ),
+            ReadableRe::StartsWith(starts_with) => Diagram::Sequence(vec![
+                Diagram::NonTerminal("start".into()),
+                Diagram::from_re(&starts_with.0),
+            ]),
+            ReadableRe::EndsWith(ends_with) => Diagram::Sequence(vec![
+                Diagram::from_re(&ends_with.0),
+                Diagram::NonTerminal("end".into()),
+            ]),
+            ReadableRe::StartsAndEndsWith(starts_and_ends_with) => {
+                Diagram::from_re(&starts_and_ends_with.0)
+            }
+            ReadableRe::Chars(chars) => Diagram::NonTerminal(format!("one of [{}]", chars.0)),
+            ReadableRe::NotChars(chars) => Diagram::NonTerminal(format!("none of [{}]", chars.0)),
+            other => Diagram::Terminal(other.to_string()),
+        }
+    }
+
+    fn group(re: &ReadableRe, label: String) -> Self {
+        Diagram::Group(Box::new(Diagram::from_re(re)), label)
+    }
+
+    fn optional(item: Diagram) -> Self {
+        Diagram::Choice(vec![Diagram::Skip, item])
+    }
+
+    fn width(&self) -> usize {
+        match self {
+            Diagram::Terminal(text) | Diagram::NonTerminal(text) => text_width(text) + 2 * GAP,
+            Diagram::Sequence(items) => {
+                items.iter().map(Diagram::width).sum::<usize>()
+                    + GAP * items.len().saturating_sub(1)
+            }
+            Diagram::Choice(items) => items.iter().map(Diagram::width).max().unwrap_or(0) + 4 * ARC,
+            Diagram::Repeat(item, label) => {
+                let label = label.as_deref().map(text_width).unwrap_or(0);
+                item.width().max(label) + 2 * ARC
+            }
+            Diagram::Group(item, label) => item.width().max(text_width(label)) + 2 * GAP,
+            Diagram::Skip => 0,
+        }
+    }
+
+    /// Height above the baseline
+    fn up(&self) -> usize {
+        match self {
+            Diagram::Terminal(_) | Diagram::NonTerminal(_) => BOX_HEIGHT / 2,
+            Diagram::Sequence(items) => items.iter().map(Diagram::up).max().unwrap_or(0),
+            Diagram::Choice(items) => items.first().map(Diagram::up).unwrap_or(0),
+            Diagram::Repeat(item, _) => item.up(),
+            Diagram::Group(item, _) => item.up() + GAP + LABEL_HEIGHT,
+            Diagram::Skip => 0,
+        }
+    }
+
+    /// Height below the baseline
+    fn down(&self) -> usize {
+        match self {
+            Diagram::Terminal(_) | Diagram::NonTerminal(_) => BOX_HEIGHT / 2,
+            Diagram::Sequence(items) => items.iter().map(Diagram::down).max().unwrap_or(0),
+            Diagram::Choice(items) => {
+                items.first().map(Diagram::down).unwrap_or(0)
+                    + items
+                        .iter()
+                        .skip(1)
+                        .map(|item| GAP + item.up().max(ARC) + item.down())
+                        .sum::<usize>()
+            }
+            Diagram::Repeat(item, label) => {
+                item.down() + GAP + label.as_ref().map(|_| LABEL_HEIGHT).unwrap_or(0)
+            }
+            Diagram::Group(item, _) => item.down() + GAP,
+            Diagram::Skip => 0,
+        }
+    }
+
+    /// Draw the element from `(x, y)` to `(x + width, y)`
+    fn draw(&self, x: usize, y: usize, out: &mut String) {
+        let width = self.width();
+        match self {
+            Diagram::Terminal(text) | Diagram::NonTerminal(text) => {
+                let (class, radius) = match self {
+                    Diagram::Terminal(_) => ("terminal", ARC),
+                    _ => ("nonterminal", 0),
+                };
+                let _ = write!(
+                    out,
+                    r#"<rect class="{class}" x="{x}" y="{}" width="{width}" height="{BOX_HEIGHT}" rx="{radius}"/><text x="{}" y="{y}">{}</text>"#,
+                    y - BOX_HEIGHT / 2,
+                    x + width / 2,
+                    xml_escape(text),
+                );
+            }
+            Diagram::Sequence(items) => {
+                let mut x = x;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        line(x, y, x + GAP, out);
+                        x += GAP;
+                    }
+                    item.draw(x, y, out);
+                    x += item.width();
+                }
+            }
+            Diagram::Choice(items) => {
+                let inner = width - 4 * ARC;
+                let mut branch_y = y;
+                for (i, item) in items.iter().enumerate() {
+                    if i == 0 {
+                        line(x, y, x + 2 * ARC, out);
+                    } else {
+                        branch_y += items[i - 1].down() + GAP + item.up().max(ARC);
+                        let _ = write!(
+                            out,
+                            r#"<path d="M{x} {y}a{ARC} {ARC} 0 0 1 {ARC} {ARC}V{}a{ARC} {ARC} 0 0 0 {ARC} {ARC}"/>"#,
+                            branch_y - ARC,
+                        );
+                        let _ = write!(
+                            out,
+                            r#"<path d="M{} {branch_y}a{ARC} {ARC} 0 0 0 {ARC} -{ARC}V{}a{ARC} {ARC} 0 0 1 {ARC} -{ARC}"/>"#,
+                            x + 2 * ARC + inner,
+                            y + ARC,
+                        );
+                    }
+                    item.draw(x + 2 * ARC, branch_y, out);
+                    line(
+                        x + 2 * ARC + item.width(),
+                        branch_y,
+                        x + 2 * ARC + inner,
+                        out,
+                    );
+                    if i == 0 {
+                        line(x + 2 * ARC + inner, y, x + width, out);
+                    }
+                }
+            }
+            Diagram::Repeat(item, label) => {
+                let item_x = x + ARC + (width - 2 * ARC - item.width()) / 2;
+                line(x, y, item_x, out);
+                item.draw(item_x, y, out);
+                line(item_x + item.width(), y, x + width, out);
+                let bottom = (y + item.down() + GAP).max(y + 2 * ARC);
+                let _ = write!(
+                    out,
+                    r#"<path d="M{} {y}a{ARC} {ARC} 0 0 1 {ARC} {ARC}V{}a{ARC} {ARC} 0 0 1 -{ARC} {ARC}H{}a{ARC} {ARC} 0 0 1 -{ARC} -{ARC}V{}a{ARC} {ARC} 0 0 1 {ARC} -{ARC}"/>"#,
+                    x + width - ARC,
+                    bottom - ARC,
+                    x + ARC,
+                    y + ARC,
+                );
+                if let Some(label) = label {
+                    let _ = write!(
+                        out,
+                        r#"<text class="label" x="{}" y="{}">{}</text>"#,
+                        x + ARC,
+                        bottom + LABEL_HEIGHT / 2 + 2,
+                        xml_escape(label),
+                    );
+                }
+            }
+            Diagram::Group(item, label) => {
+                let top = y - item.up() - GAP;
+                let _ = write!(
+                    out,
+                    r#"<rect class="group" x="{x}" y="{top}" width="{width}" height="{}"/><text class="label" x="{}" y="{}">{}</text>"#,
+                    item.up() + item.down() + 2 * GAP,
+                    x + 2,
+                    top - LABEL_HEIGHT / 2,
+                    xml_escape(label),
+                );
+                let item_x = x + (width - item.width()) / 2;
+                line(x, y, item_x, out);
+                item.draw(item_x, y, out);
+                line(item_x + item.width(), y, x + width, out);
+            }
+            Diagram::Skip => {}
+        }
+    }
+}
+
+impl<'a> ReadableRe<'a> {
+    /// Render the expression as a railroad diagram, in a standalone SVG document
+    ///
+    /// Available with feature `"diagram"`
+    pub fn railroad_svg(&self) -> String {
+        let diagram = Diagram::from_re(self);
+        let (width, up, down) = (diagram.width(), diagram.up(), diagram.down());
+        let y = PADDING + up;
+        let mut out = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{1}" viewBox="0 0 {0} {1}"><style>{STYLE}</style>"#,
+            width + 2 * PADDING + 2 * GAP,
+            up + down + 2 * PADDING,
+        );
+        let _ = write!(
+            out,
+            r#"<path d="M{PADDING} {}v{BOX_HEIGHT}M{PADDING} {y}h{GAP}"/>"#,
+            y - BOX_HEIGHT / 2,
+        );
+        diagram.draw(PADDING + GAP, y, &mut out);
+        let end = PADDING + GAP + width;
+        let _ = write!(
+            out,
+            r#"<path d="M{end} {y}h{GAP}m0 -{}v{BOX_HEIGHT}"/></svg>"#,
+            BOX_HEIGHT / 2,
+        );
+        out
+    }
+
+    /// Export the expression tree in [Graphviz](https://graphviz.org) DOT format
+    ///
+    /// Available with feature `"diagram"`
+    pub fn to_dot(&self) -> String {
+        let mut out =
+            String::from("digraph ReadableRe {\n    node [shape=box, fontname=\"monospace\"];\n");
+        let mut next_id = 0;
+        dot_node(self, &mut next_id, &mut out);
+        out.push_str("}\n");
+        out
+    }
+}
+
+/// Writes the node and its children, returning the node id
+fn dot_node(re: &ReadableRe, next_id: &mut usize, out: &mut String) -> usize {
+    let id = *next_id;
+    *next_id += 1;
+    let (label, children): (String, Vec<&ReadableRe>) = match re {
+        ReadableRe::Concat(solvers::Concat(v)) => ("concat".into(), v.iter().collect()),
+        ReadableRe::Escape(escape) => (format!("escape {}", escape.0), vec![]),
+        ReadableRe::Group(group) => ("group".into(), vec![&group.0]),
+        #[cfg(feature = "re-fancy")]
+        ReadableRe::PositiveLookAhead(look) => ("positive look ahead".into(), vec![&look.0]),
+        #[cfg(feature = "re-fancy")]
+        ReadableRe::NegativeLookAhead(look) => ("negative look ahead".into(), vec![&look.0]),
+        #[cfg(feature = "re-fancy")]
+        ReadableRe::PositiveLookBehind(look) => ("positive look behind".into(), vec![&look.0]),
+        #[cfg(feature = "re-fancy")]
+        ReadableRe::NegativeLookBehind(look) => ("negative look behind".into(), vec![&look.0]),
+        #[cfg(feature = "re-fancy")]
+        ReadableRe::AtomicGroup(atomic) => ("atomic group".into(), vec![&atomic.0]),
+        ReadableRe::NamedGroup(named) => {
+            (format!("named group {}", named.name), vec![&named.regexes])
+        }
+        ReadableRe::NonCaptureGroup(group) => ("non capture group".into(), vec![&group.0]),
+        ReadableRe::Optional(optional) => ("optional".into(), vec![&optional.0]),
+        ReadableRe::OptionalLazy(optional) => ("optional (lazy)".into(), vec![&optional.0]),
+        ReadableRe::Either(either) => ("either".into(), either.alternatives().collect()),
+        ReadableRe::Exactly(exactly) => {
+            (format!("exactly {}", exactly.quantity), vec![&exactly.re])
+        }
+        ReadableRe::Ranged(ranged) => (format!("ranged {}", range_text(ranged)), vec![&ranged.re]),
+        ReadableRe::ZeroOrMore(re) => ("zero or more".into(), vec![&re.0]),
+        ReadableRe::ZeroOrMoreLazy(re) => ("zero or more (lazy)".into(), vec![&re.0]),
+        ReadableRe::OneOrMore(re) => ("one or more".into(), vec![&re.0]),
+        ReadableRe::OneOrMoreLazy(re) => ("one or more (lazy)".into(), vec![&re.0]),
+        ReadableRe::StartsWith(re) => ("starts with".into(), vec![&re.0]),
+        ReadableRe::EndsWith(re) => ("ends with".into(), vec![&re.0]),
+        ReadableRe::StartsAndEndsWith(re) => (
+            "starts and ends with".into(),
+            match re.0.as_ref() {
+                ReadableRe::StartsWith(starts_with) => match starts_with.0.as_ref() {
+                    ReadableRe::EndsWith(ends_with) => vec![&ends_with.0],
+                    other => vec![other],
+                },
+                other => vec![other],
+            },
+        ),
+        other => {
+            let name = match other {
+                ReadableRe::Raw(_) | ReadableRe::String(_) => "raw",
+                ReadableRe::Trie(_) => "either words",
+                ReadableRe::Chars(_) => "chars",
+                ReadableRe::NotChars(_) => "not chars",
+                _ => describe::leaf(other).map_or("expression", |leaf| leaf.name),
+            };
+            (format!("{name} {other}"), vec![])
+        }
+    };
+    let _ = writeln!(out, "    n{id} [label=\"{}\"];", dot_escape(&label));
+    for child in children {
+        let child_id = dot_node(child, next_id, out);
+        let _ = writeln!(out, "    n{id} -> n{child_id};");
+    }
+    id
+}
+
+fn range_text(ranged: &solvers::Ranged) -> String {
+    match ranged.min_max() {
+        (min, Some(max)) => format!("{min}-{max}"),
+        (min, None) => format!("{min}+"),
+    }
+}
+
+fn text_width(text: &str) -> usize {
+    text.chars().count() * CHAR_WIDTH
+}
+
+fn line(from_x: usize, y: usize, to_x: usize, out: &mut String) {
+    if to_x > from_x {
+        let _ = write!(out, r#"<path d="M{from_x} {y}H{to_x}"/>"#);
+    }
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn dot_escape(s: &str) -> String {
+    s.replace('\\', r"\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ReadableRe::*;
+    use crate::*;
+
+    #[test]
+    fn railroad_svg() {
+        let re = starts_and_ends_with(
+            named_group("area", exactly(3, Digit))
+                + optional(either([Raw("-"), Period]))
+                + one_or_more(chars("a-z")),
+        );
+        let svg = re.railroad_svg();
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.ends_with("</svg>"));
+        assert!(svg.contains(">group &lt;area&gt;</text>"));
+        assert!(svg.contains(">3 times</text>"));
+        assert!(svg.contains(">one of [a-z]</text>"));
+        assert!(svg.contains(">.</text>"));
+        assert_eq!(svg.matches("<rect").count(), 7);
+    }
+
+    #[test]
+    fn dot() {
+        let re = group(Digit + either([Raw("a"), Raw("\"b\"")]));
+        assert_eq!(
+            re.to_dot(),
+            r#"digraph ReadableRe {
+    node [shape=box, fontname="monospace"];
+    n0 [label="group"];
+    n1 [label="concat"];
+    n2 [label="digit \\d"];
+    n1 -> n2;
+    n3 [label="either"];
+    n4 [label="raw a"];
+    n3 -> n4;
+    n5 [label="raw \"b\""];
+    n3 -> n5;
+    n1 -> n3;
+    n0 -> n1;
+}
+"#
+        );
+    }
+}
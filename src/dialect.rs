@@ -0,0 +1,277 @@
+//! Render [`ReadableRe`] expressions for other regex engines.
+//!
+//! The [`Display`] implementation of [`ReadableRe`] targets the backend used by
+//! [`ReadableRe::compile`]. [`ReadableRe::render`] emits the same expression for a given
+//! [`Dialect`] instead, or fails when a construct has no equivalent in it.
+//!
+//! ```
+//! use readable_regex::{digit, either, exactly, named_group, Dialect};
+//! let re = named_group("area", exactly(3, digit()));
+//! assert_eq!(re.render(Dialect::Rust).unwrap(), r"(?P<area>\d{3})");
+//! assert_eq!(re.render(Dialect::JavaScript).unwrap(), r"(?<area>\d{3})");
+//! assert!(re.render(Dialect::PostgreSql).is_err());
+//! ```
+//!
+//! [`Raw`](ReadableRe::Raw) and [`String`](ReadableRe::String) fragments are emitted verbatim.
+
+use crate::{solvers, ReadableRe};
+use std::fmt::{Display, Formatter};
+
+/// Regex engines syntaxes supported by [`ReadableRe::render`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Dialect {
+    /// [`regex`](https://docs.rs/regex) crate syntax
+    Rust,
+    /// PCRE/Perl syntax, also used by [`fancy_regex`](https://docs.rs/fancy-regex)
+    Pcre,
+    /// ECMAScript `RegExp` syntax, valid with and without the `u` flag
+    JavaScript,
+    /// Python `re` module syntax (3.11+ for atomic groups)
+    Python,
+    /// PostgreSQL advanced regular expressions
+    PostgreSql,
+    /// Go `regexp` package (RE2) syntax
+    Go,
+}
+
+impl Dialect {
+    pub const ALL: [Dialect; 6] = [
+        Dialect::Rust,
+        Dialect::Pcre,
+        Dialect::JavaScript,
+        Dialect::Python,
+        Dialect::PostgreSql,
+        Dialect::Go,
+    ];
+
+    pub fn supports_look_ahead(&self) -> bool {
+        !matches!(self, Dialect::Rust | Dialect::Go)
+    }
+
+    pub fn supports_look_behind(&self) -> bool {
+        !matches!(self, Dialect::Rust | Dialect::Go)
+    }
+
+    pub fn supports_atomic_group(&self) -> bool {
+        matches!(self, Dialect::Pcre | Dialect::Python)
+    }
+
+    pub fn supports_back_reference(&self) -> bool {
+        !matches!(self, Dialect::Rust | Dialect::Go)
+    }
+
+    pub fn supports_named_group(&self) -> bool {
+        !matches!(self, Dialect::PostgreSql)
+    }
+
+    /// Scoped inline flags, `"(?i:expr)"`
+    pub fn supports_inline_flags(&self) -> bool {
+        !matches!(self, Dialect::JavaScript | Dialect::PostgreSql)
+    }
+
+    fn boundary(&self) -> &'static str {
+        match self {
+            Dialect::PostgreSql => r"\y",
+            _ => r"\b",
+        }
+    }
+
+    /// Escape a literal string, `in_class` tells if it is placed inside a character class
+    pub fn escape(&self, s: &str, in_class: bool) -> String {
+        match self {
+            Dialect::JavaScript => {
+                let mut escaped = String::with_capacity(s.len());
+                for c in s.chars() {
+                    if r"\^$.*+?()[]{}|/".contains(c) || (in_class && c == '-') {
+                        escaped.push('\\');
+                    }
+                    escaped.push(c);
+                }
+                escaped
+            }
+            _ => regex::escape(s),
+        }
+    }
+
+    fn unsupported(self, construct: &'static str) -> DialectError {
+        DialectError::Unsupported {
+            construct,
+            dialect: self,
+        }
+    }
+}
+
+impl Display for Dialect {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Dialect::Rust => "Rust",
+            Dialect::Pcre => "PCRE",
+            Dialect::JavaScript => "JavaScript",
+            Dialect::Python => "Python",
+            Dialect::PostgreSql => "PostgreSQL",
+            Dialect::Go => "Go",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Error rendering a [`ReadableRe`] for a [`Dialect`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DialectError {
+    /// The construct has no equivalent in the dialect
+    Unsupported {
+        construct: &'static str,
+        dialect: Dialect,
+    },
+}
+
+impl Display for DialectError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DialectError::Unsupported { construct, dialect } => {
+                write!(f, "{} are not supported by {} regexes", construct, dialect)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DialectError {}
+
+pub(crate) fn render(
+    re: &ReadableRe,
+    dialect: Dialect,
+    out: &mut String,
+) -> Result<(), DialectError> {
+    match re {
+        ReadableRe::Boundary => out.push_str(dialect.boundary()),
+        ReadableRe::MinusSign if dialect == Dialect::JavaScript => out.push('-'),
+        ReadableRe::Quote if dialect == Dialect::JavaScript => out.push('\''),
+        ReadableRe::DoubleQuote if dialect == Dialect::JavaScript => out.push('"'),
+        #[cfg(feature = "re-fancy")]
+        ReadableRe::Back1
+        | ReadableRe::Back2
+        | ReadableRe::Back3
+        | ReadableRe::Back4
+        | ReadableRe::Back5
+        | ReadableRe::Back6
+        | ReadableRe::Back7
+        | ReadableRe::Back8
+        | ReadableRe::Back9
+        | ReadableRe::BackReference(_) => {
+            if !dialect.supports_back_reference() {
+                return Err(dialect.unsupported("back references"));
+            }
+            out.push_str(&re.to_string());
+        }
+        ReadableRe::Concat(solvers::Concat(v)) => {
+            for re in v {
+                render(re, dialect, out)?;
+            }
+        }
+        ReadableRe::Escape(escape) => {
+            let mut inner = String::new();
+            render(&escape.0, dialect, &mut inner)?;
+            out.push_str(&dialect.escape(&inner, false));
+        }
+        ReadableRe::Group(group) => wrap(&group.0, dialect, "(", ")", out)?,
+        #[cfg(feature = "re-fancy")]
+        ReadableRe::PositiveLookAhead(look_ahead) => {
+            if !dialect.supports_look_ahead() {
+                return Err(dialect.unsupported("look aheads"));
+            }
+            wrap(&look_ahead.0, dialect, "(?=", ")", out)?
+        }
+        #[cfg(feature = "re-fancy")]
+        ReadableRe::NegativeLookAhead(look_ahead) => {
+            if !dialect.supports_look_ahead() {
+                return Err(dialect.unsupported("look aheads"));
+            }
+            wrap(&look_ahead.0, dialect, "(?!", ")", out)?
+        }
+        #[cfg(feature = "re-fancy")]
+        ReadableRe::PositiveLookBehind(look_behind) => {
+            if !dialect.supports_look_behind() {
+                return Err(dialect.unsupported("look behinds"));
+            }
+            wrap(&look_behind.0, dialect, "(?<=", ")", out)?
+        }
+        #[cfg(feature = "re-fancy")]
+        ReadableRe::NegativeLookBehind(look_behind) => {
+            if !dialect.supports_look_behind() {
+                return Err(dialect.unsupported("look behinds"));
+            }
+            wrap(&look_behind.0, dialect, "(?<!", ")", out)?
+        }
+        ReadableRe::NamedGroup(named_group) => {
+            if !dialect.supports_named_group() {
+                return Err(dialect.unsupported("named groups"));
+            }
+            let open = match dialect {
+                Dialect::JavaScript => format!("(?<{}>", named_group.name),
+                _ => format!("(?P<{}>", named_group.name),
+            };
+            wrap(&named_group.regexes, dialect, &open, ")", out)?
+        }
+        ReadableRe::NonCaptureGroup(group) => wrap(&group.0, dialect, "(?:", ")", out)?,
+        ReadableRe::Optional(optional) => wrap(&optional.0, dialect, "", "?", out)?,
+        ReadableRe::OptionalLazy(optional) => wrap(&optional.0, dialect, "", "??", out)?,
+        ReadableRe::Either(either) => {
+            for (i, re) in either.alternatives().enumerate() {
+                if i > 0 {
+                    out.push('|');
+                }
+                render(re, dialect, out)?;
+            }
+        }
+        ReadableRe::Trie(trie) => {
+            let escape = |c: char, in_class| dialect.escape(&c.to_string(), in_class);
+            out.push_str(&trie.render_with(
+                &escape,
+                dialect.boundary(),
+                dialect.supports_inline_flags(),
+            ));
+        }
+        ReadableRe::Exactly(exactly) => {
+            let close = format!("{{{}}}", exactly.quantity);
+            wrap(&exactly.re, dialect, "", &close, out)?
+        }
+        ReadableRe::Ranged(ranged) => {
+            let close = match ranged.min_max() {
+                (min, Some(max)) => format!("{{{},{}}}", min, max),
+                (min, None) => format!("{{{},}}", min),
+            };
+            wrap(&ranged.re, dialect, "", &close, out)?
+        }
+        ReadableRe::ZeroOrMore(zero_or_more) => wrap(&zero_or_more.0, dialect, "", "*", out)?,
+        ReadableRe::ZeroOrMoreLazy(zero_or_more) => wrap(&zero_or_more.0, dialect, "", "*?", out)?,
+        ReadableRe::OneOrMore(one_or_more) => wrap(&one_or_more.0, dialect, "", "+", out)?,
+        ReadableRe::OneOrMoreLazy(one_or_more) => wrap(&one_or_more.0, dialect, "", "+?", out)?,
+        ReadableRe::StartsWith(starts_with) => wrap(&starts_with.0, dialect, "^", "", out)?,
+        ReadableRe::EndsWith(ends_with) => wrap(&ends_with.0, dialect, "", "$", out)?,
+        ReadableRe::StartsAndEndsWith(starts_and_ends_with) => {
+            render(&starts_and_ends_with.0, dialect, out)?
+        }
+        #[cfg(feature = "re-fancy")]
+        ReadableRe::AtomicGroup(atomic_group) => {
+            if !dialect.supports_atomic_group() {
+                return Err(dialect.unsupported("atomic groups"));
+            }
+            wrap(&atomic_group.0, dialect, "(?>", ")", out)?
+        }
+        other => out.push_str(&other.to_string()),
+    }
+    Ok(())
+}
+
+fn wrap(
+    re: &ReadableRe,
+    dialect: Dialect,
+    open: &str,
+    close: &str,
+    out: &mut String,
+) -> Result<(), DialectError> {
+    out.push_str(open);
+    render(re, dialect, out)?;
+    out.push_str(close);
+    Ok(())
+}
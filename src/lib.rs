@@ -70,16 +70,33 @@
 //! assert!(DATE_Y_M_D.compile().unwrap().is_match("2022/04/18"));
 //! ```
 //!
+//! ### Other regex engines
+//! Expressions can be rendered for other engines syntax with [`ReadableRe::render`],
+//! check the [`dialect`] module.
+//!
 //! ### Features
 //! * `re` => Use [`regex`] crate backend.
 //! * `re-fancy` => Use [`fancy_regex`] crate backend and expands this crate functionality.
+//! * `presets` => Ready to use expressions, check the `presets` module.
+//! * `diagram` => Railroad SVG diagrams and Graphviz DOT export of expressions, check the `diagram` module.
+//! * `parse` => Parse regex strings into [`ReadableRe`] trees, check the `parse` module.
+//! * `cli` => Build the `readable-regex` command line tool, including an interactive `repl`.
 
 mod constants;
+pub mod describe;
+#[cfg(feature = "diagram")]
+pub mod diagram;
+pub mod dialect;
+#[cfg(feature = "parse")]
+pub mod parse;
 #[cfg(feature = "presets")]
 pub mod presets;
+pub mod range;
 pub mod readable;
 pub mod solvers;
 
+pub use dialect::Dialect;
+pub use range::{LeadingZeros, RangeOptions};
 pub use readable::ReadableRe;
 use std::ops::RangeBounds;
 
@@ -300,7 +317,7 @@ pub const fn back9<'a>() -> ReadableRe<'a> {
     ReadableRe::Back9
 }
 
-pub const fn raw_regex(s: &str) -> ReadableRe {
+pub const fn raw_regex(s: &str) -> ReadableRe<'_> {
     ReadableRe::Raw(s)
 }
 
@@ -317,7 +334,7 @@ pub const fn back_reference<'a>(n: usize) -> ReadableRe<'a> {
     ReadableRe::BackReference(solvers::BackReference(n))
 }
 
-pub fn escape_str(s: &str) -> ReadableRe {
+pub fn escape_str(s: &str) -> ReadableRe<'_> {
     ReadableRe::Escape(solvers::Escape::new_str(s))
 }
 
@@ -361,10 +378,18 @@ pub fn optional(re: ReadableRe) -> ReadableRe {
     ReadableRe::Optional(solvers::Optional::new(re))
 }
 
+pub fn optional_lazy(re: ReadableRe) -> ReadableRe {
+    ReadableRe::OptionalLazy(solvers::OptionalLazy::new(re))
+}
+
 pub fn either<'a>(iter: impl IntoIterator<Item = ReadableRe<'a>>) -> ReadableRe<'a> {
     ReadableRe::Either(solvers::Either::new(iter))
 }
 
+pub fn either_words<'a, S: AsRef<str>>(words: impl IntoIterator<Item = S>) -> ReadableRe<'a> {
+    ReadableRe::Trie(solvers::Trie::new(words))
+}
+
 pub fn exactly(n: usize, re: ReadableRe) -> ReadableRe {
     ReadableRe::Exactly(solvers::Exactly::new(n, re))
 }
@@ -412,7 +437,14 @@ pub fn starts_and_ends_with(re: ReadableRe) -> ReadableRe {
     ReadableRe::StartsAndEndsWith(solvers::StartsAndEndsWith::new(re))
 }
 
-pub fn chars(re: &str) -> ReadableRe {
+/// Integers from `min` to `max`, both included, check the [`range`] module for the options
+///
+/// Panics if `min > max`
+pub fn numeric_range<'a>(min: i64, max: i64, options: RangeOptions) -> ReadableRe<'a> {
+    range::numeric_range(min, max, options)
+}
+
+pub fn chars(re: &str) -> ReadableRe<'_> {
     ReadableRe::Chars(solvers::Chars::new(re))
 }
 
@@ -0,0 +1,265 @@
+//! Parse regex strings back into [`ReadableRe`] trees.
+//!
+//! Parsing uses the [`regex_syntax`] crate, so only the syntax of the [`regex`] crate is supported
+//! (no look arounds nor back references). Fragments of the pattern that have no [`ReadableRe`]
+//! equivalent, like inline flags or lazy bounded repetitions, are kept as [`ReadableRe::Raw`] slices.
+//!
+//! ```
+//! use readable_regex::parse::parse;
+//! let re = parse(r"^(?P<area>\d{3})-[a-z]+$").unwrap();
+//! assert_eq!(re.to_string(), r"^(?P<area>\d{3})-[a-z]+$");
+//! ```
+//!
+//! Available with feature `"parse"`
+
+use crate::{constants, solvers, ReadableRe};
+use regex_syntax::ast::{self, Ast};
+
+/// Parse a regex string into an equivalent [`ReadableRe`], borrowing from the pattern
+///
+/// Available with feature `"parse"`
+#[allow(clippy::result_large_err)]
+pub fn parse(pattern: &str) -> Result<ReadableRe<'_>, ast::Error> {
+    let ast = ast::parse::Parser::new().parse(pattern)?;
+    Ok(Converter { pattern }.convert(&ast))
+}
+
+struct Converter<'a> {
+    pattern: &'a str,
+}
+
+impl<'a> Converter<'a> {
+    fn slice(&self, span: &ast::Span) -> &'a str {
+        &self.pattern[span.start.offset..span.end.offset]
+    }
+
+    fn convert(&self, ast: &Ast) -> ReadableRe<'a> {
+        match ast {
+            Ast::Empty(_) => ReadableRe::Concat(solvers::Concat::new([])),
+            Ast::Literal(literal) => self.literal(literal),
+            Ast::Dot(_) => ReadableRe::AnyChar,
+            Ast::Assertion(assertion) => match assertion.kind {
+                ast::AssertionKind::WordBoundary => ReadableRe::Boundary,
+                _ => ReadableRe::Raw(self.slice(&assertion.span)),
+            },
+            Ast::ClassPerl(class) => match (&class.kind, class.negated) {
+                (ast::ClassPerlKind::Digit, false) => ReadableRe::Digit,
+                (ast::ClassPerlKind::Digit, true) => ReadableRe::NonDigit,
+                (ast::ClassPerlKind::Word, false) => ReadableRe::Word,
+                (ast::ClassPerlKind::Word, true) => ReadableRe::NonWord,
+                (ast::ClassPerlKind::Space, false) => ReadableRe::Whitespace,
+                (ast::ClassPerlKind::Space, true) => ReadableRe::NonWhitespace,
+            },
+            Ast::ClassBracketed(class) => self.class(class),
+            Ast::Repetition(repetition) => self.repetition(repetition),
+            Ast::Group(group) => self.group(group),
+            Ast::Alternation(alternation) => ReadableRe::Either(solvers::Either::new(
+                alternation.asts.iter().map(|ast| self.convert(ast)),
+            )),
+            Ast::Concat(concat) => self.concat(&concat.asts),
+            Ast::Flags(_) | Ast::ClassUnicode(_) => ReadableRe::Raw(self.slice(ast.span())),
+        }
+    }
+
+    fn literal(&self, literal: &ast::Literal) -> ReadableRe<'a> {
+        match (&literal.kind, literal.c) {
+            (ast::LiteralKind::Meta | ast::LiteralKind::Superfluous, c) => match c {
+                '.' => ReadableRe::Period,
+                '^' => ReadableRe::Caret,
+                '$' => ReadableRe::Dollar,
+                '*' => ReadableRe::Asterisk,
+                '+' => ReadableRe::PlusSign,
+                '-' => ReadableRe::MinusSign,
+                '?' => ReadableRe::QuestionMark,
+                '{' => ReadableRe::OpenBrace,
+                '}' => ReadableRe::CloseBrace,
+                '[' => ReadableRe::OpenBracket,
+                ']' => ReadableRe::CloseBracket,
+                '(' => ReadableRe::OpenParenthesis,
+                ')' => ReadableRe::CloseParenthesis,
+                '\\' => ReadableRe::BackSlash,
+                '|' => ReadableRe::Pipe,
+                '\'' => ReadableRe::Quote,
+                '"' => ReadableRe::DoubleQuote,
+                _ => ReadableRe::Raw(self.slice(&literal.span)),
+            },
+            (ast::LiteralKind::Special(ast::SpecialLiteralKind::LineFeed), _) => {
+                ReadableRe::Newline
+            }
+            (ast::LiteralKind::Special(ast::SpecialLiteralKind::Tab), _) => ReadableRe::Tab,
+            _ => ReadableRe::Raw(self.slice(&literal.span)),
+        }
+    }
+
+    fn class(&self, class: &ast::ClassBracketed) -> ReadableRe<'a> {
+        let source = self.slice(&class.span);
+        let constant = [
+            (constants::ASCII_LETTER, ReadableRe::AsciiLetter),
+            (constants::ASCII_NON_LETTER, ReadableRe::AsciiNonLetter),
+            (constants::ASCII_UPPERCASE, ReadableRe::AsciiUppercase),
+            (
+                constants::ASCII_NON_UPPERCASE,
+                ReadableRe::AsciiNonUppercase,
+            ),
+            (constants::ASCII_LOWERCASE, ReadableRe::AsciiLowercase),
+            (
+                constants::ASCII_NON_LOWERCASE,
+                ReadableRe::AsciiNonLowercase,
+            ),
+            (constants::ASCII_ALPHANUMERIC, ReadableRe::AsciiAlphanumeric),
+            (
+                constants::ASCII_NON_ALPHANUMERIC,
+                ReadableRe::AsciiNonAlphanumeric,
+            ),
+            (constants::ASCII_NUMERIC, ReadableRe::AsciiNumeric),
+            (constants::ASCII_NON_NUMERIC, ReadableRe::AsciiNonNumeric),
+            (constants::HEXADECIMAL, ReadableRe::Hexadecimal),
+            (constants::NON_HEXADECIMAL, ReadableRe::NonHexadecimal),
+        ]
+        .into_iter()
+        .find_map(|(constant, re)| (constant == source).then_some(re));
+        if let Some(re) = constant {
+            return re;
+        }
+        let skip = if class.negated { 2 } else { 1 };
+        let inner = &source[skip..source.len() - 1];
+        if class.negated {
+            ReadableRe::NotChars(solvers::NotChars::new(inner))
+        } else {
+            ReadableRe::Chars(solvers::Chars::new(inner))
+        }
+    }
+
+    fn repetition(&self, repetition: &ast::Repetition) -> ReadableRe<'a> {
+        let re = self.convert(&repetition.ast);
+        match (&repetition.op.kind, repetition.greedy) {
+            (ast::RepetitionKind::ZeroOrOne, true) => {
+                ReadableRe::Optional(solvers::Optional::new(re))
+            }
+            (ast::RepetitionKind::ZeroOrOne, false) => {
+                ReadableRe::OptionalLazy(solvers::OptionalLazy::new(re))
+            }
+            (ast::RepetitionKind::ZeroOrMore, true) => {
+                ReadableRe::ZeroOrMore(solvers::ZeroOrMore::new(re))
+            }
+            (ast::RepetitionKind::ZeroOrMore, false) => {
+                ReadableRe::ZeroOrMoreLazy(solvers::ZeroOrMoreLazy::new(re))
+            }
+            (ast::RepetitionKind::OneOrMore, true) => {
+                ReadableRe::OneOrMore(solvers::OneOrMore::new(re))
+            }
+            (ast::RepetitionKind::OneOrMore, false) => {
+                ReadableRe::OneOrMoreLazy(solvers::OneOrMoreLazy::new(re))
+            }
+            (ast::RepetitionKind::Range(ast::RepetitionRange::Exactly(n)), _) => {
+                ReadableRe::Exactly(solvers::Exactly::new(*n as usize, re))
+            }
+            (ast::RepetitionKind::Range(ast::RepetitionRange::AtLeast(n)), true) => {
+                ReadableRe::Ranged(solvers::Ranged::new(*n as usize.., re))
+            }
+            (ast::RepetitionKind::Range(ast::RepetitionRange::Bounded(min, max)), true) => {
+                ReadableRe::Ranged(solvers::Ranged::new(*min as usize..=*max as usize, re))
+            }
+            _ => re + ReadableRe::Raw(self.slice(&repetition.op.span)),
+        }
+    }
+
+    fn group(&self, group: &ast::Group) -> ReadableRe<'a> {
+        let re = self.convert(&group.ast);
+        match &group.kind {
+            ast::GroupKind::CaptureIndex(_) => ReadableRe::Group(solvers::Group::new(re)),
+            ast::GroupKind::CaptureName { name, .. } => {
+                ReadableRe::NamedGroup(solvers::NamedGroup::new(self.slice(&name.span), re))
+            }
+            ast::GroupKind::NonCapturing(flags) if flags.items.is_empty() => {
+                ReadableRe::NonCaptureGroup(solvers::NonCaptureGroup::new(re))
+            }
+            ast::GroupKind::NonCapturing(flags) => {
+                let open = &self.pattern[group.span.start.offset..flags.span.end.offset + 1];
+                ReadableRe::Raw(open) + re + ReadableRe::Raw(")")
+            }
+        }
+    }
+
+    fn concat(&self, asts: &[Ast]) -> ReadableRe<'a> {
+        let is_assertion = |ast: Option<&Ast>, kinds: [ast::AssertionKind; 2]| matches!(ast, Some(Ast::Assertion(assertion)) if kinds.contains(&assertion.kind));
+        let starts = is_assertion(
+            asts.first(),
+            [ast::AssertionKind::StartText, ast::AssertionKind::StartLine],
+        );
+        let ends = asts.len() > 1
+            && is_assertion(
+                asts.last(),
+                [ast::AssertionKind::EndText, ast::AssertionKind::EndLine],
+            );
+        let inner = &asts[starts as usize..asts.len() - ends as usize];
+        let re = ReadableRe::Concat(solvers::Concat::new(self.merge_literals(inner)));
+        match (starts, ends) {
+            (true, true) => ReadableRe::StartsAndEndsWith(solvers::StartsAndEndsWith::new(re)),
+            (true, false) => ReadableRe::StartsWith(solvers::StartsWith::new(re)),
+            (false, true) => ReadableRe::EndsWith(solvers::EndsWith::new(re)),
+            (false, false) => re,
+        }
+    }
+
+    /// Convert the asts, joining runs of plain literals into a single [`ReadableRe::Raw`]
+    fn merge_literals(&self, asts: &[Ast]) -> Vec<ReadableRe<'a>> {
+        let mut result = Vec::new();
+        let mut run: Option<(usize, usize)> = None;
+        for ast in asts {
+            match ast {
+                Ast::Literal(literal) if literal.kind == ast::LiteralKind::Verbatim => {
+                    let span = (literal.span.start.offset, literal.span.end.offset);
+                    run = Some(run.map_or(span, |(start, _)| (start, span.1)));
+                }
+                other => {
+                    if let Some((start, end)) = run.take() {
+                        result.push(ReadableRe::Raw(&self.pattern[start..end]));
+                    }
+                    result.push(self.convert(other));
+                }
+            }
+        }
+        if let Some((start, end)) = run {
+            result.push(ReadableRe::Raw(&self.pattern[start..end]));
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+
+    #[test]
+    fn round_trip() {
+        let patterns = [
+            r"\d\d\d-\d\d\d-\d\d\d\d",
+            r"((\d{3}|\(\d{3}\))?(\s|-|\.)?\d{3}(\s|-|\.)\d{4}(\s*(ext|x|ext.)\s*\d{2,5})?)",
+            r"^[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}$",
+            r"(?i:foo|bar)+?baz*?[^abc]\b(?:x)",
+            r"(?P<year>[12]\d{3})-(?P<month>0[1-9]|1[0-2])",
+            r"a{2,}b{3}c??",
+            "",
+        ];
+        for pattern in patterns {
+            let re = parse(pattern).unwrap();
+            assert_eq!(re.to_string(), pattern);
+        }
+    }
+
+    #[test]
+    fn structure() {
+        use crate::ReadableRe;
+        let re = parse(r"^ab\.[A-Za-z]$").unwrap();
+        let ReadableRe::StartsAndEndsWith(_) = &re else {
+            panic!("expected starts and ends with");
+        };
+        assert_eq!(re.to_string(), r"^ab\.[A-Za-z]$");
+        assert!(matches!(
+            parse("[0-9A-Fa-f]").unwrap(),
+            ReadableRe::Hexadecimal
+        ));
+        assert!(parse("(unclosed").is_err());
+    }
+}
@@ -0,0 +1,282 @@
+//! Check digit algorithms, to validate what the presets can't check by themselves
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// Preset paired with the check digit algorithm of what it matches
+pub struct Validator {
+    format: Lazy<Regex>,
+    checksum: fn(&str) -> bool,
+}
+
+impl Validator {
+    pub const fn new(format: fn() -> Regex, checksum: fn(&str) -> bool) -> Self {
+        Self {
+            format: Lazy::new(format),
+            checksum,
+        }
+    }
+
+    /// Whether the text matches the preset and passes its checksum
+    pub fn is_valid(&self, text: &str) -> bool {
+        self.format.is_match(text) && (self.checksum)(text)
+    }
+}
+
+/// Decimal digits of the text, skipping spaces and hyphens. `None` when there is any other
+/// character or no digit at all.
+fn digits(text: &str) -> Option<Vec<u32>> {
+    let digits: Option<Vec<u32>> = text
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '-'))
+        .map(|c| c.to_digit(10))
+        .collect();
+    digits.filter(|digits| !digits.is_empty())
+}
+
+/// [Luhn](https://en.wikipedia.org/wiki/Luhn_algorithm) check of the digits, the last one being
+/// the check digit. Spaces and hyphens are ignored.
+///
+/// ```
+/// use readable_regex::presets::checksum::luhn;
+/// assert!(luhn("49-015420-323751-8"));
+/// assert!(!luhn("49-015420-323751-9"));
+/// ```
+pub fn luhn(text: &str) -> bool {
+    let Some(digits) = digits(text) else {
+        return false;
+    };
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &d)| match (i % 2, d * 2) {
+            (0, _) => d,
+            (_, doubled) if doubled > 9 => doubled - 9,
+            (_, doubled) => doubled,
+        })
+        .sum();
+    sum.is_multiple_of(10)
+}
+
+/// Value of an alphanumeric character, `0`-`9` and then `A` = 10 to `Z` = 35
+fn alphanumeric_value(c: char) -> Option<u32> {
+    c.to_digit(36)
+        .filter(|_| c.is_ascii_digit() || c.is_ascii_uppercase())
+}
+
+/// [ISO 7064](https://en.wikipedia.org/wiki/ISO/IEC_7064) MOD 97-10 check of uppercase letters
+/// and digits, letters counting as two digits `A` = 10 to `Z` = 35. Spaces are ignored.
+///
+/// ```
+/// use readable_regex::presets::checksum::mod97;
+/// assert!(mod97("5493001KJTIIGC8Y1R12"));
+/// assert!(!mod97("5493001KJTIIGC8Y1R13"));
+/// ```
+pub fn mod97(text: &str) -> bool {
+    let mut remainder = 0;
+    let mut empty = true;
+    for c in text.chars().filter(|&c| c != ' ') {
+        let Some(value) = alphanumeric_value(c) else {
+            return false;
+        };
+        remainder = (remainder * if value > 9 { 100 } else { 10 } + value) % 97;
+        empty = false;
+    }
+    !empty && remainder == 1
+}
+
+/// IBAN check: [`mod97`] of the number with its first four characters moved to the end
+///
+/// ```
+/// use readable_regex::presets::checksum::iban;
+/// assert!(iban("DE89 3704 0044 0532 0130 00"));
+/// assert!(!iban("DE88 3704 0044 0532 0130 00"));
+/// ```
+pub fn iban(text: &str) -> bool {
+    let compact: String = text.chars().filter(|&c| c != ' ').collect();
+    compact.len() > 4
+        && compact.is_char_boundary(4)
+        && mod97(&format!("{}{}", &compact[4..], &compact[..4]))
+}
+
+/// ISIN check: [`luhn`] of the digits, letters counting as two digits `A` = 10 to `Z` = 35
+///
+/// ```
+/// use readable_regex::presets::checksum::isin;
+/// assert!(isin("US0378331005"));
+/// assert!(!isin("US0378331006"));
+/// ```
+pub fn isin(text: &str) -> bool {
+    let digits: Option<String> = text
+        .chars()
+        .map(|c| alphanumeric_value(c).map(|value| value.to_string()))
+        .collect();
+    digits.is_some_and(|digits| luhn(&digits))
+}
+
+/// CUSIP check digit, the last of nine characters, letters counting `A` = 10 to `Z` = 35 and
+/// `*`, `@` and `#` 36 to 38
+///
+/// ```
+/// use readable_regex::presets::checksum::cusip;
+/// assert!(cusip("037833100"));
+/// assert!(!cusip("037833101"));
+/// ```
+pub fn cusip(text: &str) -> bool {
+    let values: Option<Vec<u32>> = text
+        .chars()
+        .map(|c| match c {
+            '*' => Some(36),
+            '@' => Some(37),
+            '#' => Some(38),
+            _ => alphanumeric_value(c),
+        })
+        .collect();
+    let Some(values) = values.filter(|values| values.len() == 9) else {
+        return false;
+    };
+    let sum: u32 = values[..8]
+        .iter()
+        .enumerate()
+        .map(|(i, &value)| if i % 2 == 1 { value * 2 } else { value })
+        .map(|value| value / 10 + value % 10)
+        .sum();
+    (10 - sum % 10) % 10 == values[8]
+}
+
+/// Control letters of the Spanish identity numbers, by the number modulo 23
+const SPANISH_CONTROL_LETTERS: &[u8; 23] = b"TRWAGMYFPDXBNJZSQVHLCKE";
+
+/// Spanish DNI check: the control letter of the eight digits, hyphens and spaces ignored
+///
+/// ```
+/// use readable_regex::presets::checksum::dni;
+/// assert!(dni("12345678-Z"));
+/// assert!(!dni("12345678-A"));
+/// ```
+pub fn dni(text: &str) -> bool {
+    let compact: Vec<char> = text.chars().filter(|c| !matches!(c, ' ' | '-')).collect();
+    let Some((&letter, number)) = compact.split_last() else {
+        return false;
+    };
+    let number: Option<u32> = (number.len() == 8)
+        .then(|| number.iter().collect::<String>().parse().ok())
+        .flatten();
+    number.is_some_and(|number| SPANISH_CONTROL_LETTERS[number as usize % 23] as char == letter)
+}
+
+/// Spanish NIE check: [`dni`] of the number with its `X`, `Y` or `Z` prefix as `0`, `1` or `2`
+///
+/// ```
+/// use readable_regex::presets::checksum::nie;
+/// assert!(nie("X1234567L"));
+/// assert!(!nie("X1234567T"));
+/// ```
+pub fn nie(text: &str) -> bool {
+    let mut chars = text.chars();
+    let prefix = match chars.next() {
+        Some('X') => '0',
+        Some('Y') => '1',
+        Some('Z') => '2',
+        _ => return false,
+    };
+    dni(&format!("{prefix}{}", chars.as_str()))
+}
+
+/// Dutch "elfproef" (eleven test) of the nine digits of a BSN: the digits weighted 9 to 2, and
+/// the last one -1, sum to a multiple of 11. Spaces, hyphens and dots are ignored.
+///
+/// ```
+/// use readable_regex::presets::checksum::eleven_test;
+/// assert!(eleven_test("111222333"));
+/// assert!(!eleven_test("111222334"));
+/// ```
+pub fn eleven_test(text: &str) -> bool {
+    let digits: Option<Vec<i32>> = text
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '.'))
+        .map(|c| c.to_digit(10).map(|d| d as i32))
+        .collect();
+    let Some(digits) = digits.filter(|digits| digits.len() == 9) else {
+        return false;
+    };
+    let sum: i32 = digits
+        .iter()
+        .zip([9, 8, 7, 6, 5, 4, 3, 2, -1])
+        .map(|(d, weight)| d * weight)
+        .sum();
+    sum % 11 == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::presets::checksum::{cusip, dni, eleven_test, iban, isin, luhn, mod97, nie};
+
+    #[test]
+    fn luhn_check() {
+        for v in [
+            "0",
+            "18",
+            "79927398713",
+            "490154203237518",
+            "4111 1111 1111 1111",
+        ] {
+            assert!(luhn(v), "Failed checking: {}", v);
+        }
+        for v in ["", "-", "1", "79927398710", "4111 1111 1111 1112", "12a"] {
+            assert!(!luhn(v), "Wrongly checked: {}", v);
+        }
+    }
+
+    #[test]
+    fn mod97_check() {
+        for v in [
+            "GB82 WEST 1234 5698 7654 32",
+            "NO9386011117947",
+            "BE68539007547034",
+        ] {
+            assert!(iban(v), "Failed checking: {}", v);
+        }
+        for v in [
+            "",
+            "GB82",
+            "GB83 WEST 1234 5698 7654 32",
+            "gb82west12345698765432",
+        ] {
+            assert!(!iban(v), "Wrongly checked: {}", v);
+        }
+        assert!(mod97("529900T8BM49AURSDO55"));
+        assert!(!mod97("529900T8BM49AURSDO-55"));
+    }
+
+    #[test]
+    fn securities_check() {
+        for v in ["US0378331005", "GB0002634946", "AU0000XVGZA3"] {
+            assert!(isin(v), "Failed checking: {}", v);
+        }
+        assert!(!isin("US0378331015"));
+        assert!(!isin("us0378331005"));
+        for v in ["037833100", "38259P508", "594918104"] {
+            assert!(cusip(v), "Failed checking: {}", v);
+        }
+        for v in ["037833101", "03783310", "0378331000"] {
+            assert!(!cusip(v), "Wrongly checked: {}", v);
+        }
+    }
+
+    #[test]
+    fn national_id_check() {
+        for v in ["12345678Z", "00000000T", "99999999R"] {
+            assert!(dni(v), "Failed checking: {}", v);
+        }
+        for v in ["12345678", "1234567Z", "12345678z", "X1234567L"] {
+            assert!(!dni(v), "Wrongly checked: {}", v);
+        }
+        assert!(nie("Y-1234567-X"));
+        assert!(nie("Z1234567R"));
+        assert!(!nie("A1234567L"));
+        assert!(eleven_test("123456782"));
+        assert!(!eleven_test("12345678"));
+        assert!(!eleven_test("1234567820"));
+    }
+}
@@ -0,0 +1,551 @@
+//! Programming language tokens: identifiers, string literals, comments, number literals and
+//! version constraints
+use crate::presets::identifiers::{dot_separated, prerelease, version_number};
+use crate::presets::numeric::{c_float, c_integer, digits_of, exponent, rust_float, rust_integer};
+use crate::ReadableRe::{self, Raw};
+use crate::{
+    chars, either, exactly, named_group, non_capture_group, not_chars, one_or_more, optional,
+    ranged, starts_and_ends_with, zero_or_more,
+};
+use once_cell::sync::Lazy;
+
+/// Languages with known literal syntaxes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    C,
+    JavaScript,
+    Json,
+    Python,
+    Rust,
+}
+
+/// Identifier made of ASCII letters, digits and `_`, or of the `XID_Start` and `XID_Continue`
+/// characters of [UAX #31](https://www.unicode.org/reports/tr31/) when `unicode`, in both cases
+/// allowed to start with `_`
+pub fn identifier(unicode: bool) -> ReadableRe<'static> {
+    if unicode {
+        chars(r"\p{XID_Start}_") + zero_or_more(Raw(r"\p{XID_Continue}"))
+    } else {
+        chars("A-Za-z_") + zero_or_more(chars("A-Za-z0-9_"))
+    }
+}
+
+/// ASCII identifier `snake_case`, `_private`, `CamelCase2`
+pub const ASCII_IDENTIFIER: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(identifier(false)));
+
+/// UAX #31 identifier `größe`, `변수`
+pub const UNICODE_IDENTIFIER: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(identifier(true)));
+
+/// Rust identifier or raw identifier `r#type`, a lone `_` not being one. Keywords are not excluded.
+pub const RUST_IDENTIFIER: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(
+        optional(non_capture_group(Raw("r#")))
+            + non_capture_group(either([
+                Raw(r"\p{XID_Start}") + zero_or_more(Raw(r"\p{XID_Continue}")),
+                Raw("_") + one_or_more(Raw(r"\p{XID_Continue}")),
+            ])),
+    )
+});
+
+/// Double quoted string whose characters are `plain` or `\` escapes matching `escape`, with a
+/// `content` named group
+fn quoted(plain: ReadableRe<'static>, escape: ReadableRe<'static>) -> ReadableRe<'static> {
+    Raw("\"")
+        + named_group(
+            "content",
+            zero_or_more(non_capture_group(either([plain, Raw(r"\\") + escape]))),
+        )
+        + Raw("\"")
+}
+
+/// C string literal `u8"caf\xC3\xA9\n"`, with the optional encoding `prefix` and `content` named
+/// groups
+pub const C_STRING: Lazy<ReadableRe> = Lazy::new(|| {
+    let escape = non_capture_group(either([
+        chars(r#"'"?\\abfnrtv"#),
+        ranged(1..=3, chars("0-7")),
+        Raw("x") + one_or_more(chars("0-9a-fA-F")),
+        Raw("u") + exactly(4, chars("0-9a-fA-F")),
+        Raw("U") + exactly(8, chars("0-9a-fA-F")),
+    ]));
+    starts_and_ends_with(
+        optional(named_group(
+            "prefix",
+            either(["u8", "u", "U", "L"].map(Raw)),
+        )) + quoted(not_chars(r#""\\\n"#), escape),
+    )
+});
+
+/// Rust string literal, or byte or C string literal, `b"\x7F"`, `"\u{1F600}"`, with the optional
+/// `prefix` and `content` named groups. The escapes are not restricted by the prefix, and strings
+/// may span lines.
+pub const RUST_STRING: Lazy<ReadableRe> = Lazy::new(|| {
+    let escape = non_capture_group(either([
+        chars(r#"nrt\\0"'"#),
+        Raw("x") + exactly(2, chars("0-9a-fA-F")),
+        Raw(r"u\{") + ranged(1..=6, chars("0-9a-fA-F_")) + Raw(r"\}"),
+        Raw(r"\r?\n"),
+    ]));
+    starts_and_ends_with(
+        optional(named_group("prefix", chars("bc"))) + quoted(not_chars(r#""\\"#), escape),
+    )
+});
+
+/// Largest number of `#` of the raw strings matched by [`RUST_RAW_STRING`]
+pub const RAW_STRING_HASHES: usize = 8;
+
+/// Content of a raw string closed by `"` and `hashes` `#`, which never contains this closing
+/// sequence
+fn raw_content(hashes: usize) -> ReadableRe<'static> {
+    if hashes == 0 {
+        return zero_or_more(not_chars("\""));
+    }
+    // `"` followed by fewer `#` than the closing sequence
+    let quote = || non_capture_group(ReadableRe::String(format!("\"#{{0,{}}}", hashes - 1)));
+    zero_or_more(not_chars("\""))
+        + zero_or_more(non_capture_group(
+            one_or_more(quote()) + not_chars("\"#") + zero_or_more(not_chars("\"")),
+        ))
+        + zero_or_more(quote())
+}
+
+/// Rust raw string delimited by exactly `hashes` `#`, `r#"say "hi""#` for `1`, with a `content`
+/// named group
+pub fn rust_raw_string(hashes: usize) -> ReadableRe<'static> {
+    let delimiter = "#".repeat(hashes);
+    ReadableRe::String(format!("r{delimiter}\""))
+        + named_group("content", raw_content(hashes))
+        + ReadableRe::String(format!("\"{delimiter}"))
+}
+
+/// Rust raw string, byte or C string, `r"C:\dir"` or `br##"a "#" b"##`, with up to
+/// [`RAW_STRING_HASHES`] `#` and an optional `prefix` named group
+pub const RUST_RAW_STRING: Lazy<ReadableRe> = Lazy::new(|| {
+    let raw = |hashes| {
+        let delimiter = "#".repeat(hashes);
+        ReadableRe::String(format!("r{delimiter}\""))
+            + raw_content(hashes)
+            + ReadableRe::String(format!("\"{delimiter}"))
+    };
+    starts_and_ends_with(
+        optional(named_group("prefix", chars("bc")))
+            + non_capture_group(either((0..=RAW_STRING_HASHES).map(raw))),
+    )
+});
+
+/// Rust raw string with any number of `#`, with the optional `prefix`, `hashes` and `content`
+/// named groups, the closing `#` matched by the `\k<hashes>` back reference of `fancy_regex`.
+/// With no `#`, the content has no `"`.
+#[cfg(feature = "re-fancy")]
+pub const RUST_RAW_STRING_BACK_REFERENCE: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(
+        optional(named_group("prefix", chars("bc")))
+            + Raw("r")
+            + named_group("hashes", zero_or_more(Raw("#")))
+            + Raw("\"")
+            + named_group(
+                "content",
+                zero_or_more(non_capture_group(either([
+                    not_chars("\""),
+                    Raw("\"") + crate::negative_look_ahead(Raw(r"\k<hashes>")),
+                ]))),
+            )
+            + Raw("\"")
+            + Raw(r"\k<hashes>"),
+    )
+});
+
+/// [JSON](https://www.rfc-editor.org/rfc/rfc8259#section-7) string `"tab\t\u00e9"`, control
+/// characters escaped, with a `content` named group
+pub const JSON_STRING: Lazy<ReadableRe> = Lazy::new(|| {
+    let escape = non_capture_group(either([
+        chars(r#""\\/bfnrt"#),
+        Raw("u") + exactly(4, chars("0-9a-fA-F")),
+    ]));
+    starts_and_ends_with(quoted(not_chars(r#""\\\x00-\x1F"#), escape))
+});
+
+/// Rest of the line after the `start` of a line comment, in a `content` named group
+fn line_comment(start: &'static str) -> ReadableRe<'static> {
+    Raw(start) + named_group("content", zero_or_more(not_chars(r"\r\n")))
+}
+
+/// `//` line comment of C, JavaScript or Rust, with a `content` named group
+pub const LINE_COMMENT: Lazy<ReadableRe> = Lazy::new(|| starts_and_ends_with(line_comment("//")));
+
+/// `#` line comment of Python or shells, with a `content` named group
+pub const HASH_COMMENT: Lazy<ReadableRe> = Lazy::new(|| starts_and_ends_with(line_comment("#")));
+
+/// `/* */` block comment, with a `content` named group. Nested block comments, allowed by Rust,
+/// are not matched, as the comment ends at the first `*/`.
+pub const BLOCK_COMMENT: Lazy<ReadableRe> = Lazy::new(|| {
+    let content = zero_or_more(non_capture_group(either([
+        not_chars("*"),
+        one_or_more(Raw(r"\*")) + not_chars("*/"),
+    ]))) + zero_or_more(Raw(r"\*"));
+    starts_and_ends_with(Raw(r"/\*") + named_group("content", content) + Raw(r"\*/"))
+});
+
+/// Rust `///` outer or `//!` inner line doc comment, with `kind` (`/` or `!`) and `content` named
+/// groups. `////` being a plain comment, the content never starts with `/`.
+pub const RUST_DOC_COMMENT: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(
+        Raw("//")
+            + named_group("kind", chars("/!"))
+            + named_group(
+                "content",
+                optional(non_capture_group(
+                    not_chars(r"/\r\n") + zero_or_more(not_chars(r"\r\n")),
+                )),
+            ),
+    )
+});
+
+/// Integer literal of the `language`, without capture groups:
+///
+/// | Language     | Examples                       |
+/// |--------------|---------------------------------|
+/// | C            | `0x1Fu`, `0755`, `10UL`         |
+/// | JavaScript   | `0xFF`, `1_000`, `9007n`        |
+/// | Json         | `-12`, `0`                      |
+/// | Python       | `0o755`, `0x_FF`, `1_000`, `00` |
+/// | Rust         | `0xFFu8`, `1_000i64`            |
+pub fn integer_literal(language: Language) -> ReadableRe<'static> {
+    let prefixed = |separator: bool| {
+        let separator = || {
+            if separator {
+                optional(Raw("_"))
+            } else {
+                Raw("")
+            }
+        };
+        [
+            Raw("0") + chars("xX") + separator() + digits_of("0-9a-fA-F"),
+            Raw("0") + chars("oO") + separator() + digits_of("0-7"),
+            Raw("0") + chars("bB") + separator() + digits_of("01"),
+        ]
+    };
+    let decimal =
+        || chars("1-9") + zero_or_more(non_capture_group(optional(Raw("_")) + chars("0-9")));
+    match language {
+        Language::C => c_integer(),
+        Language::JavaScript => {
+            non_capture_group(either(
+                prefixed(false).into_iter().chain([decimal(), Raw("0")]),
+            )) + optional(Raw("n"))
+        }
+        Language::Json => {
+            optional(Raw("-"))
+                + non_capture_group(either([
+                    Raw("0"),
+                    chars("1-9") + zero_or_more(chars("0-9")),
+                ]))
+        }
+        Language::Python => non_capture_group(either(
+            prefixed(true)
+                .into_iter()
+                .chain([decimal(), digits_of("0")]),
+        )),
+        Language::Rust => rust_integer(),
+    }
+}
+
+/// Float literal of the `language`, without capture groups, `1.5e-3`, `.5` or `1.` except in JSON
+pub fn float_literal(language: Language) -> ReadableRe<'static> {
+    match language {
+        Language::C => c_float(),
+        Language::JavaScript | Language::Python => {
+            let digits = || digits_of("0-9");
+            let optional_exponent = || optional(non_capture_group(exponent(true)));
+            non_capture_group(either([
+                digits() + Raw(r"\.") + optional(non_capture_group(digits())) + optional_exponent(),
+                Raw(r"\.") + digits() + optional_exponent(),
+                digits() + exponent(true),
+            ]))
+        }
+        Language::Json => {
+            integer_literal(Language::Json)
+                + non_capture_group(either([
+                    Raw(r"\.")
+                        + one_or_more(chars("0-9"))
+                        + optional(non_capture_group(exponent(false))),
+                    exponent(false),
+                ]))
+        }
+        Language::Rust => rust_float(),
+    }
+}
+
+/// Python integer or float literal `0o755`, `1_000.5e-3`
+pub const PYTHON_NUMBER: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(non_capture_group(either([
+        float_literal(Language::Python),
+        integer_literal(Language::Python),
+    ])))
+});
+
+/// JavaScript number or BigInt literal `0xFF`, `1_000.5`, `9007n`
+pub const JAVASCRIPT_NUMBER: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(non_capture_group(either([
+        float_literal(Language::JavaScript),
+        integer_literal(Language::JavaScript),
+    ])))
+});
+
+/// [npm](https://github.com/npm/node-semver#ranges) version range `^1.2.3`, `>=1.0.0 <2.0.0`,
+/// `1.2 - 2.3.4` or `1.x || >=2.5.0-beta.1`. See
+/// [`VERSION_REQUIREMENT`](crate::presets::identifiers::VERSION_REQUIREMENT) for Cargo ones.
+pub const NPM_VERSION_RANGE: Lazy<ReadableRe> = Lazy::new(|| {
+    let part = || non_capture_group(either([version_number(), chars("*xX")]));
+    let partial = || {
+        part()
+            + optional(non_capture_group(
+                Raw(r"\.")
+                    + part()
+                    + optional(non_capture_group(
+                        Raw(r"\.")
+                            + part()
+                            + optional(non_capture_group(Raw("-") + prerelease()))
+                            + optional(non_capture_group(
+                                Raw(r"\+") + dot_separated(one_or_more(chars("0-9a-zA-Z-"))),
+                            )),
+                    )),
+            ))
+    };
+    let operator = || non_capture_group(either([">=", "<=", ">", "<", "=", "~", r"\^"].map(Raw)));
+    let simple = || optional(non_capture_group(operator() + zero_or_more(Raw(" ")))) + partial();
+    let range = non_capture_group(either([
+        partial() + Raw(" +- +") + partial(),
+        simple() + zero_or_more(non_capture_group(one_or_more(Raw(" ")) + simple())),
+    ]));
+    starts_and_ends_with(range.clone() + zero_or_more(non_capture_group(Raw(r" *\|\| *") + range)))
+});
+
+#[cfg(test)]
+mod tests {
+    use crate::presets::code::{
+        float_literal, integer_literal, rust_raw_string, Language, ASCII_IDENTIFIER, BLOCK_COMMENT,
+        C_STRING, HASH_COMMENT, JAVASCRIPT_NUMBER, JSON_STRING, LINE_COMMENT, NPM_VERSION_RANGE,
+        PYTHON_NUMBER, RUST_DOC_COMMENT, RUST_IDENTIFIER, RUST_RAW_STRING, RUST_STRING,
+        UNICODE_IDENTIFIER,
+    };
+    use crate::starts_and_ends_with;
+
+    #[test]
+    fn identifiers() {
+        let query = ASCII_IDENTIFIER.compile().unwrap();
+        for v in ["snake_case", "_private", "CamelCase2", "_"] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in ["", "2fast", "kebab-case", "größe"] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        let query = UNICODE_IDENTIFIER.compile().unwrap();
+        for v in ["größe", "변수", "_x", "naïve_2"] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in ["2x", "a-b", "a b", "x\u{200b}"] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        let query = RUST_IDENTIFIER.compile().unwrap();
+        for v in ["r#type", "_unused", "größe"] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in ["_", "r#", "r#2"] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+    }
+
+    #[test]
+    fn strings() {
+        let query = C_STRING.compile().unwrap();
+        for v in [
+            r#""""#,
+            r#""say \"hi\"\n""#,
+            r#"u8"caf\xC3\xA9""#,
+            r#"L"\0\177\u00e9""#,
+        ] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in [r#""\q""#, r#""unterminated"#, "\"a\nb\"", r#"x"a""#] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        let captures = query.captures(r#"u"a\\b""#).unwrap();
+        assert_eq!(&captures["prefix"], "u");
+        assert_eq!(&captures["content"], r"a\\b");
+        let query = RUST_STRING.compile().unwrap();
+        for v in [
+            r#""\u{1F600}""#,
+            r#"b"\x7F\0""#,
+            r#"c"nul-free""#,
+            "\"multi\nline \\\n    continued\"",
+        ] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in [r#""\a""#, r#""\u{1234567}""#, r#""\x7""#] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        let query = JSON_STRING.compile().unwrap();
+        for v in [r#""tab\t\u00e9""#, r#""\/\"""#, r#""""#] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in ["\"tab\t\"", r#""\x41""#, r#""\u00e""#, "'single'"] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+    }
+
+    #[test]
+    fn raw_strings() {
+        let query = RUST_RAW_STRING.compile().unwrap();
+        for v in [
+            r#"r"C:\dir""#,
+            r##"r#"say "hi""#"##,
+            r###"br##"a "#" b"##"###,
+            "cr\"multi\nline\"",
+            r##"r#"a"""#"##,
+            r###"r##""#"""#"##"###,
+        ] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in [
+            r##"r#"a""##,
+            r##"r"a"#"##,
+            r#""a""#,
+            r###"r##"a"#"###,
+            r##"r#"a"# + r#"b"#"##,
+            r#"r"a" + r"b""#,
+        ] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        let query = starts_and_ends_with(rust_raw_string(2)).compile().unwrap();
+        let captures = query.captures(r###"r##"a "#" b"##"###).unwrap();
+        assert_eq!(&captures["content"], r##"a "#" b"##);
+    }
+
+    #[cfg(feature = "re-fancy")]
+    #[test]
+    fn raw_string_back_reference() {
+        use crate::presets::code::RUST_RAW_STRING_BACK_REFERENCE;
+        let query = fancy_regex::Regex::new(&RUST_RAW_STRING_BACK_REFERENCE.to_string()).unwrap();
+        let text = format!("r{0}\"a\"{1}\"{0}", "#".repeat(12), "#".repeat(11));
+        assert!(query.is_match(&text).unwrap());
+        assert!(!query.is_match(r###"r##"a"#"###).unwrap());
+        assert!(!query.is_match(r##"r#"a"# + r#"b"#"##).unwrap());
+        assert!(!query.is_match(r#"r"a"b""#).unwrap());
+    }
+
+    #[test]
+    fn comments() {
+        let query = LINE_COMMENT.compile().unwrap();
+        assert_eq!(
+            &query.captures("// TODO: x").unwrap()["content"],
+            " TODO: x"
+        );
+        assert!(!query.is_match("// a\nb"));
+        let query = HASH_COMMENT.compile().unwrap();
+        assert_eq!(&query.captures("#!/bin/sh").unwrap()["content"], "!/bin/sh");
+        let query = BLOCK_COMMENT.compile().unwrap();
+        for v in ["/**/", "/* a\n * b */", "/*** doc ***/"] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in ["/* a */ b */", "/* open", "/*/"] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        let query = RUST_DOC_COMMENT.compile().unwrap();
+        let captures = query.captures("//! Crate docs").unwrap();
+        assert_eq!(&captures["kind"], "!");
+        assert_eq!(&captures["content"], " Crate docs");
+        for v in ["///", "/// Outer"] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in ["// plain", "//// plain", "/// a\nb"] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+    }
+
+    #[test]
+    fn number_literals() {
+        let query = PYTHON_NUMBER.compile().unwrap();
+        for v in [
+            "0",
+            "00",
+            "1_000",
+            "0o755",
+            "0x_FF",
+            "0b1010",
+            "1.",
+            ".5",
+            "1_0.5e-1_0",
+            "2E3",
+        ] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in ["01", "1__0", "1_", "0x", "0o8", "_1", "1e", "10L"] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        let query = JAVASCRIPT_NUMBER.compile().unwrap();
+        for v in ["0", "0xFF", "1_000", "9007n", "0b1n", ".5e10", "1."] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in ["0x_FF", "1.5n", "00", "1__0"] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        let integer = starts_and_ends_with(integer_literal(Language::Json))
+            .compile()
+            .unwrap();
+        let float = starts_and_ends_with(float_literal(Language::Json))
+            .compile()
+            .unwrap();
+        assert!(integer.is_match("-12") && !integer.is_match("012"));
+        assert!(float.is_match("-0.5e+3") && float.is_match("1E2"));
+        for v in ["1", ".5", "1.", "+1.0"] {
+            assert!(!float.is_match(v), "Wrongly matched: {}", v);
+        }
+        for (language, v) in [
+            (Language::C, "0x1Fu"),
+            (Language::Rust, "0xFFu8"),
+            (Language::Rust, "1_000i64"),
+        ] {
+            let query = starts_and_ends_with(integer_literal(language))
+                .compile()
+                .unwrap();
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for (language, v) in [(Language::C, ".5e3f"), (Language::Rust, "2.5e-3f32")] {
+            let query = starts_and_ends_with(float_literal(language))
+                .compile()
+                .unwrap();
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+    }
+
+    #[test]
+    fn npm_version_range() {
+        let query = NPM_VERSION_RANGE.compile().unwrap();
+        for v in [
+            "^1.2.3",
+            "~1.2",
+            ">=1.0.0 <2.0.0",
+            ">= 1.0.0",
+            "1.2 - 2.3.4",
+            "1.x || >=2.5.0-beta.1",
+            "*",
+            "=1.0.0+build.5",
+        ] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in [
+            "",
+            "^01.2",
+            "1.2.3.4",
+            ">=1.0.0,<2.0.0",
+            "1.2 -",
+            "|| 1.0",
+            "latest",
+        ] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+    }
+}
@@ -1,19 +1,24 @@
+use crate::presets::locale::{month_names, weekday_names, Form, Locale};
+use crate::solvers::Trie;
 use crate::ReadableRe::*;
-use crate::{chars, either, exactly, group, optional, starts_and_ends_with, ReadableRe};
+use crate::{
+    chars, either, exactly, group, named_group, non_capture_group, numeric_range, one_or_more,
+    optional, starts_and_ends_with, zero_or_more, LeadingZeros, RangeOptions, ReadableRe,
+};
 use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+use std::fmt::{Display, Formatter};
+
+/// Zero padded up to the digits of the largest number
+fn padded() -> RangeOptions {
+    RangeOptions::new().leading_zeros(LeadingZeros::Required)
+}
 
 /// Month day, `01`-`31`
-pub const DAY: Lazy<ReadableRe> = Lazy::new(|| {
-    either([
-        Raw("0") + chars("1-9"),
-        chars("12") + chars("0-9"),
-        Raw("3") + chars("01"),
-    ])
-});
+pub const DAY: Lazy<ReadableRe> = Lazy::new(|| numeric_range(1, 31, padded()));
 
 /// Month numeral, `01`-`12`
-pub const MONTH: Lazy<ReadableRe> =
-    Lazy::new(|| either([Raw("0") + chars("1-9"), Raw("1") + chars("0-2")]));
+pub const MONTH: Lazy<ReadableRe> = Lazy::new(|| numeric_range(1, 12, padded()));
 
 /// Calendar month, `Jan|Feb|Mar|Apr|May|Jun|Jul|Aug|Sep|Oct|Nov|Dec`
 pub const MONTH_CALENDAR: Lazy<ReadableRe> = Lazy::new(|| {
@@ -26,7 +31,7 @@ pub const MONTH_CALENDAR: Lazy<ReadableRe> = Lazy::new(|| {
 });
 
 /// Years from `1000` to `2999`
-pub const YEAR: Lazy<ReadableRe> = Lazy::new(|| chars("12") + exactly(3, Digit));
+pub const YEAR: Lazy<ReadableRe> = Lazy::new(|| numeric_range(1000, 2999, RangeOptions::new()));
 
 /// Date Format `YYYY-MM-dd`
 pub const DATE_Y_M_D: Lazy<ReadableRe> = Lazy::new(|| {
@@ -51,20 +56,13 @@ pub const DATE_D_M_Y: Lazy<ReadableRe> = Lazy::new(|| {
 });
 
 /// Minutes or seconds representation `00`-`59`
-pub const MIN_SEC: Lazy<ReadableRe> = Lazy::new(|| chars("0-5") + chars("0-9"));
-
-/// Hours 12h format
-pub const HOURS_12: Lazy<ReadableRe> =
-    Lazy::new(|| either([Raw("0") + chars("0-9"), Raw("1") + chars("0-2")]));
-
-/// Hours 24h format
-pub const HOURS_24: Lazy<ReadableRe> = Lazy::new(|| {
-    either([
-        Raw("0") + chars("0-9"),
-        Raw("1") + chars("0-9"),
-        Raw("2") + chars("0-4"),
-    ])
-});
+pub const MIN_SEC: Lazy<ReadableRe> = Lazy::new(|| numeric_range(0, 59, padded()));
+
+/// Hours 12h format `00`-`12`
+pub const HOURS_12: Lazy<ReadableRe> = Lazy::new(|| numeric_range(0, 12, padded()));
+
+/// Hours 24h format `00`-`23`
+pub const HOURS_24: Lazy<ReadableRe> = Lazy::new(|| numeric_range(0, 23, padded()));
 
 const MERIDIEMS: Lazy<ReadableRe> =
     Lazy::new(|| either([chars("ap") + "m".into(), chars("AP") + chars("Mm")]));
@@ -93,11 +91,637 @@ pub const HH_MM_SS_24: Lazy<ReadableRe> = Lazy::new(|| {
     )
 });
 
+/// Named group when `named`, non capturing group otherwise.
+/// Group names must be unique, so the pieces used twice in a pattern (intervals) go unnamed.
+fn part<'a>(named: bool, name: &'a str, re: ReadableRe<'a>) -> ReadableRe<'a> {
+    if named {
+        named_group(name, re)
+    } else {
+        non_capture_group(re)
+    }
+}
+
+/// Separator between the fields, only in the extended format
+fn separator(extended: bool, separator: &str) -> ReadableRe<'_> {
+    Raw(if extended { separator } else { "" })
+}
+
+fn date(named: bool, extended: bool) -> ReadableRe<'static> {
+    part(named, "year", YEAR.clone())
+        + separator(extended, "-")
+        + part(named, "month", MONTH.clone())
+        + separator(extended, "-")
+        + part(named, "day", DAY.clone())
+}
+
+/// `hh:mm[:ss[.fff]]`, seconds are mandatory when `full`. The fraction of a second follows
+/// `fraction_separator`.
+fn time(
+    named: bool,
+    extended: bool,
+    full: bool,
+    fraction_separator: ReadableRe<'static>,
+) -> ReadableRe<'static> {
+    let seconds = separator(extended, ":")
+        + part(named, "second", MIN_SEC.clone())
+        + optional(non_capture_group(
+            fraction_separator + part(named, "fraction", one_or_more(chars("0-9"))),
+        ));
+    part(named, "hour", HOURS_24.clone())
+        + separator(extended, ":")
+        + part(named, "minute", MIN_SEC.clone())
+        + if full {
+            seconds
+        } else {
+            optional(non_capture_group(seconds))
+        }
+}
+
+/// `Z` or `±hh[:mm]`, RFC 3339 also allows a lowercase `z` but requires the minutes
+fn offset(named: bool, extended: bool, rfc3339: bool) -> ReadableRe<'static> {
+    let minutes = separator(extended, ":") + MIN_SEC.clone();
+    part(
+        named,
+        "offset",
+        either([
+            if rfc3339 { chars("Zz") } else { Raw("Z") },
+            chars("+-")
+                + non_capture_group(HOURS_24.clone())
+                + if rfc3339 {
+                    minutes
+                } else {
+                    optional(non_capture_group(minutes))
+                },
+        ]),
+    )
+}
+
+fn date_time(named: bool, extended: bool) -> ReadableRe<'static> {
+    date(named, extended)
+        + Raw("T")
+        + time(named, extended, false, chars(".,"))
+        + optional(offset(named, extended, false))
+}
+
+fn duration(named: bool) -> ReadableRe<'static> {
+    let component = |name, designator| {
+        optional(non_capture_group(
+            part(
+                named,
+                name,
+                one_or_more(chars("0-9"))
+                    + optional(non_capture_group(chars(".,") + one_or_more(chars("0-9")))),
+            ) + Raw(designator),
+        ))
+    };
+    Raw("P")
+        + component("years", "Y")
+        + component("months", "M")
+        + component("weeks", "W")
+        + component("days", "D")
+        + optional(non_capture_group(
+            Raw("T")
+                + component("hours", "H")
+                + component("minutes", "M")
+                + component("seconds", "S"),
+        ))
+}
+
+/// ISO 8601 calendar date, extended format `YYYY-MM-DD`, with `year`, `month` and `day` named groups
+pub const ISO8601_DATE: Lazy<ReadableRe> = Lazy::new(|| starts_and_ends_with(date(true, true)));
+
+/// ISO 8601 calendar date, basic format `YYYYMMDD`, with `year`, `month` and `day` named groups
+pub const ISO8601_DATE_BASIC: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(date(true, false)));
+
+fn week_date(named: bool, extended: bool) -> ReadableRe<'static> {
+    part(named, "year", YEAR.clone())
+        + separator(extended, "-")
+        + Raw("W")
+        + part(named, "week", numeric_range(1, 53, padded()))
+        + separator(extended, "-")
+        + part(named, "weekday", chars("1-7"))
+}
+
+fn ordinal_date(named: bool, extended: bool) -> ReadableRe<'static> {
+    part(named, "year", YEAR.clone())
+        + separator(extended, "-")
+        + part(named, "ordinal", numeric_range(1, 366, padded().width(3)))
+}
+
+/// ISO 8601 week date, extended format `YYYY-Www-D`, with `year`, `week` and `weekday` named
+/// groups
+pub const ISO8601_WEEK_DATE: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(week_date(true, true)));
+
+/// ISO 8601 week date, basic format `YYYYWwwD`, with `year`, `week` and `weekday` named groups
+pub const ISO8601_WEEK_DATE_BASIC: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(week_date(true, false)));
+
+/// ISO 8601 ordinal date, extended format `YYYY-DDD`, with `year` and `ordinal` named groups
+pub const ISO8601_ORDINAL_DATE: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(ordinal_date(true, true)));
+
+/// ISO 8601 ordinal date, basic format `YYYYDDD`, with `year` and `ordinal` named groups
+pub const ISO8601_ORDINAL_DATE_BASIC: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(ordinal_date(true, false)));
+
+/// ISO 8601 date and time, extended format `YYYY-MM-DDThh:mm[:ss[.fff]][Z|±hh[:mm]]`.
+///
+/// Named groups: `year`, `month`, `day`, `hour`, `minute`, `second`, `fraction` and `offset`
+pub const ISO8601_DATE_TIME: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(date_time(true, true)));
+
+/// ISO 8601 date and time, basic format `YYYYMMDDThhmm[ss[.fff]][Z|±hh[mm]]`, same named groups as
+/// [`ISO8601_DATE_TIME`]
+pub const ISO8601_DATE_TIME_BASIC: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(date_time(true, false)));
+
+/// [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339#section-5.6) timestamp
+/// `YYYY-MM-DDThh:mm:ss[.fff](Z|±hh:mm)`, same named groups as [`ISO8601_DATE_TIME`]
+pub const RFC3339: Lazy<ReadableRe> = Lazy::new(|| starts_and_ends_with(rfc3339(true)));
+
+/// RFC 3339 timestamp, with the [`ISO8601_DATE_TIME`] named groups when `named`
+pub(crate) fn rfc3339(named: bool) -> ReadableRe<'static> {
+    date(named, true) + chars("Tt ") + time(named, true, true, Period) + offset(named, true, true)
+}
+
+/// ISO 8601 duration `P[nY][nM][nW][nD][T[nH][nM][nS]]`, e.g. `P3Y6M4DT12H30M5S`.
+///
+/// Named groups: `years`, `months`, `weeks`, `days`, `hours`, `minutes` and `seconds`.
+/// The bare designators `P` and `PT` also match, [`Duration::parse`] rejects them.
+pub const ISO8601_DURATION: Lazy<ReadableRe> = Lazy::new(|| starts_and_ends_with(duration(true)));
+
+/// ISO 8601 interval `start/end`, `start/duration` or `duration/end`, optionally repeating
+/// `Rn/...`, with `repetitions`, `start` and `end` named groups
+pub const ISO8601_INTERVAL: Lazy<ReadableRe> = Lazy::new(|| {
+    let bound = || either([date_time(false, true), date(false, true), duration(false)]);
+    starts_and_ends_with(
+        optional(non_capture_group(
+            Raw("R") + named_group("repetitions", zero_or_more(chars("0-9"))) + Raw("/"),
+        )) + named_group("start", bound())
+            + Raw("/")
+            + named_group("end", bound()),
+    )
+});
+
+/// Day of the month, zero padded or not, `1`-`31`
+fn day_number() -> ReadableRe<'static> {
+    numeric_range(
+        1,
+        31,
+        RangeOptions::new().leading_zeros(LeadingZeros::Optional),
+    )
+}
+
+fn english_months(form: Form) -> ReadableRe<'static> {
+    Trie(month_names(&[Locale::English], form))
+}
+
+/// Textual date `April 18, 2022` or `Apr. 18 2022`, with `month`, `day` and `year` named groups
+pub const DATE_MONTH_D_Y: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(
+        named_group("month", english_months(Form::Any))
+            + optional(Period)
+            + Raw(" ")
+            + named_group("day", day_number())
+            + optional(Raw(","))
+            + Raw(" ")
+            + named_group("year", YEAR.clone()),
+    )
+});
+
+/// Textual date `18 April 2022` or `18 Apr. 2022`, with `day`, `month` and `year` named groups
+pub const DATE_D_MONTH_Y: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(
+        named_group("day", day_number())
+            + Raw(" ")
+            + named_group("month", english_months(Form::Any))
+            + optional(Period)
+            + Raw(" ")
+            + named_group("year", YEAR.clone()),
+    )
+});
+
+/// [RFC 2822](https://www.rfc-editor.org/rfc/rfc2822#section-3.3) date `[Mon, ]18 Apr 2022 10:00[:00] +0000`.
+///
+/// Named groups: `weekday`, `day`, `month`, `year`, `hour`, `minute`, `second` and `zone`
+pub const RFC2822: Lazy<ReadableRe> = Lazy::new(|| {
+    let zones = [
+        "UT", "GMT", "EST", "EDT", "CST", "CDT", "MST", "MDT", "PST", "PDT",
+    ];
+    starts_and_ends_with(
+        optional(non_capture_group(
+            named_group(
+                "weekday",
+                Trie(weekday_names(&[Locale::English], Form::Abbreviated)),
+            ) + Raw(", "),
+        )) + named_group("day", day_number())
+            + Raw(" ")
+            + named_group("month", english_months(Form::Abbreviated))
+            + Raw(" ")
+            + named_group("year", YEAR.clone())
+            + Raw(" ")
+            + named_group("hour", HOURS_24.clone())
+            + Raw(":")
+            + named_group("minute", MIN_SEC.clone())
+            + optional(non_capture_group(
+                Raw(":") + named_group("second", MIN_SEC.clone()),
+            ))
+            + Raw(" ")
+            + named_group(
+                "zone",
+                either([
+                    chars("+-") + exactly(4, chars("0-9")),
+                    Trie(Trie::new(zones)),
+                ]),
+            ),
+    )
+});
+
+/// HTTP date, the IMF-fixdate of [RFC 9110](https://www.rfc-editor.org/rfc/rfc9110#section-5.6.7)
+/// `Mon, 18 Apr 2022 10:00:00 GMT`.
+///
+/// Named groups: `weekday`, `day`, `month`, `year`, `hour`, `minute` and `second`
+pub const HTTP_DATE: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(
+        named_group(
+            "weekday",
+            Trie(weekday_names(&[Locale::English], Form::Abbreviated)),
+        ) + Raw(", ")
+            + named_group("day", DAY.clone())
+            + Raw(" ")
+            + named_group("month", english_months(Form::Abbreviated))
+            + Raw(" ")
+            + named_group("year", YEAR.clone())
+            + Raw(" ")
+            + named_group("hour", HOURS_24.clone())
+            + Raw(":")
+            + named_group("minute", MIN_SEC.clone())
+            + Raw(":")
+            + named_group("second", MIN_SEC.clone())
+            + Raw(" GMT"),
+    )
+});
+
+static DATE_TIME_FORMATS: Lazy<[Regex; 3]> = Lazy::new(|| {
+    [&RFC3339, &ISO8601_DATE_TIME, &ISO8601_DATE_TIME_BASIC].map(|re| re.compile().unwrap())
+});
+
+static DURATION_FORMAT: Lazy<Regex> = Lazy::new(|| ISO8601_DURATION.compile().unwrap());
+
+/// Anchored date presets, in the order [`validate_date`] tries them
+struct DateFormats {
+    /// [`ISO8601_DATE`] and [`ISO8601_DATE_BASIC`]
+    calendar: [Regex; 2],
+    /// [`ISO8601_WEEK_DATE`] and [`ISO8601_WEEK_DATE_BASIC`]
+    week: [Regex; 2],
+    /// [`ISO8601_ORDINAL_DATE`] and [`ISO8601_ORDINAL_DATE_BASIC`]
+    ordinal: [Regex; 2],
+    y_m_d: Regex,
+    d_m_y: Regex,
+}
+
+static DATE_FORMATS: Lazy<DateFormats> = Lazy::new(|| DateFormats {
+    calendar: [&ISO8601_DATE, &ISO8601_DATE_BASIC].map(|re| re.compile().unwrap()),
+    week: [&ISO8601_WEEK_DATE, &ISO8601_WEEK_DATE_BASIC].map(|re| re.compile().unwrap()),
+    ordinal: [&ISO8601_ORDINAL_DATE, &ISO8601_ORDINAL_DATE_BASIC].map(|re| re.compile().unwrap()),
+    y_m_d: starts_and_ends_with(DATE_Y_M_D.clone()).compile().unwrap(),
+    d_m_y: starts_and_ends_with(DATE_D_M_Y.clone()).compile().unwrap(),
+});
+
+/// Components of an ISO 8601 or RFC 3339 date and time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateTime {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: Option<u8>,
+    /// Fraction of second, in nanoseconds
+    pub nanosecond: Option<u32>,
+    /// Offset from UTC in minutes, `None` for local time
+    pub offset_minutes: Option<i16>,
+}
+
+impl DateTime {
+    /// Extract the components of a [`RFC3339`], [`ISO8601_DATE_TIME`] or
+    /// [`ISO8601_DATE_TIME_BASIC`] timestamp, if its date exists in the calendar
+    ///
+    /// ```
+    /// use readable_regex::presets::datetime::DateTime;
+    /// let date_time = DateTime::parse("2022-04-18T10:30:05.25+02:00").unwrap();
+    /// assert_eq!((date_time.year, date_time.month, date_time.day), (2022, 4, 18));
+    /// assert_eq!(date_time.nanosecond, Some(250_000_000));
+    /// assert_eq!(date_time.offset_minutes, Some(120));
+    /// ```
+    pub fn parse(text: &str) -> Option<Self> {
+        let captures = DATE_TIME_FORMATS
+            .iter()
+            .find_map(|format| format.captures(text))?;
+        let date = Date::new(
+            number(&captures, "year")?,
+            number(&captures, "month")?,
+            number(&captures, "day")?,
+        )
+        .ok()?;
+        Some(Self {
+            year: date.year,
+            month: date.month,
+            day: date.day,
+            hour: number(&captures, "hour")?,
+            minute: number(&captures, "minute")?,
+            second: number(&captures, "second"),
+            nanosecond: match captures.name("fraction") {
+                Some(fraction) => {
+                    let digits: std::string::String = fraction
+                        .as_str()
+                        .chars()
+                        .chain("000000000".chars())
+                        .take(9)
+                        .collect();
+                    Some(digits.parse().ok()?)
+                }
+                None => None,
+            },
+            offset_minutes: captures.name("offset").map(|offset| {
+                let offset = offset.as_str();
+                let sign = if offset.starts_with('-') { -1 } else { 1 };
+                let digits: std::string::String =
+                    offset.chars().filter(char::is_ascii_digit).collect();
+                let hours: i16 = digits.get(..2).map_or(0, |h| h.parse().unwrap());
+                let minutes: i16 = digits.get(2..).map_or(0, |m| m.parse().unwrap_or(0));
+                sign * (hours * 60 + minutes)
+            }),
+        })
+    }
+}
+
+/// Components of an ISO 8601 duration
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Duration {
+    pub years: Option<f64>,
+    pub months: Option<f64>,
+    pub weeks: Option<f64>,
+    pub days: Option<f64>,
+    pub hours: Option<f64>,
+    pub minutes: Option<f64>,
+    pub seconds: Option<f64>,
+}
+
+impl Duration {
+    /// Extract the components of a [`ISO8601_DURATION`], at least one must be present
+    ///
+    /// ```
+    /// use readable_regex::presets::datetime::Duration;
+    /// let duration = Duration::parse("P3Y6M4DT12H30M5,5S").unwrap();
+    /// assert_eq!(duration.years, Some(3.0));
+    /// assert_eq!(duration.minutes, Some(30.0));
+    /// assert_eq!(duration.seconds, Some(5.5));
+    /// assert!(Duration::parse("PT").is_none());
+    /// ```
+    pub fn parse(text: &str) -> Option<Self> {
+        let captures = DURATION_FORMAT.captures(text)?;
+        let value = |name| {
+            captures
+                .name(name)
+                .and_then(|m| m.as_str().replace(',', ".").parse().ok())
+        };
+        let duration = Self {
+            years: value("years"),
+            months: value("months"),
+            weeks: value("weeks"),
+            days: value("days"),
+            hours: value("hours"),
+            minutes: value("minutes"),
+            seconds: value("seconds"),
+        };
+        let time_designator = text.contains('T');
+        let has_time =
+            duration.hours.is_some() || duration.minutes.is_some() || duration.seconds.is_some();
+        match (duration == Self::default(), time_designator && !has_time) {
+            (false, false) => Some(duration),
+            _ => None,
+        }
+    }
+}
+
+/// A date that exists in the (proleptic Gregorian) calendar
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl Date {
+    /// Check that the month has that day in that year
+    pub fn new(year: u16, month: u8, day: u8) -> Result<Self, DateError> {
+        if (1..=12).contains(&month) && (1..=days_in_month(year, month)).contains(&day) {
+            Ok(Self { year, month, day })
+        } else {
+            Err(DateError::Day { year, month, day })
+        }
+    }
+
+    /// Date of the day of the year, from `1` to `365` (`366` in leap years)
+    pub fn from_ordinal(year: u16, ordinal: u16) -> Result<Self, DateError> {
+        if !(1..=days_in_year(year)).contains(&ordinal) {
+            return Err(DateError::Ordinal { year, ordinal });
+        }
+        let mut day = ordinal;
+        let mut month = 1;
+        while day > days_in_month(year, month) as u16 {
+            day -= days_in_month(year, month) as u16;
+            month += 1;
+        }
+        Ok(Self {
+            year,
+            month,
+            day: day as u8,
+        })
+    }
+
+    /// Date of an ISO 8601 week date, `weekday` goes from `1` (Monday) to `7` (Sunday).
+    /// The first days of week 1 and the last days of week 52 or 53 may fall in another year.
+    pub fn from_iso_week(year: u16, week: u8, weekday: u8) -> Result<Self, DateError> {
+        if !(1..=iso_weeks_in_year(year)).contains(&week) || !(1..=7).contains(&weekday) {
+            return Err(DateError::Week { year, week });
+        }
+        // the week 1 is the one with the 4th of January
+        let january_4 = Self::new(year, 1, 4)?.weekday() as i32;
+        let ordinal = week as i32 * 7 + weekday as i32 - (january_4 + 3);
+        let out_of_range = DateError::Week { year, week };
+        let (year, ordinal) = if ordinal < 1 {
+            let previous = year.checked_sub(1).ok_or(out_of_range)?;
+            (previous, ordinal + days_in_year(previous) as i32)
+        } else if ordinal > days_in_year(year) as i32 {
+            let next = year.checked_add(1).ok_or(out_of_range)?;
+            (next, ordinal - days_in_year(year) as i32)
+        } else {
+            (year, ordinal)
+        };
+        Self::from_ordinal(year, ordinal as u16)
+    }
+
+    /// Day of the year, from `1`
+    pub fn ordinal(&self) -> u16 {
+        (1..self.month)
+            .map(|month| days_in_month(self.year, month) as u16)
+            .sum::<u16>()
+            + self.day as u16
+    }
+
+    /// ISO 8601 weekday, from `1` (Monday) to `7` (Sunday), in the proleptic Gregorian calendar
+    /// for the year `0`
+    pub fn weekday(&self) -> u8 {
+        let previous = self.year as i32 - 1;
+        // Gauss' algorithm, 0 is Sunday
+        let january_1 = (1
+            + 5 * previous.rem_euclid(4)
+            + 4 * previous.rem_euclid(100)
+            + 6 * previous.rem_euclid(400))
+            % 7;
+        ((january_1 + 6 + self.ordinal() as i32 - 1) % 7) as u8 + 1
+    }
+}
+
+/// Why a text is not a real date
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateError {
+    /// The text does not match any of the date presets
+    Format,
+    /// The month does not have that day, e.g. `2022-02-30`
+    Day { year: u16, month: u8, day: u8 },
+    /// The year does not have that week, only some years have a week 53
+    Week { year: u16, week: u8 },
+    /// The year does not have that day, only leap years have a day 366
+    Ordinal { year: u16, ordinal: u16 },
+}
+
+impl Display for DateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DateError::Format => write!(f, "not a date in a supported format"),
+            DateError::Day { year, month, day } => {
+                write!(f, "{year:04}-{month:02} does not have a day {day}")
+            }
+            DateError::Week { year, week } => write!(f, "{year:04} does not have a week {week}"),
+            DateError::Ordinal { year, ordinal } => {
+                write!(f, "{year:04} does not have a day {ordinal}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DateError {}
+
+/// Leap years of the Gregorian calendar
+pub fn is_leap_year(year: u16) -> bool {
+    year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400))
+}
+
+/// Number of days of the month, `0` for months out of `1`-`12`
+pub fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+fn days_in_year(year: u16) -> u16 {
+    if is_leap_year(year) {
+        366
+    } else {
+        365
+    }
+}
+
+/// Years starting on Thursday, and leap years starting on Wednesday, have 53 weeks
+fn iso_weeks_in_year(year: u16) -> u8 {
+    match (
+        Date {
+            year,
+            month: 1,
+            day: 1,
+        }
+        .weekday(),
+        is_leap_year(year),
+    ) {
+        (4, _) | (3, true) => 53,
+        _ => 52,
+    }
+}
+
+/// Check that a date matched by [`ISO8601_DATE`], [`ISO8601_WEEK_DATE`], [`ISO8601_ORDINAL_DATE`],
+/// their basic formats, [`DATE_Y_M_D`] or [`DATE_D_M_Y`] (as the whole text) exists in the
+/// calendar
+///
+/// ```
+/// use readable_regex::presets::datetime::{validate_date, Date, DateError};
+/// assert_eq!(validate_date("2024-02-29"), Ok(Date { year: 2024, month: 2, day: 29 }));
+/// assert_eq!(validate_date("2022-W01-1"), Ok(Date { year: 2022, month: 1, day: 3 }));
+/// assert_eq!(
+///     validate_date("30/02/2022"),
+///     Err(DateError::Day { year: 2022, month: 2, day: 30 })
+/// );
+/// ```
+pub fn validate_date(text: &str) -> Result<Date, DateError> {
+    let formats = &*DATE_FORMATS;
+    let field = |captures: &Captures, name: &str| captures[name].parse().unwrap();
+    let index = |captures: &Captures, i: usize| captures[i].parse().unwrap();
+    if let Some(captures) = formats.calendar.iter().find_map(|f| f.captures(text)) {
+        let year = field(&captures, "year");
+        return Date::new(
+            year,
+            field(&captures, "month") as u8,
+            field(&captures, "day") as u8,
+        );
+    }
+    if let Some(captures) = formats.week.iter().find_map(|f| f.captures(text)) {
+        return Date::from_iso_week(
+            field(&captures, "year"),
+            field(&captures, "week") as u8,
+            field(&captures, "weekday") as u8,
+        );
+    }
+    if let Some(captures) = formats.ordinal.iter().find_map(|f| f.captures(text)) {
+        return Date::from_ordinal(field(&captures, "year"), field(&captures, "ordinal"));
+    }
+    if let Some(captures) = formats.y_m_d.captures(text) {
+        return Date::new(
+            index(&captures, 2),
+            index(&captures, 3) as u8,
+            index(&captures, 4) as u8,
+        );
+    }
+    if let Some(captures) = formats.d_m_y.captures(text) {
+        return Date::new(
+            index(&captures, 4),
+            index(&captures, 3) as u8,
+            index(&captures, 2) as u8,
+        );
+    }
+    Err(DateError::Format)
+}
+
+fn number<T: std::str::FromStr>(captures: &Captures, name: &str) -> Option<T> {
+    captures.name(name)?.as_str().parse().ok()
+}
+
 #[cfg(test)]
 mod tests {
     use crate::presets::datetime::{
-        DATE_D_M_Y, DATE_Y_M_D, DAY, HH_MM_12, HH_MM_24, HH_MM_SS_24, HOURS_12, HOURS_24,
-        MERIDIEMS, MIN_SEC, MONTH, YEAR,
+        iso_weeks_in_year, validate_date, Date, DateError, DateTime, Duration, DATE_D_MONTH_Y,
+        DATE_D_M_Y, DATE_MONTH_D_Y, DATE_Y_M_D, DAY, HH_MM_12, HH_MM_24, HH_MM_SS_24, HOURS_12,
+        HOURS_24, HTTP_DATE, ISO8601_DATE, ISO8601_DATE_BASIC, ISO8601_DATE_TIME,
+        ISO8601_DATE_TIME_BASIC, ISO8601_DURATION, ISO8601_INTERVAL, ISO8601_ORDINAL_DATE,
+        ISO8601_ORDINAL_DATE_BASIC, ISO8601_WEEK_DATE, ISO8601_WEEK_DATE_BASIC, MERIDIEMS, MIN_SEC,
+        MONTH, RFC2822, RFC3339, YEAR,
     };
 
     #[test]
@@ -173,11 +797,11 @@ mod tests {
     #[test]
     fn hour_24() {
         let query = HOURS_24.compile().unwrap();
-        for i in 0..25 {
+        for i in 0..24 {
             let v = format!("{i:02}");
             assert!(query.is_match(&v), "Failed matching: {}", v);
         }
-        for i in 25..99 {
+        for i in 24..99 {
             let v = format!("{i:02}");
             assert!(!query.is_match(&v), "Failed matching: {}", v);
         }
@@ -219,4 +843,263 @@ mod tests {
         assert!(query.is_match("23:30:59"));
         assert!(!query.is_match("18:30:89"));
     }
+
+    #[test]
+    fn iso8601_dates() {
+        let query = ISO8601_DATE.compile().unwrap();
+        assert!(query.is_match("2022-04-18"));
+        assert!(!query.is_match("20220418"));
+        assert!(!query.is_match("2022-13-18"));
+        let query = ISO8601_DATE_BASIC.compile().unwrap();
+        assert!(query.is_match("20220418"));
+        assert!(!query.is_match("2022-04-18"));
+        let query = ISO8601_WEEK_DATE.compile().unwrap();
+        assert!(query.is_match("2022-W15-3"));
+        for v in [
+            "2022W153",
+            "2022-W54-3",
+            "2022-W15-8",
+            "2022-W151",
+            "2022W15-1",
+        ] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        let query = ISO8601_WEEK_DATE_BASIC.compile().unwrap();
+        assert!(query.is_match("2022W153"));
+        for v in ["2022-W15-3", "2022-W151", "2022W15-1"] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        let query = ISO8601_ORDINAL_DATE.compile().unwrap();
+        assert!(query.is_match("2022-108"));
+        for v in ["2022366", "2022-000", "2022-367"] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        let query = ISO8601_ORDINAL_DATE_BASIC.compile().unwrap();
+        assert!(query.is_match("2022366"));
+        assert!(!query.is_match("2022-108"));
+    }
+
+    #[test]
+    fn iso8601_date_time() {
+        let query = ISO8601_DATE_TIME.compile().unwrap();
+        for v in [
+            "2022-04-18T10:30",
+            "2022-04-18T10:30:05",
+            "2022-04-18T10:30:05.123Z",
+            "2022-04-18T10:30:05,5+02",
+            "2022-04-18T10:30-05:30",
+        ] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in [
+            "2022-04-18 10:30",
+            "2022-04-18T1030",
+            "2022-04-18T10:30:05+2",
+        ] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        let query = ISO8601_DATE_TIME_BASIC.compile().unwrap();
+        assert!(query.is_match("20220418T103005Z"));
+        assert!(query.is_match("20220418T1030+0530"));
+        assert!(!query.is_match("20220418T10:30"));
+    }
+
+    #[test]
+    fn rfc3339() {
+        let query = RFC3339.compile().unwrap();
+        assert!(query.is_match("2022-04-18T10:30:05Z"));
+        assert!(query.is_match("2022-04-18t10:30:05.99z"));
+        assert!(query.is_match("2022-04-18 10:30:05-08:00"));
+        assert!(!query.is_match("2022-04-18T10:30Z"));
+        assert!(!query.is_match("2022-04-18T10:30:05"));
+        assert!(!query.is_match("2022-04-18T10:30:05+08"));
+        assert!(!query.is_match("2022-04-18T10:30:05,5Z"));
+        assert!(!query.is_match("2022-04-18T10:30:05.\u{665}Z"));
+        assert_eq!(DateTime::parse("2022-04-18T10:30:05.\u{665}Z"), None);
+        assert_eq!(
+            DateTime::parse("2022-04-18T10:30:05,5Z").and_then(|t| t.nanosecond),
+            Some(500_000_000)
+        );
+    }
+
+    #[test]
+    fn iso8601_duration_and_interval() {
+        let query = ISO8601_DURATION.compile().unwrap();
+        assert!(query.is_match("P3Y6M4DT12H30M5S"));
+        assert!(query.is_match("P2W"));
+        assert!(query.is_match("PT0,5S"));
+        assert!(!query.is_match("P3S"));
+        assert!(!query.is_match("P1D2Y"));
+        let query = ISO8601_INTERVAL.compile().unwrap();
+        let captures = query.captures("R5/2022-04-18T10:30Z/P1M").unwrap();
+        assert_eq!(&captures["repetitions"], "5");
+        assert_eq!(&captures["start"], "2022-04-18T10:30Z");
+        assert_eq!(&captures["end"], "P1M");
+        assert!(query.is_match("2007-03-01/2007-03-31"));
+        assert!(!query.is_match("2007-03-01"));
+        assert!(!query.is_match("R\u{665}/2008-03-01T13:00:00Z/P1Y"));
+    }
+
+    #[test]
+    fn parse_components() {
+        assert_eq!(
+            DateTime::parse("20220418T103005Z"),
+            Some(DateTime {
+                year: 2022,
+                month: 4,
+                day: 18,
+                hour: 10,
+                minute: 30,
+                second: Some(5),
+                nanosecond: None,
+                offset_minutes: Some(0),
+            })
+        );
+        let date_time = DateTime::parse("2022-04-18T10:30-05:30").unwrap();
+        assert_eq!(date_time.second, None);
+        assert_eq!(date_time.offset_minutes, Some(-330));
+        assert!(DateTime::parse("2022-04-18").is_none());
+        let duration = Duration::parse("P1WT2H").unwrap();
+        assert_eq!((duration.weeks, duration.hours), (Some(1.0), Some(2.0)));
+        assert!(Duration::parse("P").is_none());
+        assert!(Duration::parse("P1DT").is_none());
+    }
+
+    #[test]
+    fn validate_dates() {
+        let date = |year, month, day| Ok(Date { year, month, day });
+        assert_eq!(validate_date("2024-02-29"), date(2024, 2, 29));
+        assert_eq!(validate_date("20000229"), date(2000, 2, 29));
+        assert_eq!(validate_date("2022/04/30"), date(2022, 4, 30));
+        assert_eq!(validate_date("31.12.2022"), date(2022, 12, 31));
+        assert_eq!(validate_date("2024-366"), date(2024, 12, 31));
+        assert_eq!(validate_date("2022-060"), date(2022, 3, 1));
+        assert_eq!(validate_date("2019-W01-1"), date(2018, 12, 31));
+        assert_eq!(validate_date("2019W011"), date(2018, 12, 31));
+        assert_eq!(validate_date("2022060"), date(2022, 3, 1));
+        assert_eq!(validate_date("2019-W011"), Err(DateError::Format));
+        assert_eq!(validate_date("2020-W53-5"), date(2021, 1, 1));
+        assert_eq!(validate_date("2021W527"), date(2022, 1, 2));
+        for (text, error) in [
+            (
+                "2022-02-29",
+                DateError::Day {
+                    year: 2022,
+                    month: 2,
+                    day: 29,
+                },
+            ),
+            (
+                "1900-02-29",
+                DateError::Day {
+                    year: 1900,
+                    month: 2,
+                    day: 29,
+                },
+            ),
+            (
+                "31/04/2022",
+                DateError::Day {
+                    year: 2022,
+                    month: 4,
+                    day: 31,
+                },
+            ),
+            (
+                "2022-366",
+                DateError::Ordinal {
+                    year: 2022,
+                    ordinal: 366,
+                },
+            ),
+            (
+                "2022-W53-1",
+                DateError::Week {
+                    year: 2022,
+                    week: 53,
+                },
+            ),
+            ("2022-4-18", DateError::Format),
+        ] {
+            assert_eq!(
+                validate_date(text),
+                Err(error),
+                "Failed validating: {}",
+                text
+            );
+        }
+        assert!(DateTime::parse("2022-02-30T10:30Z").is_none());
+    }
+
+    #[test]
+    fn weekday_and_ordinal() {
+        let date = Date::new(2022, 4, 18).unwrap();
+        assert_eq!(date.weekday(), 1);
+        assert_eq!(date.ordinal(), 108);
+        assert_eq!(Date::new(2000, 1, 1).unwrap().weekday(), 6);
+        assert_eq!(Date::from_ordinal(2022, 108), Ok(date));
+        assert_eq!(Date::from_iso_week(2022, 16, 1), Ok(date));
+    }
+
+    #[test]
+    fn year_bounds() {
+        let first = Date::new(0, 1, 1).unwrap();
+        assert_eq!(first.weekday(), 6);
+        assert_eq!(iso_weeks_in_year(0), 52);
+        assert_eq!(Date::from_iso_week(0, 1, 1), Date::new(0, 1, 3));
+        assert_eq!(Date::from_iso_week(0, 52, 7), Date::new(0, 12, 31));
+        assert_eq!(
+            Date::from_iso_week(0, 53, 1),
+            Err(DateError::Week { year: 0, week: 53 })
+        );
+        let last = Date::new(u16::MAX, 12, 31).unwrap();
+        assert_eq!(Date::new(u16::MAX, 1, 1).unwrap().weekday(), 2);
+        assert_eq!(last.weekday(), 2);
+        assert_eq!(iso_weeks_in_year(u16::MAX), 52);
+        assert_eq!(
+            Date::from_iso_week(u16::MAX, 1, 1),
+            Date::new(u16::MAX - 1, 12, 31)
+        );
+        assert_eq!(
+            Date::from_iso_week(u16::MAX, 52, 7),
+            Date::new(u16::MAX, 12, 29)
+        );
+    }
+
+    #[test]
+    fn textual_dates() {
+        let query = DATE_MONTH_D_Y.compile().unwrap();
+        let captures = query.captures("April 18, 2022").unwrap();
+        assert_eq!(
+            (&captures["month"], &captures["day"], &captures["year"]),
+            ("April", "18", "2022")
+        );
+        assert!(query.is_match("Apr. 8 2022"));
+        assert!(!query.is_match("Avril 18, 2022"));
+        let query = DATE_D_MONTH_Y.compile().unwrap();
+        assert!(query.is_match("18 Apr 2022"));
+        assert!(query.is_match("01 September 2022"));
+        assert!(!query.is_match("18 Apr, 2022"));
+    }
+
+    #[test]
+    fn rfc2822_and_http_date() {
+        let query = RFC2822.compile().unwrap();
+        for v in [
+            "Mon, 18 Apr 2022 10:00:00 GMT",
+            "18 Apr 2022 10:00 +0200",
+            "Mon, 8 Apr 2022 23:59:59 -0800",
+            "Thu, 1 Dec 2022 07:30 PDT",
+        ] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        assert!(!query.is_match("Mon, 18 Apr 2022 10:00:00 +02:00"));
+        assert!(!query.is_match("18 Apr 2022 10:00 +\u{660}\u{662}\u{660}\u{660}"));
+        let query = HTTP_DATE.compile().unwrap();
+        let captures = query.captures("Mon, 18 Apr 2022 10:00:00 GMT").unwrap();
+        assert_eq!(&captures["weekday"], "Mon");
+        assert_eq!(&captures["second"], "00");
+        assert!(!query.is_match("Mon, 8 Apr 2022 10:00:00 GMT"));
+        assert!(!query.is_match("Mon, 18 Apr 2022 10:00:00 +0000"));
+    }
 }
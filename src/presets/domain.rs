@@ -0,0 +1,223 @@
+//! Host and domain names, as per [RFC 1035](https://www.rfc-editor.org/rfc/rfc1035#section-2.3.1)
+//! and [RFC 1123](https://www.rfc-editor.org/rfc/rfc1123#page-13), and their internationalized
+//! forms
+use crate::solvers::{Chars, Trie};
+use crate::ReadableRe::{self, Period, Raw};
+use crate::{
+    chars, either, named_group, non_capture_group, one_or_more, optional, optional_lazy, ranged,
+    starts_and_ends_with, zero_or_more, zero_or_more_lazy,
+};
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// Letters and digits of internationalized labels
+const UNICODE_ALPHANUMERIC: &str = r"\p{L}\p{M}\p{N}";
+
+/// Public suffixes embedded from `public_suffixes.dat`
+const PUBLIC_SUFFIX_LIST: &str = include_str!("public_suffixes.dat");
+
+/// Label of 1 to 63 `alphanumeric` characters and inner hyphens, without capture groups
+fn label_of(alphanumeric: &str) -> ReadableRe<'static> {
+    let class = |extra: &str| ReadableRe::Chars(Chars::new(&format!("{alphanumeric}{extra}")));
+    non_capture_group(
+        class("") + optional(non_capture_group(ranged(0..=61, class("-")) + class(""))),
+    )
+}
+
+/// Label of 1 to 63 ASCII letters, digits and inner hyphens, without capture groups
+fn label() -> ReadableRe<'static> {
+    label_of("a-zA-Z0-9")
+}
+
+/// Punycode encoded label `xn--...`, without capture groups
+fn punycode_label() -> ReadableRe<'static> {
+    non_capture_group(
+        chars("xX")
+            + chars("nN")
+            + Raw("--")
+            + ranged(0..=58, chars("a-zA-Z0-9-"))
+            + chars("a-zA-Z0-9"),
+    )
+}
+
+/// Host name made of RFC 1123 labels, without capture groups
+pub(crate) fn hostname() -> ReadableRe<'static> {
+    label() + zero_or_more(non_capture_group(Period + label()))
+}
+
+/// Fully qualified domain name, without capture groups: two or more labels, the last of them
+/// alphabetic (or punycode), and an optional trailing dot. Labels can have any Unicode letter
+/// when `unicode`.
+pub fn domain_name(unicode: bool) -> ReadableRe<'static> {
+    let label = if unicode {
+        label_of(UNICODE_ALPHANUMERIC)
+    } else {
+        label()
+    };
+    one_or_more(non_capture_group(label + Period)) + top_level_domain(unicode) + optional(Period)
+}
+
+/// Alphabetic or punycode top level domain, without capture groups
+pub(crate) fn top_level_domain(unicode: bool) -> ReadableRe<'static> {
+    let letters = if unicode { r"\p{L}\p{M}" } else { "a-zA-Z" };
+    non_capture_group(either([
+        punycode_label(),
+        ranged(2..=63, ReadableRe::Chars(Chars::new(letters))),
+    ]))
+}
+
+/// Suffixes under which names can be registered, `com`, `co.uk`...
+///
+/// Only a subset of the [Public Suffix List](https://publicsuffix.org/list/) is embedded: the
+/// generic and country code top level domains and their most used second level suffixes.
+pub fn public_suffixes() -> Vec<&'static str> {
+    PUBLIC_SUFFIX_LIST
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with("//"))
+        .collect()
+}
+
+/// Host name made of RFC 1123 labels, `localhost`, `www.example.com`
+pub const HOSTNAME: Lazy<ReadableRe> = Lazy::new(|| starts_and_ends_with(hostname()));
+
+/// Fully qualified domain name `www.example.com` (or `www.example.com.`), check
+/// [`is_domain_name`] for the total length
+pub const DOMAIN_NAME: Lazy<ReadableRe> = Lazy::new(|| starts_and_ends_with(domain_name(false)));
+
+/// Internationalized domain name, with Unicode or punycode labels, `bücher.example`
+pub const IDN_DOMAIN_NAME: Lazy<ReadableRe> = Lazy::new(|| starts_and_ends_with(domain_name(true)));
+
+/// Wildcard certificate name `*.example.com`
+pub const WILDCARD_DOMAIN_NAME: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(Raw(r"\*\.") + domain_name(false)));
+
+/// Punycode encoded label `xn--bcher-kva`
+pub const PUNYCODE_LABEL: Lazy<ReadableRe> = Lazy::new(|| starts_and_ends_with(punycode_label()));
+
+/// Domain name ending with a known public suffix, see [`public_suffixes`], with `subdomain`,
+/// `domain` and `suffix` named groups. The longest suffix is preferred, so `www.example.co.uk`
+/// has the `example` domain and the `co.uk` suffix.
+pub const PUBLIC_SUFFIX_DOMAIN: Lazy<ReadableRe> = Lazy::new(|| {
+    let suffixes = Trie::new(public_suffixes()).case_insensitive(true);
+    let subdomain = named_group(
+        "subdomain",
+        label() + zero_or_more_lazy(non_capture_group(Period + label())),
+    );
+    starts_and_ends_with(
+        // the shortest subdomain leaves the longest suffix
+        optional_lazy(non_capture_group(subdomain + Period))
+            + named_group("domain", label())
+            + Period
+            + named_group("suffix", ReadableRe::Trie(suffixes))
+            + optional(Period),
+    )
+});
+
+static DOMAIN_NAME_FORMAT: Lazy<Regex> = Lazy::new(|| DOMAIN_NAME.compile().unwrap());
+
+/// Whether the text is a [`DOMAIN_NAME`] of at most 253 characters, without the trailing dot
+///
+/// ```
+/// use readable_regex::presets::domain::is_domain_name;
+/// assert!(is_domain_name("www.example.com"));
+/// assert!(!is_domain_name(&format!("{}com", "a.".repeat(126))));
+/// ```
+pub fn is_domain_name(text: &str) -> bool {
+    text.strip_suffix('.').unwrap_or(text).len() <= 253 && DOMAIN_NAME_FORMAT.is_match(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::presets::domain::{
+        is_domain_name, public_suffixes, DOMAIN_NAME, HOSTNAME, IDN_DOMAIN_NAME,
+        PUBLIC_SUFFIX_DOMAIN, PUNYCODE_LABEL, WILDCARD_DOMAIN_NAME,
+    };
+
+    #[test]
+    fn hostname() {
+        let query = HOSTNAME.compile().unwrap();
+        for v in [
+            "localhost",
+            "www.example.com",
+            "a-b.c1",
+            "1host",
+            &"a".repeat(63),
+        ] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in ["", "-a", "a-", "a..b", ".a", "a_b", &"a".repeat(64)] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+    }
+
+    #[test]
+    fn domain_name() {
+        let query = DOMAIN_NAME.compile().unwrap();
+        for v in [
+            "example.com",
+            "www.example.com.",
+            "a.xn--p1ai",
+            "x-1.example.museum",
+        ] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in [
+            "localhost",
+            "example.c",
+            "example.123",
+            "example.com..",
+            "-a.com",
+        ] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        assert!(is_domain_name(&format!("{}com.", "a.".repeat(125))));
+        assert!(!is_domain_name(&format!("{}com", "a.".repeat(126))));
+    }
+
+    #[test]
+    fn idn_and_punycode() {
+        let query = IDN_DOMAIN_NAME.compile().unwrap();
+        for v in [
+            "bücher.example",
+            "пример.рф",
+            "例え.テスト",
+            "xn--bcher-kva.example",
+        ] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        assert!(!query.is_match("bücher-.example"));
+        assert!(!DOMAIN_NAME.compile().unwrap().is_match("bücher.example"));
+        let query = PUNYCODE_LABEL.compile().unwrap();
+        assert!(query.is_match("xn--bcher-kva"));
+        assert!(query.is_match("XN--P1AI"));
+        assert!(!query.is_match("xn--"));
+        assert!(!query.is_match("xn--bcher-"));
+    }
+
+    #[test]
+    fn wildcard() {
+        let query = WILDCARD_DOMAIN_NAME.compile().unwrap();
+        assert!(query.is_match("*.example.com"));
+        assert!(!query.is_match("*.com."));
+        assert!(!query.is_match("www.*.example.com"));
+        assert!(!query.is_match("example.com"));
+    }
+
+    #[test]
+    fn public_suffix() {
+        assert!(public_suffixes().contains(&"co.uk"));
+        let query = PUBLIC_SUFFIX_DOMAIN.compile().unwrap();
+        let captures = query.captures("www.example.co.uk").unwrap();
+        assert_eq!(&captures["subdomain"], "www");
+        assert_eq!(&captures["domain"], "example");
+        assert_eq!(&captures["suffix"], "co.uk");
+        let captures = query.captures("a.b.example.COM").unwrap();
+        assert_eq!(&captures["subdomain"], "a.b");
+        assert_eq!(&captures["domain"], "example");
+        assert_eq!(&captures["suffix"], "COM");
+        let captures = query.captures("example.jp").unwrap();
+        assert!(captures.name("subdomain").is_none());
+        assert!(!query.is_match("example.invalid"));
+    }
+}
@@ -0,0 +1,384 @@
+//! Financial identifiers: IBAN, BIC, payment card numbers and securities identifiers. A format
+//! alone matches many wrong numbers, so each of them has a [`Validator`] also running its
+//! checksum.
+use crate::presets::checksum::{self, Validator};
+use crate::solvers::Trie;
+use crate::ReadableRe::{self, Raw};
+use crate::{
+    chars, either, exactly, named_group, non_capture_group, numeric_range, optional,
+    starts_and_ends_with, RangeOptions,
+};
+use once_cell::sync::Lazy;
+use std::collections::BTreeMap;
+
+/// IBAN length of the countries using it
+pub const IBAN_LENGTHS: [(&str, usize); 77] = [
+    ("AD", 24),
+    ("AE", 23),
+    ("AL", 28),
+    ("AT", 20),
+    ("AZ", 28),
+    ("BA", 20),
+    ("BE", 16),
+    ("BG", 22),
+    ("BH", 22),
+    ("BR", 29),
+    ("BY", 28),
+    ("CH", 21),
+    ("CR", 22),
+    ("CY", 28),
+    ("CZ", 24),
+    ("DE", 22),
+    ("DK", 18),
+    ("DO", 28),
+    ("EE", 20),
+    ("EG", 29),
+    ("ES", 24),
+    ("FI", 18),
+    ("FO", 18),
+    ("FR", 27),
+    ("GB", 22),
+    ("GE", 22),
+    ("GI", 23),
+    ("GL", 18),
+    ("GR", 27),
+    ("GT", 28),
+    ("HR", 21),
+    ("HU", 28),
+    ("IE", 22),
+    ("IL", 23),
+    ("IQ", 23),
+    ("IS", 26),
+    ("IT", 27),
+    ("JO", 30),
+    ("KW", 30),
+    ("KZ", 20),
+    ("LB", 28),
+    ("LC", 32),
+    ("LI", 21),
+    ("LT", 20),
+    ("LU", 20),
+    ("LV", 21),
+    ("MC", 27),
+    ("MD", 24),
+    ("ME", 22),
+    ("MK", 19),
+    ("MR", 27),
+    ("MT", 31),
+    ("MU", 30),
+    ("NL", 18),
+    ("NO", 15),
+    ("PK", 24),
+    ("PL", 28),
+    ("PS", 29),
+    ("PT", 25),
+    ("QA", 29),
+    ("RO", 24),
+    ("RS", 22),
+    ("SA", 24),
+    ("SC", 31),
+    ("SE", 24),
+    ("SI", 19),
+    ("SK", 24),
+    ("SM", 27),
+    ("ST", 25),
+    ("SV", 28),
+    ("TL", 23),
+    ("TN", 24),
+    ("TR", 26),
+    ("UA", 29),
+    ("VA", 22),
+    ("VG", 24),
+    ("XK", 20),
+];
+
+/// IBAN length of the country, by its ISO 3166-1 alpha-2 code
+pub fn iban_length(country: &str) -> Option<usize> {
+    IBAN_LENGTHS
+        .iter()
+        .find(|(code, _)| *code == country)
+        .map(|&(_, length)| length)
+}
+
+/// IBAN of the exact length of its country, in electronic form `DE89370400440532013000` or in
+/// groups of four `DE89 3704 0044 0532 0130 00`, without capture groups
+pub fn iban() -> ReadableRe<'static> {
+    let mut countries: BTreeMap<usize, Vec<&str>> = BTreeMap::new();
+    for (country, length) in IBAN_LENGTHS {
+        countries.entry(length).or_default().push(country);
+    }
+    let alphanumeric = || chars("A-Z0-9");
+    non_capture_group(either(countries.into_iter().map(|(length, countries)| {
+        let bban = length - 4;
+        let mut grouped = exactly(
+            bban / 4,
+            non_capture_group(Raw(" ") + exactly(4, alphanumeric())),
+        );
+        if bban % 4 > 0 {
+            grouped = grouped + Raw(" ") + exactly(bban % 4, alphanumeric());
+        }
+        ReadableRe::Trie(Trie::new(countries))
+            + exactly(2, chars("0-9"))
+            + non_capture_group(either([exactly(bban, alphanumeric()), grouped]))
+    })))
+}
+
+/// IBAN, see [`iban`] and [`IBAN_VALIDATOR`]
+pub const IBAN: Lazy<ReadableRe> = Lazy::new(|| starts_and_ends_with(iban()));
+
+/// [`IBAN`] with a valid MOD 97-10 checksum
+pub static IBAN_VALIDATOR: Validator = Validator::new(|| IBAN.compile().unwrap(), checksum::iban);
+
+/// BIC (SWIFT code) `DEUTDEFF` or `DEUTDEFF500`, with `bank`, `country`, `location` and the
+/// optional `branch` named groups
+pub const BIC: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(
+        named_group("bank", exactly(4, chars("A-Z")))
+            + named_group("country", exactly(2, chars("A-Z")))
+            + named_group("location", exactly(2, chars("A-Z0-9")))
+            + optional(named_group("branch", exactly(3, chars("A-Z0-9")))),
+    )
+});
+
+/// Payment card networks, told apart by the first digits of their numbers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardNetwork {
+    /// `4`, 16 or 19 digits
+    Visa,
+    /// `51` to `55` and `2221` to `2720`, 16 digits
+    Mastercard,
+    /// `34` and `37`, 15 digits grouped 4-6-5
+    AmericanExpress,
+    /// `6011`, `644` to `649` and `65`, 16 digits
+    Discover,
+    /// `300` to `305`, `36` and `38`, 14 digits grouped 4-6-4
+    DinersClub,
+    /// `3528` to `3589`, 16 digits
+    Jcb,
+}
+
+impl CardNetwork {
+    pub const ALL: [CardNetwork; 6] = [
+        CardNetwork::Visa,
+        CardNetwork::Mastercard,
+        CardNetwork::AmericanExpress,
+        CardNetwork::Discover,
+        CardNetwork::DinersClub,
+        CardNetwork::Jcb,
+    ];
+
+    /// Ranges of the first four digits
+    fn prefixes(self) -> &'static [(i64, i64)] {
+        match self {
+            CardNetwork::Visa => &[(4000, 4999)],
+            CardNetwork::Mastercard => &[(5100, 5599), (2221, 2720)],
+            CardNetwork::AmericanExpress => &[(3400, 3499), (3700, 3799)],
+            CardNetwork::Discover => &[(6011, 6011), (6440, 6599)],
+            CardNetwork::DinersClub => &[(3000, 3059), (3600, 3699), (3800, 3899)],
+            CardNetwork::Jcb => &[(3528, 3589)],
+        }
+    }
+
+    /// Sizes of the digit groups, the first one being four digits
+    fn layouts(self) -> &'static [&'static [usize]] {
+        match self {
+            CardNetwork::Visa => &[&[4, 4, 4, 4], &[4, 4, 4, 4, 3]],
+            CardNetwork::AmericanExpress => &[&[4, 6, 5]],
+            CardNetwork::DinersClub => &[&[4, 6, 4]],
+            _ => &[&[4, 4, 4, 4]],
+        }
+    }
+}
+
+/// Card number of the `network`, with its digit groups optionally separated by spaces or
+/// hyphens, `4111 1111 1111 1111`, without capture groups
+pub fn card_number(network: CardNetwork) -> ReadableRe<'static> {
+    let first = non_capture_group(either(
+        network
+            .prefixes()
+            .iter()
+            .map(|&(min, max)| numeric_range(min, max, RangeOptions::new())),
+    ));
+    let layouts = network.layouts().iter().map(|layout| {
+        layout[1..].iter().fold(ReadableRe::Raw(""), |re, &size| {
+            re + optional(chars(" -")) + exactly(size, chars("0-9"))
+        })
+    });
+    first + non_capture_group(either(layouts))
+}
+
+/// Card number of any [`CardNetwork`], see [`CREDIT_CARD_VALIDATOR`]
+pub const CREDIT_CARD: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(non_capture_group(either(CardNetwork::ALL.map(card_number))))
+});
+
+/// Visa card number, see [`card_number`]
+pub const VISA: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(card_number(CardNetwork::Visa)));
+
+/// Mastercard card number, see [`card_number`]
+pub const MASTERCARD: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(card_number(CardNetwork::Mastercard)));
+
+/// American Express card number, see [`card_number`]
+pub const AMERICAN_EXPRESS: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(card_number(CardNetwork::AmericanExpress)));
+
+/// Discover card number, see [`card_number`]
+pub const DISCOVER: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(card_number(CardNetwork::Discover)));
+
+/// Diners Club card number, see [`card_number`]
+pub const DINERS_CLUB: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(card_number(CardNetwork::DinersClub)));
+
+/// JCB card number, see [`card_number`]
+pub const JCB: Lazy<ReadableRe> = Lazy::new(|| starts_and_ends_with(card_number(CardNetwork::Jcb)));
+
+/// [`CREDIT_CARD`] with a valid Luhn check digit
+pub static CREDIT_CARD_VALIDATOR: Validator =
+    Validator::new(|| CREDIT_CARD.compile().unwrap(), checksum::luhn);
+
+/// ISIN `US0378331005`, with `country`, `nsin` (national security identifier) and `check`
+/// named groups
+pub const ISIN: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(
+        named_group("country", exactly(2, chars("A-Z")))
+            + named_group("nsin", exactly(9, chars("A-Z0-9")))
+            + named_group("check", chars("0-9")),
+    )
+});
+
+/// [`ISIN`] with a valid check digit
+pub static ISIN_VALIDATOR: Validator = Validator::new(|| ISIN.compile().unwrap(), checksum::isin);
+
+/// CUSIP `037833100`, with `issuer`, `issue` and `check` named groups
+pub const CUSIP: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(
+        named_group("issuer", exactly(6, chars("A-Z0-9")))
+            + named_group("issue", exactly(2, chars("A-Z0-9*@#")))
+            + named_group("check", chars("0-9")),
+    )
+});
+
+/// [`CUSIP`] with a valid check digit
+pub static CUSIP_VALIDATOR: Validator =
+    Validator::new(|| CUSIP.compile().unwrap(), checksum::cusip);
+
+/// Legal Entity Identifier `5493001KJTIIGC8Y1R12`, with `lou` (issuing organization), `entity`
+/// and `check` named groups
+pub const LEI: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(
+        named_group("lou", exactly(4, chars("A-Z0-9")))
+            + named_group("entity", exactly(14, chars("A-Z0-9")))
+            + named_group("check", exactly(2, chars("0-9"))),
+    )
+});
+
+/// [`LEI`] with a valid MOD 97-10 checksum
+pub static LEI_VALIDATOR: Validator = Validator::new(|| LEI.compile().unwrap(), checksum::mod97);
+
+#[cfg(test)]
+mod tests {
+    use crate::presets::financial::{
+        iban_length, AMERICAN_EXPRESS, BIC, CREDIT_CARD, CREDIT_CARD_VALIDATOR, CUSIP,
+        CUSIP_VALIDATOR, DINERS_CLUB, DISCOVER, IBAN, IBAN_LENGTHS, IBAN_VALIDATOR, ISIN,
+        ISIN_VALIDATOR, JCB, LEI, LEI_VALIDATOR, MASTERCARD, VISA,
+    };
+
+    #[test]
+    fn iban() {
+        assert_eq!(iban_length("DE"), Some(22));
+        assert_eq!(iban_length("US"), None);
+        assert!(IBAN_LENGTHS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        let query = IBAN.compile().unwrap();
+        for v in [
+            "DE89370400440532013000",
+            "DE89 3704 0044 0532 0130 00",
+            "GB82 WEST 1234 5698 7654 32",
+            "NO93 8601 1117 947",
+        ] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in [
+            "DE8937040044053201300",
+            "DE89 3704 0044 0532 0130 0",
+            "US89370400440532013000",
+            "DE8937 0400 4405 3201 3000",
+        ] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        assert!(IBAN_VALIDATOR.is_valid("DE89 3704 0044 0532 0130 00"));
+        assert!(!IBAN_VALIDATOR.is_valid("DE88 3704 0044 0532 0130 00"));
+        assert!(!IBAN_VALIDATOR.is_valid("DE89 3704 0044 0532 0130"));
+    }
+
+    #[test]
+    fn bic() {
+        let query = BIC.compile().unwrap();
+        let captures = query.captures("DEUTDEFF500").unwrap();
+        assert_eq!(&captures["bank"], "DEUT");
+        assert_eq!(&captures["country"], "DE");
+        assert_eq!(&captures["location"], "FF");
+        assert_eq!(&captures["branch"], "500");
+        assert!(query.is_match("NWBKGB2L"));
+        for v in ["DEUTDEFF50", "DEUT1EFF", "deutdeff"] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+    }
+
+    #[test]
+    fn cards() {
+        for (preset, number) in [
+            (VISA, "4111 1111 1111 1111"),
+            (MASTERCARD, "5555-5555-5555-4444"),
+            (MASTERCARD, "2223003122003222"),
+            (AMERICAN_EXPRESS, "3782 822463 10005"),
+            (DISCOVER, "6011 1111 1111 1117"),
+            (DINERS_CLUB, "3056 930902 5904"),
+            (JCB, "3530111333300000"),
+        ] {
+            assert!(
+                preset.compile().unwrap().is_match(number),
+                "Failed matching: {}",
+                number
+            );
+            assert!(CREDIT_CARD_VALIDATOR.is_valid(number), "Failed: {}", number);
+        }
+        assert!(!VISA.compile().unwrap().is_match("5555555555554444"));
+        assert!(!MASTERCARD.compile().unwrap().is_match("2721000000000000"));
+        assert!(!AMERICAN_EXPRESS
+            .compile()
+            .unwrap()
+            .is_match("3782 8224 6310 005"));
+        let query = CREDIT_CARD.compile().unwrap();
+        assert!(query.is_match("4111111111111112"));
+        assert!(!CREDIT_CARD_VALIDATOR.is_valid("4111111111111112"));
+        assert!(!CREDIT_CARD_VALIDATOR.is_valid("1111111111111117"));
+    }
+
+    #[test]
+    fn securities() {
+        let captures = ISIN.compile().unwrap().captures("US0378331005").unwrap();
+        assert_eq!(&captures["country"], "US");
+        assert_eq!(&captures["nsin"], "037833100");
+        assert_eq!(&captures["check"], "5");
+        assert!(ISIN_VALIDATOR.is_valid("US0378331005"));
+        assert!(!ISIN_VALIDATOR.is_valid("US0378331006"));
+        let captures = CUSIP.compile().unwrap().captures("38259P508").unwrap();
+        assert_eq!(&captures["issuer"], "38259P");
+        assert_eq!(&captures["issue"], "50");
+        assert!(CUSIP_VALIDATOR.is_valid("38259P508"));
+        assert!(!CUSIP_VALIDATOR.is_valid("38259P509"));
+        let captures = LEI
+            .compile()
+            .unwrap()
+            .captures("5493001KJTIIGC8Y1R12")
+            .unwrap();
+        assert_eq!(&captures["lou"], "5493");
+        assert_eq!(&captures["check"], "12");
+        assert!(LEI_VALIDATOR.is_valid("5493001KJTIIGC8Y1R12"));
+        assert!(!LEI_VALIDATOR.is_valid("5493001KJTIIGC8Y1R21"));
+        assert!(!LEI_VALIDATOR.is_valid("5493001KJTIIGC8Y1R1"));
+    }
+}
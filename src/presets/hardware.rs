@@ -0,0 +1,319 @@
+//! Hardware identifiers: MAC addresses, EUI-64, IMEI and serial numbers
+use crate::presets::checksum::luhn;
+use crate::ReadableRe::{self, Hexadecimal, Raw};
+use crate::{
+    chars, either, exactly, named_group, non_capture_group, one_or_more, starts_and_ends_with,
+    zero_or_more,
+};
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// How the octets of a hardware address are written
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddressFormat {
+    /// `00:1a:2b:3c:4d:5e`
+    Colon,
+    /// `00-1A-2B-3C-4D-5E`
+    Hyphen,
+    /// Cisco style `001a.2b3c.4d5e`
+    Dotted,
+    /// `001A2B3C4D5E`
+    Bare,
+}
+
+impl AddressFormat {
+    pub const ALL: [AddressFormat; 4] = [
+        AddressFormat::Colon,
+        AddressFormat::Hyphen,
+        AddressFormat::Dotted,
+        AddressFormat::Bare,
+    ];
+}
+
+/// Hardware address of `octets` bytes (`6` for MAC addresses, `8` for EUI-64) written in the
+/// `format`, without capture groups
+///
+/// Panics if `octets` is `0`, or odd in the [`AddressFormat::Dotted`] format
+pub fn hardware_address(octets: usize, format: AddressFormat) -> ReadableRe<'static> {
+    assert!(octets > 0, "hardware address of 0 octets");
+    assert!(
+        format != AddressFormat::Dotted || octets.is_multiple_of(2),
+        "dotted hardware address of {octets} octets, not a whole number of 4 digits groups"
+    );
+    let groups = |size: usize, separator: &'static str| {
+        exactly(size, Hexadecimal)
+            + exactly(
+                octets * 2 / size - 1,
+                non_capture_group(Raw(separator) + exactly(size, Hexadecimal)),
+            )
+    };
+    match format {
+        AddressFormat::Colon => groups(2, ":"),
+        AddressFormat::Hyphen => groups(2, "-"),
+        AddressFormat::Dotted => groups(4, r"\."),
+        AddressFormat::Bare => exactly(octets * 2, Hexadecimal),
+    }
+}
+
+/// Hardware address in any of the formats, all of its separators being the same
+fn any_format(octets: usize) -> ReadableRe<'static> {
+    non_capture_group(either(
+        AddressFormat::ALL.map(|format| hardware_address(octets, format)),
+    ))
+}
+
+/// MAC address (EUI-48) in any [`AddressFormat`], with a single kind of separator
+pub const MAC_ADDRESS: Lazy<ReadableRe> = Lazy::new(|| starts_and_ends_with(any_format(6)));
+
+/// MAC address with `:` or `-` separators, the same one checked with a back reference
+/// (`fancy_regex` syntax, so not compiled by [`ReadableRe::compile`] when `re` is enabled)
+#[cfg(feature = "re-fancy")]
+pub const MAC_ADDRESS_BACK_REFERENCE: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(
+        exactly(2, Hexadecimal)
+            + crate::group(chars(":-"))
+            + exactly(2, Hexadecimal)
+            + exactly(
+                4,
+                non_capture_group(ReadableRe::Back1 + exactly(2, Hexadecimal)),
+            ),
+    )
+});
+
+/// EUI-64 identifier in any [`AddressFormat`], with a single kind of separator
+pub const EUI64: Lazy<ReadableRe> = Lazy::new(|| starts_and_ends_with(any_format(8)));
+
+/// Separators of the IMEI layouts: none, spaces or hyphens
+const IMEI_SEPARATORS: [&str; 3] = ["", " ", "-"];
+
+/// IMEI digits with the `separator` between their parts and `last` digits at the end, in the
+/// `tac`, `serial` and `last_name` named groups when `named`
+fn imei_layout(
+    separator: &'static str,
+    last: usize,
+    last_name: &'static str,
+    named: bool,
+) -> ReadableRe<'static> {
+    let part = |name, re| {
+        if named {
+            named_group(name, re)
+        } else {
+            re
+        }
+    };
+    part(
+        "tac",
+        exactly(2, chars("0-9")) + Raw(separator) + exactly(6, chars("0-9")),
+    ) + Raw(separator)
+        + part("serial", exactly(6, chars("0-9")))
+        + Raw(separator)
+        + part(last_name, exactly(last, chars("0-9")))
+}
+
+/// IMEI written with the `separator` (`""`, `" "` or `"-"`) between its parts,
+/// `AA-BBBBBB-CCCCCC-D`, with `tac` (type allocation code), `serial` and `check` digit named
+/// groups
+pub fn imei(separator: &'static str) -> ReadableRe<'static> {
+    imei_layout(separator, 1, "check", true)
+}
+
+/// IMEISV written with the `separator` between its parts, with `tac`, `serial` and `version`
+/// named groups
+pub fn imeisv(separator: &'static str) -> ReadableRe<'static> {
+    imei_layout(separator, 2, "version", true)
+}
+
+/// IMEI, 15 digits optionally written `AA-BBBBBB-CCCCCC-D` or with spaces, a single kind of
+/// separator, without capture groups (see [`imei`] for them). Use [`is_valid_imei`] to verify
+/// the check digit.
+pub const IMEI: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(non_capture_group(either(
+        IMEI_SEPARATORS.map(|separator| imei_layout(separator, 1, "check", false)),
+    )))
+});
+
+/// IMEISV, 16 digits with a two digits software version instead of the check digit, with a
+/// single kind of separator, without capture groups (see [`imeisv`] for them)
+pub const IMEISV: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(non_capture_group(either(
+        IMEI_SEPARATORS.map(|separator| imei_layout(separator, 2, "version", false)),
+    )))
+});
+
+static IMEI_FORMAT: Lazy<Regex> = Lazy::new(|| IMEI.compile().unwrap());
+
+/// Whether the text is an [`IMEI`] with a valid Luhn check digit
+///
+/// ```
+/// use readable_regex::presets::hardware::is_valid_imei;
+/// assert!(is_valid_imei("490154203237518"));
+/// assert!(is_valid_imei("49-015420-323751-8"));
+/// assert!(!is_valid_imei("490154203237519"));
+/// ```
+pub fn is_valid_imei(text: &str) -> bool {
+    IMEI_FORMAT.is_match(text) && luhn(text)
+}
+
+/// USB device identifier `046d:c52b`, with `vendor` and `product` named groups
+pub const USB_ID: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(
+        named_group("vendor", exactly(4, Hexadecimal))
+            + Raw(":")
+            + named_group("product", exactly(4, Hexadecimal)),
+    )
+});
+
+/// `groups` hyphen separated groups of `length` uppercase letters and digits, like the product
+/// keys `XXXXX-XXXXX-XXXXX-XXXXX-XXXXX`, without capture groups
+///
+/// Panics if `groups` or `length` is `0`
+pub fn serial_number(groups: usize, length: usize) -> ReadableRe<'static> {
+    assert!(
+        groups > 0 && length > 0,
+        "serial number of {groups} groups of {length} characters"
+    );
+    let group = || exactly(length, chars("A-Z0-9"));
+    group() + exactly(groups - 1, non_capture_group(Raw("-") + group()))
+}
+
+/// Serial number of uppercase letters and digits, optionally in hyphen separated groups,
+/// `SN-4C2X-0091`
+pub const SERIAL_NUMBER: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(
+        one_or_more(chars("A-Z0-9"))
+            + zero_or_more(non_capture_group(Raw("-") + one_or_more(chars("A-Z0-9")))),
+    )
+});
+
+/// Product key of five groups of five characters `XXXXX-XXXXX-XXXXX-XXXXX-XXXXX`
+pub const PRODUCT_KEY: Lazy<ReadableRe> = Lazy::new(|| starts_and_ends_with(serial_number(5, 5)));
+
+#[cfg(test)]
+mod tests {
+    use crate::presets::hardware::{
+        hardware_address, imeisv, is_valid_imei, serial_number, AddressFormat, EUI64, IMEI, IMEISV,
+        MAC_ADDRESS, PRODUCT_KEY, SERIAL_NUMBER, USB_ID,
+    };
+    use crate::starts_and_ends_with;
+
+    #[test]
+    fn mac_address() {
+        let query = MAC_ADDRESS.compile().unwrap();
+        for v in [
+            "00:1a:2b:3c:4d:5e",
+            "00-1A-2B-3C-4D-5E",
+            "001a.2b3c.4d5e",
+            "001A2B3C4D5E",
+        ] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in [
+            "00:1a:2b-3c:4d:5e",
+            "00:1a:2b:3c:4d",
+            "00:1a:2b:3c:4d:5e:6f",
+            "001a.2b3c:4d5e",
+            "00:1g:2b:3c:4d:5e",
+            "0:1a:2b:3c:4d:5e",
+        ] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        let query = starts_and_ends_with(hardware_address(6, AddressFormat::Dotted))
+            .compile()
+            .unwrap();
+        assert!(!query.is_match("00:1a:2b:3c:4d:5e"));
+    }
+
+    #[test]
+    #[should_panic(expected = "hardware address of 0 octets")]
+    fn empty_hardware_address() {
+        hardware_address(0, AddressFormat::Colon);
+    }
+
+    #[test]
+    #[should_panic(expected = "dotted hardware address of 1 octets")]
+    fn odd_dotted_hardware_address() {
+        hardware_address(1, AddressFormat::Dotted);
+    }
+
+    #[test]
+    #[should_panic(expected = "serial number of 0 groups of 4 characters")]
+    fn empty_serial_number() {
+        serial_number(0, 4);
+    }
+
+    #[cfg(feature = "re-fancy")]
+    #[test]
+    fn mac_address_back_reference() {
+        use crate::presets::hardware::MAC_ADDRESS_BACK_REFERENCE;
+        let query = fancy_regex::Regex::new(&MAC_ADDRESS_BACK_REFERENCE.to_string()).unwrap();
+        assert!(query.is_match("00:1a:2b:3c:4d:5e").unwrap());
+        assert!(query.is_match("00-1A-2B-3C-4D-5E").unwrap());
+        assert!(!query.is_match("00:1a:2b-3c:4d:5e").unwrap());
+    }
+
+    #[test]
+    fn eui64() {
+        let query = EUI64.compile().unwrap();
+        for v in [
+            "00:1a:2b:ff:fe:3c:4d:5e",
+            "00-1A-2B-FF-FE-3C-4D-5E",
+            "001a.2bff.fe3c.4d5e",
+        ] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        assert!(!query.is_match("00:1a:2b:3c:4d:5e"));
+    }
+
+    #[test]
+    fn imei() {
+        let captures = starts_and_ends_with(crate::presets::hardware::imei("-"))
+            .compile()
+            .unwrap()
+            .captures("49-015420-323751-8")
+            .unwrap();
+        assert_eq!(&captures["tac"], "49-015420");
+        assert_eq!(&captures["serial"], "323751");
+        assert_eq!(&captures["check"], "8");
+        let query = IMEI.compile().unwrap();
+        for v in [
+            "490154203237518",
+            "49 015420 323751 8",
+            "49-015420-323751-8",
+        ] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in ["49015420323751", "35-209900 176148-1", "35209900-176148-1"] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        assert!(is_valid_imei("35-209900-176148-1"));
+        assert!(!is_valid_imei("35-209900-176148-2"));
+        assert!(!is_valid_imei("3520990017614823"));
+        let query = starts_and_ends_with(imeisv("-")).compile().unwrap();
+        let captures = query.captures("35-209900-176148-23").unwrap();
+        assert_eq!(&captures["version"], "23");
+        let query = IMEISV.compile().unwrap();
+        assert!(query.is_match("35 209900 176148 23"));
+        assert!(!query.is_match("35-209900 176148-23"));
+    }
+
+    #[test]
+    fn usb_id() {
+        let captures = USB_ID.compile().unwrap().captures("046d:c52b").unwrap();
+        assert_eq!(&captures["vendor"], "046d");
+        assert_eq!(&captures["product"], "c52b");
+    }
+
+    #[test]
+    fn serial_numbers() {
+        let query = SERIAL_NUMBER.compile().unwrap();
+        for v in ["SN-4C2X-0091", "C02XK0JHJG5J", "A1"] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in ["", "sn-1", "A--1", "-A1", "A1-"] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        let query = PRODUCT_KEY.compile().unwrap();
+        assert!(query.is_match("VK7JG-NPHTM-C97JM-9MPGT-3V66T"));
+        assert!(!query.is_match("VK7JG-NPHTM-C97JM-9MPGT"));
+    }
+}
@@ -0,0 +1,326 @@
+//! Identifiers: UUIDs, ULIDs, KSUIDs, semantic versions and hashes
+use crate::ReadableRe::{self, Hexadecimal, Raw};
+use crate::{
+    chars, either, exactly, named_group, non_capture_group, one_or_more, optional, ranged,
+    starts_and_ends_with, zero_or_more,
+};
+use once_cell::sync::Lazy;
+
+/// How a UUID is written
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UuidForm {
+    /// `123e4567-e89b-12d3-a456-426614174000`
+    Plain,
+    /// `{123e4567-e89b-12d3-a456-426614174000}`
+    Braced,
+    /// `urn:uuid:123e4567-e89b-12d3-a456-426614174000`
+    Urn,
+}
+
+/// UUID of the given `version` (any of them when `None`) in the `form`, without capture groups.
+/// Versioned UUIDs also check the RFC 4122 variant, `8`, `9`, `a` or `b`.
+pub fn uuid(version: Option<u8>, form: UuidForm) -> ReadableRe<'static> {
+    let hex = |n| exactly(n, Hexadecimal);
+    let (version, variant) = match version {
+        Some(version) => (ReadableRe::String(format!("{version:x}")), chars("89abAB")),
+        None => (Hexadecimal, Hexadecimal),
+    };
+    let uuid = hex(8)
+        + Raw("-")
+        + hex(4)
+        + Raw("-")
+        + version
+        + hex(3)
+        + Raw("-")
+        + variant
+        + hex(3)
+        + Raw("-")
+        + hex(12);
+    match form {
+        UuidForm::Plain => uuid,
+        UuidForm::Braced => Raw(r"\{") + uuid + Raw(r"\}"),
+        UuidForm::Urn => chars("uU") + chars("rR") + chars("nN") + Raw(":uuid:") + uuid,
+    }
+}
+
+/// UUID of any version, `123e4567-e89b-12d3-a456-426614174000`
+pub const UUID: Lazy<ReadableRe> = Lazy::new(|| starts_and_ends_with(uuid(None, UuidForm::Plain)));
+
+/// Time based version 1 UUID
+pub const UUID_V1: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(uuid(Some(1), UuidForm::Plain)));
+
+/// Random version 4 UUID
+pub const UUID_V4: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(uuid(Some(4), UuidForm::Plain)));
+
+/// Unix time ordered version 7 UUID
+pub const UUID_V7: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(uuid(Some(7), UuidForm::Plain)));
+
+/// Braced UUID of any version, `{123e4567-e89b-12d3-a456-426614174000}`
+pub const UUID_BRACED: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(uuid(None, UuidForm::Braced)));
+
+/// URN of a UUID of any version, `urn:uuid:123e4567-e89b-12d3-a456-426614174000`
+pub const UUID_URN: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(uuid(None, UuidForm::Urn)));
+
+/// [ULID](https://github.com/ulid/spec), 26 Crockford's base32 characters, with `timestamp`
+/// and `randomness` named groups
+pub const ULID: Lazy<ReadableRe> = Lazy::new(|| {
+    let base32 = || chars("0-9A-HJKMNP-TV-Za-hjkmnp-tv-z");
+    starts_and_ends_with(
+        named_group("timestamp", chars("0-7") + exactly(9, base32()))
+            + named_group("randomness", exactly(16, base32())),
+    )
+});
+
+/// [KSUID](https://github.com/segmentio/ksuid), 27 base62 characters
+pub const KSUID: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(exactly(27, chars("0-9A-Za-z"))));
+
+/// Version number without leading zeros, `0`, `1`, `42`
+pub(crate) fn version_number() -> ReadableRe<'static> {
+    non_capture_group(either([
+        Raw("0"),
+        chars("1-9") + zero_or_more(chars("0-9")),
+    ]))
+}
+
+/// Dot separated identifiers made of `identifier`
+pub(crate) fn dot_separated(identifier: ReadableRe<'static>) -> ReadableRe<'static> {
+    identifier.clone() + zero_or_more(non_capture_group(Raw(r"\.") + identifier))
+}
+
+/// Pre-release identifiers, numeric ones without leading zeros
+pub(crate) fn prerelease() -> ReadableRe<'static> {
+    dot_separated(non_capture_group(either([
+        version_number(),
+        zero_or_more(chars("0-9")) + chars("a-zA-Z-") + zero_or_more(chars("0-9a-zA-Z-")),
+    ])))
+}
+
+/// [Semantic Versioning 2.0.0](https://semver.org) version `1.0.0-alpha.1+build.5`, with `major`,
+/// `minor`, `patch`, `prerelease` and `build` named groups
+pub const SEMVER: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(
+        named_group("major", version_number())
+            + Raw(r"\.")
+            + named_group("minor", version_number())
+            + Raw(r"\.")
+            + named_group("patch", version_number())
+            + optional(non_capture_group(
+                Raw("-") + named_group("prerelease", prerelease()),
+            ))
+            + optional(non_capture_group(
+                Raw(r"\+") + named_group("build", dot_separated(one_or_more(chars("0-9a-zA-Z-")))),
+            )),
+    )
+});
+
+/// Cargo style version requirement `^1.2`, `>=1.0, <2.0.0-0`, `1.*` or `*`
+pub const VERSION_REQUIREMENT: Lazy<ReadableRe> = Lazy::new(|| {
+    let wildcard = || chars("*xX");
+    let part = || non_capture_group(either([version_number(), wildcard()]));
+    let operator = non_capture_group(either([">=", "<=", ">", "<", "=", "~", r"\^"].map(Raw)));
+    let comparator = || {
+        optional(operator.clone())
+            + zero_or_more(chars(" "))
+            + part()
+            + optional(non_capture_group(
+                Raw(r"\.")
+                    + part()
+                    + optional(non_capture_group(
+                        Raw(r"\.") + part() + optional(non_capture_group(Raw("-") + prerelease())),
+                    )),
+            ))
+    };
+    starts_and_ends_with(
+        comparator()
+            + zero_or_more(non_capture_group(
+                zero_or_more(chars(" ")) + Raw(",") + zero_or_more(chars(" ")) + comparator(),
+            )),
+    )
+});
+
+/// `bits` long hexadecimal digest, in any case, without capture groups
+pub fn hex_digest(bits: usize) -> ReadableRe<'static> {
+    exactly(bits / 4, Hexadecimal)
+}
+
+/// Git object name, lowercase hexadecimal with `bits` (`160` for SHA-1, `256` for SHA-256), or
+/// abbreviated to its first 7 or more characters when `short`
+fn git_object(bits: usize, short: bool) -> ReadableRe<'static> {
+    let digits = bits / 4;
+    ranged(if short { 7 } else { digits }..=digits, chars("0-9a-f"))
+}
+
+/// Full git SHA-1 object name, 40 lowercase hexadecimal digits
+pub const GIT_SHA1: Lazy<ReadableRe> = Lazy::new(|| starts_and_ends_with(git_object(160, false)));
+
+/// Abbreviated git SHA-1 object name, 7 to 40 lowercase hexadecimal digits
+pub const GIT_SHA1_SHORT: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(git_object(160, true)));
+
+/// Full git SHA-256 object name, 64 lowercase hexadecimal digits
+pub const GIT_SHA256: Lazy<ReadableRe> = Lazy::new(|| starts_and_ends_with(git_object(256, false)));
+
+/// Abbreviated git SHA-256 object name, 7 to 64 lowercase hexadecimal digits
+pub const GIT_SHA256_SHORT: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(git_object(256, true)));
+
+/// MD5 hex digest, 32 hexadecimal digits
+pub const MD5: Lazy<ReadableRe> = Lazy::new(|| starts_and_ends_with(hex_digest(128)));
+
+/// SHA-1 hex digest, 40 hexadecimal digits
+pub const SHA1: Lazy<ReadableRe> = Lazy::new(|| starts_and_ends_with(hex_digest(160)));
+
+/// SHA-256 hex digest, 64 hexadecimal digits
+pub const SHA256: Lazy<ReadableRe> = Lazy::new(|| starts_and_ends_with(hex_digest(256)));
+
+/// SHA-512 hex digest, 128 hexadecimal digits
+pub const SHA512: Lazy<ReadableRe> = Lazy::new(|| starts_and_ends_with(hex_digest(512)));
+
+#[cfg(test)]
+mod tests {
+    use crate::presets::identifiers::{
+        GIT_SHA1, GIT_SHA1_SHORT, GIT_SHA256, GIT_SHA256_SHORT, KSUID, MD5, SEMVER, SHA1, SHA256,
+        SHA512, ULID, UUID, UUID_BRACED, UUID_URN, UUID_V1, UUID_V4, UUID_V7, VERSION_REQUIREMENT,
+    };
+
+    #[test]
+    fn uuid() {
+        let query = UUID.compile().unwrap();
+        assert!(query.is_match("123e4567-e89b-12d3-a456-426614174000"));
+        assert!(query.is_match("00000000-0000-0000-0000-000000000000"));
+        assert!(!query.is_match("123e4567e89b12d3a456426614174000"));
+        assert!(!query.is_match("123e4567-e89b-12d3-a456-42661417400g"));
+    }
+
+    #[test]
+    fn uuid_versions() {
+        let query = UUID_V1.compile().unwrap();
+        assert!(query.is_match("c232ab00-9414-11ec-b3c8-9f6bdeced846"));
+        assert!(!query.is_match("c232ab00-9414-41ec-b3c8-9f6bdeced846"));
+        let query = UUID_V4.compile().unwrap();
+        assert!(query.is_match("919108f7-52d1-4320-9bac-f847db4148a8"));
+        assert!(query.is_match("919108F7-52D1-4320-BBAC-F847DB4148A8"));
+        assert!(!query.is_match("919108f7-52d1-4320-7bac-f847db4148a8"));
+        let query = UUID_V7.compile().unwrap();
+        assert!(query.is_match("017f22e2-79b0-7cc3-98c4-dc0c0c07398f"));
+        assert!(!query.is_match("017f22e2-79b0-4cc3-98c4-dc0c0c07398f"));
+    }
+
+    #[test]
+    fn uuid_forms() {
+        let query = UUID_BRACED.compile().unwrap();
+        assert!(query.is_match("{123e4567-e89b-12d3-a456-426614174000}"));
+        assert!(!query.is_match("{123e4567-e89b-12d3-a456-426614174000"));
+        let query = UUID_URN.compile().unwrap();
+        assert!(query.is_match("urn:uuid:123e4567-e89b-12d3-a456-426614174000"));
+        assert!(!query.is_match("uuid:123e4567-e89b-12d3-a456-426614174000"));
+    }
+
+    #[test]
+    fn ulid() {
+        let query = ULID.compile().unwrap();
+        let captures = query.captures("01ARZ3NDEKTSV4RRFFQ69G5FAV").unwrap();
+        assert_eq!(&captures["timestamp"], "01ARZ3NDEK");
+        assert_eq!(&captures["randomness"], "TSV4RRFFQ69G5FAV");
+        assert!(!query.is_match("81ARZ3NDEKTSV4RRFFQ69G5FAV"));
+        assert!(!query.is_match("01ARZ3NDEKTSV4RRFFQ69G5FAU"));
+        assert!(!query.is_match("01ARZ3NDEKTSV4RRFFQ69G5FA"));
+    }
+
+    #[test]
+    fn ksuid() {
+        let query = KSUID.compile().unwrap();
+        assert!(query.is_match("0ujtsYcgvSTl8PAuAdqWYSMnLOv"));
+        assert!(!query.is_match("0ujtsYcgvSTl8PAuAdqWYSMnLO"));
+        assert!(!query.is_match("0ujtsYcgvSTl8PAuAdqWYSMnLO-"));
+    }
+
+    #[test]
+    fn semver() {
+        let query = SEMVER.compile().unwrap();
+        for v in [
+            "0.0.4",
+            "1.2.3",
+            "10.20.30",
+            "1.0.0-alpha",
+            "1.0.0-alpha.beta.1",
+            "1.0.0-0A.is.legal",
+            "1.0.0-alpha+001",
+            "1.0.0+21AF26D3----117B344092BD",
+        ] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in [
+            "1",
+            "1.2",
+            "01.1.1",
+            "1.2.3-0123",
+            "1.2.3-alpha..1",
+            "1.2.3+",
+            "1.2.3-+build",
+            "v1.2.3",
+        ] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        let captures = query.captures("1.2.3-rc.1+build.5").unwrap();
+        assert_eq!(&captures["major"], "1");
+        assert_eq!(&captures["minor"], "2");
+        assert_eq!(&captures["patch"], "3");
+        assert_eq!(&captures["prerelease"], "rc.1");
+        assert_eq!(&captures["build"], "build.5");
+    }
+
+    #[test]
+    fn version_requirement() {
+        let query = VERSION_REQUIREMENT.compile().unwrap();
+        for v in [
+            "1.2.3",
+            "^1.2",
+            "~1",
+            "=1.2.3-beta.1",
+            ">= 1.0, < 2.0.0-0",
+            "1.*",
+            "*",
+            "1.2.x",
+        ] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in ["", "1.2.3.4", "=>1", ">=1,", "^01"] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+    }
+
+    #[test]
+    fn git_hashes() {
+        let sha1 = "e83c5163316f89bfbde7d9ab23ca2e25604af290";
+        assert!(GIT_SHA1.compile().unwrap().is_match(sha1));
+        assert!(!GIT_SHA1.compile().unwrap().is_match(&sha1[..7]));
+        assert!(!GIT_SHA1.compile().unwrap().is_match(&sha1.to_uppercase()));
+        let query = GIT_SHA1_SHORT.compile().unwrap();
+        assert!(query.is_match(&sha1[..7]));
+        assert!(query.is_match(sha1));
+        assert!(!query.is_match(&sha1[..6]));
+        let sha256 = "b".repeat(64);
+        assert!(GIT_SHA256.compile().unwrap().is_match(&sha256));
+        assert!(!GIT_SHA256.compile().unwrap().is_match(sha1));
+        assert!(GIT_SHA256_SHORT.compile().unwrap().is_match(&sha256[..12]));
+    }
+
+    #[test]
+    fn digests() {
+        let query = MD5.compile().unwrap();
+        assert!(query.is_match("d41d8cd98f00b204e9800998ecf8427e"));
+        assert!(query.is_match("D41D8CD98F00B204E9800998ECF8427E"));
+        assert!(!query.is_match("d41d8cd98f00b204e9800998ecf8427"));
+        assert!(SHA1.compile().unwrap().is_match(&"a".repeat(40)));
+        assert!(SHA256.compile().unwrap().is_match(&"a".repeat(64)));
+        assert!(!SHA256.compile().unwrap().is_match(&"a".repeat(63)));
+        assert!(SHA512.compile().unwrap().is_match(&"a".repeat(128)));
+    }
+}
@@ -0,0 +1,379 @@
+//! Month and weekday names in several languages
+use crate::solvers::Trie;
+use crate::ReadableRe;
+use once_cell::sync::Lazy;
+
+/// Languages with known month and weekday names
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Locale {
+    English,
+    Spanish,
+    French,
+    German,
+    Portuguese,
+    Italian,
+}
+
+/// Which forms of the names to match
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Form {
+    /// `January`, `Monday`
+    Full,
+    /// `Jan`, `Mon`
+    Abbreviated,
+    /// Full and abbreviated
+    Any,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 6] = [
+        Locale::English,
+        Locale::Spanish,
+        Locale::French,
+        Locale::German,
+        Locale::Portuguese,
+        Locale::Italian,
+    ];
+
+    /// Month names, from January, as usually written in the middle of a sentence
+    pub const fn months(self) -> [&'static str; 12] {
+        match self {
+            Locale::English => [
+                "January",
+                "February",
+                "March",
+                "April",
+                "May",
+                "June",
+                "July",
+                "August",
+                "September",
+                "October",
+                "November",
+                "December",
+            ],
+            Locale::Spanish => [
+                "enero",
+                "febrero",
+                "marzo",
+                "abril",
+                "mayo",
+                "junio",
+                "julio",
+                "agosto",
+                "septiembre",
+                "octubre",
+                "noviembre",
+                "diciembre",
+            ],
+            Locale::French => [
+                "janvier",
+                "février",
+                "mars",
+                "avril",
+                "mai",
+                "juin",
+                "juillet",
+                "août",
+                "septembre",
+                "octobre",
+                "novembre",
+                "décembre",
+            ],
+            Locale::German => [
+                "Januar",
+                "Februar",
+                "März",
+                "April",
+                "Mai",
+                "Juni",
+                "Juli",
+                "August",
+                "September",
+                "Oktober",
+                "November",
+                "Dezember",
+            ],
+            Locale::Portuguese => [
+                "janeiro",
+                "fevereiro",
+                "março",
+                "abril",
+                "maio",
+                "junho",
+                "julho",
+                "agosto",
+                "setembro",
+                "outubro",
+                "novembro",
+                "dezembro",
+            ],
+            Locale::Italian => [
+                "gennaio",
+                "febbraio",
+                "marzo",
+                "aprile",
+                "maggio",
+                "giugno",
+                "luglio",
+                "agosto",
+                "settembre",
+                "ottobre",
+                "novembre",
+                "dicembre",
+            ],
+        }
+    }
+
+    /// Abbreviated month names, from January, without the trailing period
+    pub const fn months_abbreviated(self) -> [&'static str; 12] {
+        match self {
+            Locale::English => [
+                "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+            ],
+            Locale::Spanish => [
+                "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sep", "oct", "nov", "dic",
+            ],
+            Locale::French => [
+                "janv", "févr", "mars", "avr", "mai", "juin", "juil", "août", "sept", "oct", "nov",
+                "déc",
+            ],
+            Locale::German => [
+                "Jan", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez",
+            ],
+            Locale::Portuguese => [
+                "jan", "fev", "mar", "abr", "mai", "jun", "jul", "ago", "set", "out", "nov", "dez",
+            ],
+            Locale::Italian => [
+                "gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic",
+            ],
+        }
+    }
+
+    /// Weekday names, from Monday
+    pub const fn weekdays(self) -> [&'static str; 7] {
+        match self {
+            Locale::English => [
+                "Monday",
+                "Tuesday",
+                "Wednesday",
+                "Thursday",
+                "Friday",
+                "Saturday",
+                "Sunday",
+            ],
+            Locale::Spanish => [
+                "lunes",
+                "martes",
+                "miércoles",
+                "jueves",
+                "viernes",
+                "sábado",
+                "domingo",
+            ],
+            Locale::French => [
+                "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche",
+            ],
+            Locale::German => [
+                "Montag",
+                "Dienstag",
+                "Mittwoch",
+                "Donnerstag",
+                "Freitag",
+                "Samstag",
+                "Sonntag",
+            ],
+            Locale::Portuguese => [
+                "segunda-feira",
+                "terça-feira",
+                "quarta-feira",
+                "quinta-feira",
+                "sexta-feira",
+                "sábado",
+                "domingo",
+            ],
+            Locale::Italian => [
+                "lunedì",
+                "martedì",
+                "mercoledì",
+                "giovedì",
+                "venerdì",
+                "sabato",
+                "domenica",
+            ],
+        }
+    }
+
+    /// Abbreviated weekday names, from Monday, without the trailing period
+    pub const fn weekdays_abbreviated(self) -> [&'static str; 7] {
+        match self {
+            Locale::English => ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+            Locale::Spanish => ["lun", "mar", "mié", "jue", "vie", "sáb", "dom"],
+            Locale::French => ["lun", "mar", "mer", "jeu", "ven", "sam", "dim"],
+            Locale::German => ["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"],
+            Locale::Portuguese => ["seg", "ter", "qua", "qui", "sex", "sáb", "dom"],
+            Locale::Italian => ["lun", "mar", "mer", "gio", "ven", "sab", "dom"],
+        }
+    }
+}
+
+fn names<const N: usize>(
+    locales: &[Locale],
+    form: Form,
+    full: fn(Locale) -> [&'static str; N],
+    abbreviated: fn(Locale) -> [&'static str; N],
+) -> Trie {
+    let mut words = Vec::new();
+    for &locale in locales {
+        if form != Form::Abbreviated {
+            words.extend(full(locale));
+        }
+        if form != Form::Full {
+            words.extend(abbreviated(locale));
+        }
+    }
+    words.sort_unstable();
+    words.dedup();
+    Trie::new(words)
+}
+
+/// Month names of the locales, as a [`Trie`] to further configure case and word boundaries
+///
+/// ```
+/// use readable_regex::presets::locale::{month_names, Form, Locale};
+/// use readable_regex::ReadableRe;
+/// let months = ReadableRe::Trie(month_names(&[Locale::Spanish], Form::Full).case_insensitive(true));
+/// assert!(months.compile().unwrap().is_match("Septiembre"));
+/// ```
+pub fn month_names(locales: &[Locale], form: Form) -> Trie {
+    names(locales, form, Locale::months, Locale::months_abbreviated)
+}
+
+/// Weekday names of the locales, as a [`Trie`] to further configure case and word boundaries
+pub fn weekday_names(locales: &[Locale], form: Form) -> Trie {
+    names(
+        locales,
+        form,
+        Locale::weekdays,
+        Locale::weekdays_abbreviated,
+    )
+}
+
+/// Full month name in any of the [`Locale::ALL`] languages, as usually written
+pub const MONTH_NAME: Lazy<ReadableRe> =
+    Lazy::new(|| ReadableRe::Trie(month_names(&Locale::ALL, Form::Full).word_boundaries(true)));
+
+/// Full month name in any of the [`Locale::ALL`] languages, ignoring case
+pub const MONTH_NAME_IGNORE_CASE: Lazy<ReadableRe> = Lazy::new(|| {
+    ReadableRe::Trie(
+        month_names(&Locale::ALL, Form::Full)
+            .word_boundaries(true)
+            .case_insensitive(true),
+    )
+});
+
+/// Abbreviated month name in any of the [`Locale::ALL`] languages, as usually written
+pub const MONTH_ABBREVIATION: Lazy<ReadableRe> = Lazy::new(|| {
+    ReadableRe::Trie(month_names(&Locale::ALL, Form::Abbreviated).word_boundaries(true))
+});
+
+/// Abbreviated month name in any of the [`Locale::ALL`] languages, ignoring case
+pub const MONTH_ABBREVIATION_IGNORE_CASE: Lazy<ReadableRe> = Lazy::new(|| {
+    ReadableRe::Trie(
+        month_names(&Locale::ALL, Form::Abbreviated)
+            .word_boundaries(true)
+            .case_insensitive(true),
+    )
+});
+
+/// Full weekday name in any of the [`Locale::ALL`] languages, as usually written
+pub const WEEKDAY_NAME: Lazy<ReadableRe> =
+    Lazy::new(|| ReadableRe::Trie(weekday_names(&Locale::ALL, Form::Full).word_boundaries(true)));
+
+/// Full weekday name in any of the [`Locale::ALL`] languages, ignoring case
+pub const WEEKDAY_NAME_IGNORE_CASE: Lazy<ReadableRe> = Lazy::new(|| {
+    ReadableRe::Trie(
+        weekday_names(&Locale::ALL, Form::Full)
+            .word_boundaries(true)
+            .case_insensitive(true),
+    )
+});
+
+/// Abbreviated weekday name in any of the [`Locale::ALL`] languages, as usually written
+pub const WEEKDAY_ABBREVIATION: Lazy<ReadableRe> = Lazy::new(|| {
+    ReadableRe::Trie(weekday_names(&Locale::ALL, Form::Abbreviated).word_boundaries(true))
+});
+
+/// Abbreviated weekday name in any of the [`Locale::ALL`] languages, ignoring case
+pub const WEEKDAY_ABBREVIATION_IGNORE_CASE: Lazy<ReadableRe> = Lazy::new(|| {
+    ReadableRe::Trie(
+        weekday_names(&Locale::ALL, Form::Abbreviated)
+            .word_boundaries(true)
+            .case_insensitive(true),
+    )
+});
+
+#[cfg(test)]
+mod tests {
+    use crate::presets::locale::{
+        month_names, weekday_names, Form, Locale, MONTH_ABBREVIATION, MONTH_NAME,
+        MONTH_NAME_IGNORE_CASE, WEEKDAY_ABBREVIATION_IGNORE_CASE, WEEKDAY_NAME,
+    };
+    use crate::{starts_and_ends_with, ReadableRe};
+
+    #[test]
+    fn month_name() {
+        let query = starts_and_ends_with(MONTH_NAME.clone()).compile().unwrap();
+        for locale in Locale::ALL {
+            for month in locale.months() {
+                assert!(query.is_match(month), "Failed matching: {}", month);
+            }
+        }
+        assert!(!query.is_match("Jan"));
+        assert!(!query.is_match("MARZO"));
+        let query = MONTH_NAME_IGNORE_CASE.compile().unwrap();
+        assert!(query.is_match("MARZO"));
+        assert!(query.is_match("FÉVRIER"));
+        assert!(!query.is_match("marzoo"));
+    }
+
+    #[test]
+    fn month_abbreviation() {
+        let query = starts_and_ends_with(MONTH_ABBREVIATION.clone())
+            .compile()
+            .unwrap();
+        for v in ["Jan", "Okt", "févr", "mag", "dez"] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        assert!(!query.is_match("January"));
+    }
+
+    #[test]
+    fn weekday_name() {
+        let query = WEEKDAY_NAME.compile().unwrap();
+        for locale in Locale::ALL {
+            for weekday in locale.weekdays() {
+                assert!(query.is_match(weekday), "Failed matching: {}", weekday);
+            }
+        }
+        let query = WEEKDAY_ABBREVIATION_IGNORE_CASE.compile().unwrap();
+        assert!(query.is_match("on MON."));
+        assert!(query.is_match("mié"));
+        assert!(!query.is_match("Monday"));
+    }
+
+    #[test]
+    fn selected_locales() {
+        let query = ReadableRe::Trie(month_names(&[Locale::German], Form::Any))
+            .compile()
+            .unwrap();
+        assert!(query.is_match("März"));
+        assert!(query.is_match("Mär"));
+        assert!(!query.is_match("marzo"));
+        let query = ReadableRe::Trie(weekday_names(&[Locale::English], Form::Abbreviated))
+            .compile()
+            .unwrap();
+        assert!(query.is_match("Thu"));
+        assert!(!query.is_match("lundi"));
+    }
+}
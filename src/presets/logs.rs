@@ -0,0 +1,605 @@
+//! Log lines: web server access logs, syslog, logfmt and the Rust logging crates output
+use crate::presets::datetime::{rfc3339, DAY, HOURS_24, MIN_SEC, YEAR};
+use crate::presets::domain::hostname;
+use crate::presets::locale::{month_names, Form, Locale};
+use crate::presets::network::{dotted_quad, ipv6_address};
+use crate::presets::uri::{uri, UriKind};
+use crate::ReadableRe::{self, Everything, Raw};
+use crate::{
+    chars, either, exactly, named_group, non_capture_group, not_chars, numeric_range, one_or_more,
+    optional, ranged, starts_and_ends_with, zero_or_more, RangeOptions,
+};
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+
+/// Text with `\"` escapes, up to the closing double quote, without capture groups
+fn escaped() -> ReadableRe<'static> {
+    zero_or_more(non_capture_group(either([
+        not_chars(r#""\\"#),
+        Raw(r"\\") + ReadableRe::AnyChar,
+    ])))
+}
+
+/// Double quoted text, its content in the `name` group
+fn quoted(name: &'static str) -> ReadableRe<'static> {
+    Raw("\"") + named_group(name, escaped()) + Raw("\"")
+}
+
+/// Named group when `named`, non capturing group otherwise
+fn part(named: bool, name: &'static str, re: ReadableRe<'static>) -> ReadableRe<'static> {
+    if named {
+        named_group(name, re)
+    } else {
+        non_capture_group(re)
+    }
+}
+
+/// Text up to the next space
+fn field() -> ReadableRe<'static> {
+    one_or_more(not_chars(" "))
+}
+
+/// Common Log Format timestamp `10/Oct/2000:13:55:36 -0700`, without capture groups
+fn clf_timestamp() -> ReadableRe<'static> {
+    DAY.clone()
+        + Raw("/")
+        + ReadableRe::Trie(month_names(&[Locale::English], Form::Abbreviated))
+        + Raw("/")
+        + YEAR.clone()
+        + Raw(":")
+        + HOURS_24.clone()
+        + Raw(":")
+        + MIN_SEC.clone()
+        + Raw(":")
+        + MIN_SEC.clone()
+        + Raw(" ")
+        + chars("+-")
+        + exactly(4, chars("0-9"))
+}
+
+/// Apache/Nginx access log line, in the
+/// [Common Log Format](https://httpd.apache.org/docs/2.4/logs.html#common) or, when `combined`,
+/// the Combined Log Format (with the referer and user agent), without anchors.
+///
+/// Named groups: `client` (IPv4, IPv6 or host name), `ident`, `user`, `timestamp`, `request`,
+/// `method`, `target`, `protocol`, `status`, `size`, and `referer` and `user_agent` when
+/// `combined`. A request target in origin form `/path?query` is further split by the
+/// [`uri`] named groups (`path`, `query`...). A malformed request, like `-`, only fills
+/// `request`.
+pub fn access_log(combined: bool) -> ReadableRe<'static> {
+    let client = non_capture_group(either([dotted_quad(), ipv6_address(false), hostname()]));
+    let target = non_capture_group(either([
+        uri(UriKind::Relative, false),
+        one_or_more(not_chars(r#" ""#)),
+    ]));
+    let request = non_capture_group(either([
+        named_group("method", one_or_more(chars("A-Z")))
+            + Raw(" ")
+            + named_group("target", target)
+            + Raw(" ")
+            + named_group("protocol", Raw("HTTP/") + one_or_more(chars("0-9."))),
+        zero_or_more(not_chars(r#"""#)),
+    ]));
+    let common = named_group("client", client)
+        + Raw(" ")
+        + named_group("ident", field())
+        + Raw(" ")
+        + named_group("user", field())
+        + Raw(r" \[")
+        + named_group("timestamp", clf_timestamp())
+        + Raw(r#"\] ""#)
+        + named_group("request", request)
+        + Raw(r#"" "#)
+        + named_group("status", chars("1-5") + exactly(2, chars("0-9")))
+        + Raw(" ")
+        + named_group("size", either([one_or_more(chars("0-9")), Raw("-")]));
+    if combined {
+        common + Raw(" ") + quoted("referer") + Raw(" ") + quoted("user_agent")
+    } else {
+        common
+    }
+}
+
+/// Common Log Format line
+/// `127.0.0.1 - frank [10/Oct/2000:13:55:36 -0700] "GET /apache_pb.gif HTTP/1.0" 200 2326`,
+/// see [`access_log`] for the named groups
+pub const COMMON_LOG: Lazy<ReadableRe> = Lazy::new(|| starts_and_ends_with(access_log(false)));
+
+/// Combined Log Format line, the default of Nginx, a [`COMMON_LOG`] line followed by the quoted
+/// referer and user agent, see [`access_log`] for the named groups
+pub const COMBINED_LOG: Lazy<ReadableRe> = Lazy::new(|| starts_and_ends_with(access_log(true)));
+
+/// `<PRI>` syslog priority `0`-`191`, in the `priority` named group
+fn priority() -> ReadableRe<'static> {
+    Raw("<") + named_group("priority", numeric_range(0, 191, RangeOptions::new())) + Raw(">")
+}
+
+/// [RFC 3164](https://www.rfc-editor.org/rfc/rfc3164) (BSD) syslog message
+/// `<34>Oct 11 22:14:15 mymachine su[230]: 'su root' failed`.
+///
+/// Named groups: `priority`, `timestamp`, `hostname`, `app_name`, `proc_id` and `message`
+pub const SYSLOG_RFC3164: Lazy<ReadableRe> = Lazy::new(|| {
+    let day = non_capture_group(either([Raw(" ") + chars("1-9"), DAY.clone()]));
+    let timestamp = ReadableRe::Trie(month_names(&[Locale::English], Form::Abbreviated))
+        + Raw(" ")
+        + day
+        + Raw(" ")
+        + HOURS_24.clone()
+        + Raw(":")
+        + MIN_SEC.clone()
+        + Raw(":")
+        + MIN_SEC.clone();
+    starts_and_ends_with(
+        priority()
+            + named_group("timestamp", timestamp)
+            + Raw(" ")
+            + named_group("hostname", field())
+            + Raw(" ")
+            + named_group("app_name", one_or_more(not_chars(r"\s:\[")))
+            + optional(non_capture_group(
+                Raw(r"\[") + named_group("proc_id", one_or_more(chars("0-9"))) + Raw(r"\]"),
+            ))
+            + Raw(": ")
+            + named_group("message", Everything),
+    )
+});
+
+/// Printable ASCII field of at most `length` characters, `-` when empty
+fn header_field(name: &'static str, length: usize) -> ReadableRe<'static> {
+    named_group(name, ranged(1..=length, chars("!-~")))
+}
+
+/// [RFC 5424](https://www.rfc-editor.org/rfc/rfc5424) syslog message
+/// `<165>1 2003-10-11T22:14:15.003Z mymachine.example.com evntslog - ID47 [exampleSDID@32473
+/// iut="3"] An application event`. The empty fields are written `-`.
+///
+/// Named groups: `priority`, `version`, `timestamp`, `hostname`, `app_name`, `proc_id`,
+/// `msg_id`, `structured_data` and the optional `message`
+pub const SYSLOG_RFC5424: Lazy<ReadableRe> = Lazy::new(|| {
+    let element = Raw(r"\[")
+        + zero_or_more(non_capture_group(either([
+            not_chars(r"\]\\"),
+            Raw(r"\\") + ReadableRe::AnyChar,
+        ])))
+        + Raw(r"\]");
+    starts_and_ends_with(
+        priority()
+            + named_group("version", chars("1-9") + ranged(0..=2, chars("0-9")))
+            + Raw(" ")
+            + named_group(
+                "timestamp",
+                non_capture_group(either([rfc3339(false), Raw("-")])),
+            )
+            + Raw(" ")
+            + header_field("hostname", 255)
+            + Raw(" ")
+            + header_field("app_name", 48)
+            + Raw(" ")
+            + header_field("proc_id", 128)
+            + Raw(" ")
+            + header_field("msg_id", 32)
+            + Raw(" ")
+            + named_group(
+                "structured_data",
+                non_capture_group(either([Raw("-"), one_or_more(non_capture_group(element))])),
+            )
+            + optional(non_capture_group(
+                Raw(" ") + named_group("message", Everything),
+            )),
+    )
+});
+
+/// logfmt pair, with `key` and `value` named groups when `named`
+fn logfmt(named: bool) -> ReadableRe<'static> {
+    let value = either([
+        Raw("\"") + escaped() + Raw("\""),
+        zero_or_more(not_chars(r#"\s""#)),
+    ]);
+    part(named, "key", one_or_more(not_chars(r"\s=")))
+        + optional(non_capture_group(Raw("=") + part(named, "value", value)))
+}
+
+/// [logfmt](https://brandur.org/logfmt) pair `key=value`, `key="quoted value"` or a bare `key`,
+/// with `key` and the optional `value` (with its quotes) named groups, without anchors
+pub fn logfmt_pair() -> ReadableRe<'static> {
+    logfmt(true)
+}
+
+/// Line of space separated [logfmt](https://brandur.org/logfmt) pairs
+/// `level=info msg="request done" status=200`, use [`parse_logfmt`] to get them
+pub const LOGFMT: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(
+        logfmt(false) + zero_or_more(non_capture_group(one_or_more(chars(" \t")) + logfmt(false))),
+    )
+});
+
+/// Rust `log` levels, as written by `env_logger` and `tracing_subscriber`
+fn level() -> ReadableRe<'static> {
+    named_group(
+        "level",
+        ReadableRe::Trie(crate::solvers::Trie::new([
+            "ERROR", "WARN", "INFO", "DEBUG", "TRACE",
+        ])),
+    )
+}
+
+/// Rust module path `my_crate::module`
+fn module_path() -> ReadableRe<'static> {
+    let identifier = || chars("A-Za-z_") + zero_or_more(chars("A-Za-z0-9_"));
+    identifier() + zero_or_more(non_capture_group(Raw("::") + identifier()))
+}
+
+/// Default `env_logger` line `[2024-01-15T10:30:00Z INFO  my_crate::module] message`.
+///
+/// Named groups: `timestamp`, `level`, the optional `target` and `message`
+pub const ENV_LOGGER: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(
+        Raw(r"\[")
+            + named_group("timestamp", rfc3339(false))
+            + Raw(" ")
+            + level()
+            + optional(non_capture_group(
+                one_or_more(Raw(" ")) + named_group("target", module_path()),
+            ))
+            + Raw(r"\] ")
+            + named_group("message", Everything),
+    )
+});
+
+/// Default `tracing_subscriber::fmt` line, without colors,
+/// `2024-01-15T10:30:00.123456Z  INFO request{id=7}: my_crate::module: message`.
+///
+/// Named groups: `timestamp`, `level`, the optional `spans` (with their fields and trailing
+/// `:`), `target` and `message`
+pub const TRACING_SUBSCRIBER: Lazy<ReadableRe> = Lazy::new(|| {
+    let span = chars("A-Za-z_")
+        + zero_or_more(chars("A-Za-z0-9_"))
+        + optional(non_capture_group(
+            Raw(r"\{") + zero_or_more(not_chars("}")) + Raw(r"\}"),
+        ))
+        + Raw(":");
+    starts_and_ends_with(
+        named_group("timestamp", rfc3339(false))
+            + one_or_more(Raw(" "))
+            + level()
+            + Raw(" ")
+            + optional(non_capture_group(
+                named_group("spans", one_or_more(non_capture_group(span))) + Raw(" "),
+            ))
+            + named_group("target", module_path())
+            + Raw(": ")
+            + named_group("message", Everything),
+    )
+});
+
+static FORMATS: Lazy<Formats> = Lazy::new(|| Formats {
+    combined: COMBINED_LOG.compile().unwrap(),
+    common: COMMON_LOG.compile().unwrap(),
+    rfc5424: SYSLOG_RFC5424.compile().unwrap(),
+    rfc3164: SYSLOG_RFC3164.compile().unwrap(),
+    logfmt_pair: logfmt_pair().compile().unwrap(),
+    env_logger: ENV_LOGGER.compile().unwrap(),
+    tracing: TRACING_SUBSCRIBER.compile().unwrap(),
+});
+
+struct Formats {
+    combined: Regex,
+    common: Regex,
+    rfc5424: Regex,
+    rfc3164: Regex,
+    logfmt_pair: Regex,
+    env_logger: Regex,
+    tracing: Regex,
+}
+
+/// Text of the group, `None` when it didn't match or is the `-` placeholder
+fn text(captures: &Captures, name: &str) -> Option<String> {
+    captures
+        .name(name)
+        .map(|part| part.as_str())
+        .filter(|part| *part != "-")
+        .map(str::to_string)
+}
+
+/// Request of an access log, parsed by [`parse_access_log`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessLog {
+    pub client: String,
+    pub ident: Option<String>,
+    pub user: Option<String>,
+    /// As written, `10/Oct/2000:13:55:36 -0700`
+    pub timestamp: String,
+    /// The whole request line, which might be malformed
+    pub request: String,
+    pub method: Option<String>,
+    pub target: Option<String>,
+    pub protocol: Option<String>,
+    pub status: u16,
+    pub size: Option<u64>,
+    pub referer: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+/// Fields of a [`COMBINED_LOG`] or [`COMMON_LOG`] line, `-` placeholders as `None`
+///
+/// ```
+/// use readable_regex::presets::logs::parse_access_log;
+/// let line = r#"127.0.0.1 - frank [10/Oct/2000:13:55:36 -0700] "GET /a.gif HTTP/1.0" 200 -"#;
+/// let log = parse_access_log(line).unwrap();
+/// assert_eq!(log.user.as_deref(), Some("frank"));
+/// assert_eq!(log.status, 200);
+/// assert_eq!(log.size, None);
+/// ```
+pub fn parse_access_log(line: &str) -> Option<AccessLog> {
+    let formats = &*FORMATS;
+    let captures = formats
+        .combined
+        .captures(line)
+        .or_else(|| formats.common.captures(line))?;
+    Some(AccessLog {
+        client: captures["client"].to_string(),
+        ident: text(&captures, "ident"),
+        user: text(&captures, "user"),
+        timestamp: captures["timestamp"].to_string(),
+        request: captures["request"].to_string(),
+        method: text(&captures, "method"),
+        target: text(&captures, "target"),
+        protocol: text(&captures, "protocol"),
+        status: captures["status"].parse().ok()?,
+        size: text(&captures, "size").and_then(|size| size.parse().ok()),
+        referer: text(&captures, "referer"),
+        user_agent: text(&captures, "user_agent"),
+    })
+}
+
+/// Syslog message, parsed by [`parse_syslog`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyslogMessage {
+    pub facility: u8,
+    pub severity: u8,
+    /// `None` for RFC 3164 messages
+    pub version: Option<u16>,
+    /// As written, RFC 3339 for RFC 5424 messages
+    pub timestamp: Option<String>,
+    pub hostname: Option<String>,
+    pub app_name: Option<String>,
+    pub proc_id: Option<String>,
+    pub msg_id: Option<String>,
+    pub structured_data: Option<String>,
+    pub message: Option<String>,
+}
+
+/// Fields of a [`SYSLOG_RFC5424`] or [`SYSLOG_RFC3164`] message, `-` placeholders as `None`,
+/// and the priority split into facility and severity
+///
+/// ```
+/// use readable_regex::presets::logs::parse_syslog;
+/// let message = parse_syslog("<34>Oct 11 22:14:15 mymachine su: 'su root' failed").unwrap();
+/// assert_eq!((message.facility, message.severity), (4, 2));
+/// assert_eq!(message.app_name.as_deref(), Some("su"));
+/// ```
+pub fn parse_syslog(line: &str) -> Option<SyslogMessage> {
+    let formats = &*FORMATS;
+    let captures = formats
+        .rfc5424
+        .captures(line)
+        .or_else(|| formats.rfc3164.captures(line))?;
+    let priority: u8 = captures["priority"].parse().ok()?;
+    Some(SyslogMessage {
+        facility: priority / 8,
+        severity: priority % 8,
+        version: text(&captures, "version").and_then(|version| version.parse().ok()),
+        timestamp: text(&captures, "timestamp"),
+        hostname: text(&captures, "hostname"),
+        app_name: text(&captures, "app_name"),
+        proc_id: text(&captures, "proc_id"),
+        msg_id: text(&captures, "msg_id"),
+        structured_data: text(&captures, "structured_data"),
+        message: captures
+            .name("message")
+            .map(|message| message.as_str().to_string()),
+    })
+}
+
+/// Key and value pairs of a logfmt line, in order, with the quoted values unescaped. A bare key
+/// has an empty value.
+///
+/// ```
+/// use readable_regex::presets::logs::parse_logfmt;
+/// let pairs = parse_logfmt(r#"level=info msg="said \"hi\"" debug"#);
+/// assert_eq!(pairs[1], ("msg".to_string(), r#"said "hi""#.to_string()));
+/// assert_eq!(pairs[2], ("debug".to_string(), String::new()));
+/// ```
+pub fn parse_logfmt(line: &str) -> Vec<(String, String)> {
+    FORMATS
+        .logfmt_pair
+        .captures_iter(line)
+        .map(|captures| {
+            let value = captures.name("value").map_or("", |value| value.as_str());
+            let value = match value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+                Some(quoted) => unescape(quoted),
+                None => value.to_string(),
+            };
+            (captures["key"].to_string(), value)
+        })
+        .collect()
+}
+
+/// Removes the backslashes escaping the next character
+fn unescape(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        unescaped.extend(if c == '\\' { chars.next() } else { Some(c) });
+    }
+    unescaped
+}
+
+/// Record of the Rust logging crates, parsed by [`parse_rust_log`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogRecord {
+    /// RFC 3339
+    pub timestamp: String,
+    /// `ERROR`, `WARN`, `INFO`, `DEBUG` or `TRACE`
+    pub level: String,
+    pub target: Option<String>,
+    /// `tracing_subscriber` span context
+    pub spans: Option<String>,
+    pub message: String,
+}
+
+/// Fields of an [`ENV_LOGGER`] or [`TRACING_SUBSCRIBER`] line
+///
+/// ```
+/// use readable_regex::presets::logs::parse_rust_log;
+/// let record = parse_rust_log("[2024-01-15T10:30:00Z WARN  app::db] slow query").unwrap();
+/// assert_eq!(record.level, "WARN");
+/// assert_eq!(record.target.as_deref(), Some("app::db"));
+/// assert_eq!(record.message, "slow query");
+/// ```
+pub fn parse_rust_log(line: &str) -> Option<LogRecord> {
+    let formats = &*FORMATS;
+    let captures = formats
+        .env_logger
+        .captures(line)
+        .or_else(|| formats.tracing.captures(line))?;
+    Some(LogRecord {
+        timestamp: captures["timestamp"].to_string(),
+        level: captures["level"].to_string(),
+        target: text(&captures, "target"),
+        spans: text(&captures, "spans"),
+        message: captures["message"].to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::presets::logs::{
+        parse_access_log, parse_logfmt, parse_rust_log, parse_syslog, COMBINED_LOG, COMMON_LOG,
+        ENV_LOGGER, LOGFMT, SYSLOG_RFC3164, SYSLOG_RFC5424, TRACING_SUBSCRIBER,
+    };
+
+    #[test]
+    fn access_logs() {
+        let query = COMMON_LOG.compile().unwrap();
+        let line = r#"127.0.0.1 - frank [10/Oct/2000:13:55:36 -0700] "GET /apache_pb.gif?a=1 HTTP/1.0" 200 2326"#;
+        let captures = query.captures(line).unwrap();
+        assert_eq!(&captures["client"], "127.0.0.1");
+        assert_eq!(&captures["user"], "frank");
+        assert_eq!(&captures["timestamp"], "10/Oct/2000:13:55:36 -0700");
+        assert_eq!(&captures["method"], "GET");
+        assert_eq!(&captures["path"], "/apache_pb.gif");
+        assert_eq!(&captures["query"], "a=1");
+        assert_eq!(&captures["protocol"], "HTTP/1.0");
+        assert_eq!(&captures["status"], "200");
+        for v in [
+            r#"::1 - - [01/Jan/2024:00:00:00 +0000] "-" 400 0"#,
+            r#"proxy.example.com - - [01/Jan/2024:00:00:00 +0000] "CONNECT example.com:443 HTTP/1.1" 200 -"#,
+        ] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        assert!(!query.is_match(r#"127.0.0.1 - - [32/Jan/2024:00:00:00 +0000] "-" 400 0"#));
+        let query = COMBINED_LOG.compile().unwrap();
+        let line = r#"203.0.113.9 - - [15/Jan/2024:10:30:00 +0100] "POST /api HTTP/2.0" 201 17 "https://example.com/" "curl/8.5.0 \"x\"""#;
+        let captures = query.captures(line).unwrap();
+        assert_eq!(&captures["referer"], "https://example.com/");
+        assert_eq!(&captures["user_agent"], r#"curl/8.5.0 \"x\""#);
+        let log = parse_access_log(line).unwrap();
+        assert_eq!(log.client, "203.0.113.9");
+        assert_eq!(log.user, None);
+        assert_eq!(log.method.as_deref(), Some("POST"));
+        assert_eq!(log.status, 201);
+        assert_eq!(log.size, Some(17));
+        let log = parse_access_log(r#"::1 - - [01/Jan/2024:00:00:00 +0000] "-" 400 0"#).unwrap();
+        assert_eq!(log.request, "-");
+        assert_eq!(log.method, None);
+        assert_eq!(log.referer, None);
+        assert!(parse_access_log("not a log line").is_none());
+    }
+
+    #[test]
+    fn syslog() {
+        let query = SYSLOG_RFC3164.compile().unwrap();
+        let captures = query
+            .captures("<34>Oct  1 22:14:15 mymachine su[230]: 'su root' failed")
+            .unwrap();
+        assert_eq!(&captures["priority"], "34");
+        assert_eq!(&captures["timestamp"], "Oct  1 22:14:15");
+        assert_eq!(&captures["hostname"], "mymachine");
+        assert_eq!(&captures["app_name"], "su");
+        assert_eq!(&captures["proc_id"], "230");
+        assert_eq!(&captures["message"], "'su root' failed");
+        assert!(!query.is_match("<192>Oct 11 22:14:15 mymachine su: failed"));
+        let query = SYSLOG_RFC5424.compile().unwrap();
+        let line = r#"<165>1 2003-10-11T22:14:15.003Z mymachine.example.com evntslog - ID47 [exampleSDID@32473 iut="3" eventID="1011"] An application event"#;
+        let captures = query.captures(line).unwrap();
+        assert_eq!(&captures["version"], "1");
+        assert_eq!(&captures["timestamp"], "2003-10-11T22:14:15.003Z");
+        assert_eq!(&captures["app_name"], "evntslog");
+        assert_eq!(&captures["proc_id"], "-");
+        assert_eq!(
+            &captures["structured_data"],
+            r#"[exampleSDID@32473 iut="3" eventID="1011"]"#
+        );
+        assert_eq!(&captures["message"], "An application event");
+        assert!(query.is_match("<13>1 - - - - - -"));
+        let message = parse_syslog(line).unwrap();
+        assert_eq!((message.facility, message.severity), (20, 5));
+        assert_eq!(message.version, Some(1));
+        assert_eq!(message.proc_id, None);
+        assert_eq!(message.msg_id.as_deref(), Some("ID47"));
+        let message = parse_syslog("<13>1 - host app - - -").unwrap();
+        assert_eq!(message.timestamp, None);
+        assert_eq!(message.message, None);
+    }
+
+    #[test]
+    fn logfmt() {
+        let query = LOGFMT.compile().unwrap();
+        for v in [
+            "level=info msg=done",
+            r#"level=info msg="request \"done\"" status=200 debug"#,
+            "key= other=1",
+        ] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in ["", "=value", r#"msg="unterminated"#] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        assert_eq!(
+            parse_logfmt(r#"at=info path="/a b" dyno= bare"#),
+            [
+                ("at".to_string(), "info".to_string()),
+                ("path".to_string(), "/a b".to_string()),
+                ("dyno".to_string(), String::new()),
+                ("bare".to_string(), String::new()),
+            ]
+        );
+    }
+
+    #[test]
+    fn rust_logs() {
+        let query = ENV_LOGGER.compile().unwrap();
+        let captures = query
+            .captures("[2024-01-15T10:30:00Z INFO  my_crate::module] server started")
+            .unwrap();
+        assert_eq!(&captures["timestamp"], "2024-01-15T10:30:00Z");
+        assert_eq!(&captures["level"], "INFO");
+        assert_eq!(&captures["target"], "my_crate::module");
+        assert_eq!(&captures["message"], "server started");
+        assert!(query.is_match("[2024-01-15T10:30:00.123Z ERROR] boom"));
+        assert!(!query.is_match("[2024-01-15T10:30:00Z NOTICE app] boom"));
+        let query = TRACING_SUBSCRIBER.compile().unwrap();
+        let line = "2024-01-15T10:30:00.123456Z  INFO request{id=7}:handle: my_crate::api: done status=200";
+        let captures = query.captures(line).unwrap();
+        assert_eq!(&captures["level"], "INFO");
+        assert_eq!(&captures["spans"], "request{id=7}:handle:");
+        assert_eq!(&captures["target"], "my_crate::api");
+        assert_eq!(&captures["message"], "done status=200");
+        let record = parse_rust_log("2024-01-15T10:30:00Z ERROR app: failed").unwrap();
+        assert_eq!(record.level, "ERROR");
+        assert_eq!(record.spans, None);
+        assert_eq!(record.target.as_deref(), Some("app"));
+        assert!(parse_rust_log("INFO app: failed").is_none());
+    }
+}
@@ -1,3 +1,366 @@
+pub mod checksum;
+pub mod code;
 pub mod datetime;
+pub mod domain;
+pub mod encoding;
+pub mod financial;
+pub mod hardware;
+pub mod identifiers;
+pub mod locale;
+pub mod logs;
+pub mod money;
 pub mod network;
 pub mod numeric;
+pub mod path;
+pub mod phone;
+pub mod postal;
+pub mod uri;
+pub mod web;
+
+use crate::ReadableRe;
+
+/// Every preset, by its `module::NAME` path
+pub fn all() -> Vec<(&'static str, ReadableRe<'static>)> {
+    vec![
+        ("code::ASCII_IDENTIFIER", code::ASCII_IDENTIFIER.clone()),
+        ("code::UNICODE_IDENTIFIER", code::UNICODE_IDENTIFIER.clone()),
+        ("code::RUST_IDENTIFIER", code::RUST_IDENTIFIER.clone()),
+        ("code::C_STRING", code::C_STRING.clone()),
+        ("code::RUST_STRING", code::RUST_STRING.clone()),
+        ("code::RUST_RAW_STRING", code::RUST_RAW_STRING.clone()),
+        ("code::JSON_STRING", code::JSON_STRING.clone()),
+        ("code::LINE_COMMENT", code::LINE_COMMENT.clone()),
+        ("code::HASH_COMMENT", code::HASH_COMMENT.clone()),
+        ("code::BLOCK_COMMENT", code::BLOCK_COMMENT.clone()),
+        ("code::RUST_DOC_COMMENT", code::RUST_DOC_COMMENT.clone()),
+        ("code::PYTHON_NUMBER", code::PYTHON_NUMBER.clone()),
+        ("code::JAVASCRIPT_NUMBER", code::JAVASCRIPT_NUMBER.clone()),
+        ("code::NPM_VERSION_RANGE", code::NPM_VERSION_RANGE.clone()),
+        ("datetime::DAY", datetime::DAY.clone()),
+        ("datetime::MONTH", datetime::MONTH.clone()),
+        ("datetime::MONTH_CALENDAR", datetime::MONTH_CALENDAR.clone()),
+        ("datetime::YEAR", datetime::YEAR.clone()),
+        ("datetime::DATE_Y_M_D", datetime::DATE_Y_M_D.clone()),
+        ("datetime::DATE_D_M_Y", datetime::DATE_D_M_Y.clone()),
+        ("datetime::MIN_SEC", datetime::MIN_SEC.clone()),
+        ("datetime::HOURS_12", datetime::HOURS_12.clone()),
+        ("datetime::HOURS_24", datetime::HOURS_24.clone()),
+        ("datetime::HH_MM_12", datetime::HH_MM_12.clone()),
+        ("datetime::HH_MM_24", datetime::HH_MM_24.clone()),
+        ("datetime::HH_MM_SS_24", datetime::HH_MM_SS_24.clone()),
+        ("datetime::ISO8601_DATE", datetime::ISO8601_DATE.clone()),
+        (
+            "datetime::ISO8601_DATE_BASIC",
+            datetime::ISO8601_DATE_BASIC.clone(),
+        ),
+        (
+            "datetime::ISO8601_WEEK_DATE",
+            datetime::ISO8601_WEEK_DATE.clone(),
+        ),
+        (
+            "datetime::ISO8601_WEEK_DATE_BASIC",
+            datetime::ISO8601_WEEK_DATE_BASIC.clone(),
+        ),
+        (
+            "datetime::ISO8601_ORDINAL_DATE",
+            datetime::ISO8601_ORDINAL_DATE.clone(),
+        ),
+        (
+            "datetime::ISO8601_ORDINAL_DATE_BASIC",
+            datetime::ISO8601_ORDINAL_DATE_BASIC.clone(),
+        ),
+        (
+            "datetime::ISO8601_DATE_TIME",
+            datetime::ISO8601_DATE_TIME.clone(),
+        ),
+        (
+            "datetime::ISO8601_DATE_TIME_BASIC",
+            datetime::ISO8601_DATE_TIME_BASIC.clone(),
+        ),
+        ("datetime::RFC3339", datetime::RFC3339.clone()),
+        (
+            "datetime::ISO8601_DURATION",
+            datetime::ISO8601_DURATION.clone(),
+        ),
+        (
+            "datetime::ISO8601_INTERVAL",
+            datetime::ISO8601_INTERVAL.clone(),
+        ),
+        ("datetime::DATE_MONTH_D_Y", datetime::DATE_MONTH_D_Y.clone()),
+        ("datetime::DATE_D_MONTH_Y", datetime::DATE_D_MONTH_Y.clone()),
+        ("datetime::RFC2822", datetime::RFC2822.clone()),
+        ("datetime::HTTP_DATE", datetime::HTTP_DATE.clone()),
+        ("domain::HOSTNAME", domain::HOSTNAME.clone()),
+        ("domain::DOMAIN_NAME", domain::DOMAIN_NAME.clone()),
+        ("domain::IDN_DOMAIN_NAME", domain::IDN_DOMAIN_NAME.clone()),
+        (
+            "domain::WILDCARD_DOMAIN_NAME",
+            domain::WILDCARD_DOMAIN_NAME.clone(),
+        ),
+        ("domain::PUNYCODE_LABEL", domain::PUNYCODE_LABEL.clone()),
+        (
+            "domain::PUBLIC_SUFFIX_DOMAIN",
+            domain::PUBLIC_SUFFIX_DOMAIN.clone(),
+        ),
+        ("encoding::BASE64", encoding::BASE64.clone()),
+        ("encoding::BASE64_IN_TEXT", encoding::BASE64_IN_TEXT.clone()),
+        ("encoding::BASE64_URL", encoding::BASE64_URL.clone()),
+        (
+            "encoding::BASE64_URL_IN_TEXT",
+            encoding::BASE64_URL_IN_TEXT.clone(),
+        ),
+        ("encoding::BASE32", encoding::BASE32.clone()),
+        ("encoding::BASE32_IN_TEXT", encoding::BASE32_IN_TEXT.clone()),
+        ("encoding::BASE58", encoding::BASE58.clone()),
+        ("encoding::BASE58_IN_TEXT", encoding::BASE58_IN_TEXT.clone()),
+        ("encoding::HEX_STRING", encoding::HEX_STRING.clone()),
+        (
+            "encoding::HEX_STRING_IN_TEXT",
+            encoding::HEX_STRING_IN_TEXT.clone(),
+        ),
+        ("encoding::JWT", encoding::JWT.clone()),
+        ("encoding::JWT_IN_TEXT", encoding::JWT_IN_TEXT.clone()),
+        (
+            "encoding::PERCENT_ENCODED",
+            encoding::PERCENT_ENCODED.clone(),
+        ),
+        (
+            "encoding::PERCENT_ENCODED_IN_TEXT",
+            encoding::PERCENT_ENCODED_IN_TEXT.clone(),
+        ),
+        (
+            "encoding::QUOTED_PRINTABLE",
+            encoding::QUOTED_PRINTABLE.clone(),
+        ),
+        (
+            "encoding::QUOTED_PRINTABLE_IN_TEXT",
+            encoding::QUOTED_PRINTABLE_IN_TEXT.clone(),
+        ),
+        ("financial::IBAN", financial::IBAN.clone()),
+        ("financial::BIC", financial::BIC.clone()),
+        ("financial::CREDIT_CARD", financial::CREDIT_CARD.clone()),
+        ("financial::VISA", financial::VISA.clone()),
+        ("financial::MASTERCARD", financial::MASTERCARD.clone()),
+        (
+            "financial::AMERICAN_EXPRESS",
+            financial::AMERICAN_EXPRESS.clone(),
+        ),
+        ("financial::DISCOVER", financial::DISCOVER.clone()),
+        ("financial::DINERS_CLUB", financial::DINERS_CLUB.clone()),
+        ("financial::JCB", financial::JCB.clone()),
+        ("financial::ISIN", financial::ISIN.clone()),
+        ("financial::CUSIP", financial::CUSIP.clone()),
+        ("financial::LEI", financial::LEI.clone()),
+        ("hardware::MAC_ADDRESS", hardware::MAC_ADDRESS.clone()),
+        ("hardware::EUI64", hardware::EUI64.clone()),
+        ("hardware::IMEI", hardware::IMEI.clone()),
+        ("hardware::IMEISV", hardware::IMEISV.clone()),
+        ("hardware::USB_ID", hardware::USB_ID.clone()),
+        ("hardware::SERIAL_NUMBER", hardware::SERIAL_NUMBER.clone()),
+        ("hardware::PRODUCT_KEY", hardware::PRODUCT_KEY.clone()),
+        ("identifiers::UUID", identifiers::UUID.clone()),
+        ("identifiers::UUID_V1", identifiers::UUID_V1.clone()),
+        ("identifiers::UUID_V4", identifiers::UUID_V4.clone()),
+        ("identifiers::UUID_V7", identifiers::UUID_V7.clone()),
+        ("identifiers::UUID_BRACED", identifiers::UUID_BRACED.clone()),
+        ("identifiers::UUID_URN", identifiers::UUID_URN.clone()),
+        ("identifiers::ULID", identifiers::ULID.clone()),
+        ("identifiers::KSUID", identifiers::KSUID.clone()),
+        ("identifiers::SEMVER", identifiers::SEMVER.clone()),
+        (
+            "identifiers::VERSION_REQUIREMENT",
+            identifiers::VERSION_REQUIREMENT.clone(),
+        ),
+        ("identifiers::GIT_SHA1", identifiers::GIT_SHA1.clone()),
+        (
+            "identifiers::GIT_SHA1_SHORT",
+            identifiers::GIT_SHA1_SHORT.clone(),
+        ),
+        ("identifiers::GIT_SHA256", identifiers::GIT_SHA256.clone()),
+        (
+            "identifiers::GIT_SHA256_SHORT",
+            identifiers::GIT_SHA256_SHORT.clone(),
+        ),
+        ("identifiers::MD5", identifiers::MD5.clone()),
+        ("identifiers::SHA1", identifiers::SHA1.clone()),
+        ("identifiers::SHA256", identifiers::SHA256.clone()),
+        ("identifiers::SHA512", identifiers::SHA512.clone()),
+        ("locale::MONTH_NAME", locale::MONTH_NAME.clone()),
+        (
+            "locale::MONTH_NAME_IGNORE_CASE",
+            locale::MONTH_NAME_IGNORE_CASE.clone(),
+        ),
+        (
+            "locale::MONTH_ABBREVIATION",
+            locale::MONTH_ABBREVIATION.clone(),
+        ),
+        (
+            "locale::MONTH_ABBREVIATION_IGNORE_CASE",
+            locale::MONTH_ABBREVIATION_IGNORE_CASE.clone(),
+        ),
+        ("locale::WEEKDAY_NAME", locale::WEEKDAY_NAME.clone()),
+        (
+            "locale::WEEKDAY_NAME_IGNORE_CASE",
+            locale::WEEKDAY_NAME_IGNORE_CASE.clone(),
+        ),
+        (
+            "locale::WEEKDAY_ABBREVIATION",
+            locale::WEEKDAY_ABBREVIATION.clone(),
+        ),
+        (
+            "locale::WEEKDAY_ABBREVIATION_IGNORE_CASE",
+            locale::WEEKDAY_ABBREVIATION_IGNORE_CASE.clone(),
+        ),
+        ("logs::COMMON_LOG", logs::COMMON_LOG.clone()),
+        ("logs::COMBINED_LOG", logs::COMBINED_LOG.clone()),
+        ("logs::SYSLOG_RFC3164", logs::SYSLOG_RFC3164.clone()),
+        ("logs::SYSLOG_RFC5424", logs::SYSLOG_RFC5424.clone()),
+        ("logs::LOGFMT", logs::LOGFMT.clone()),
+        ("logs::ENV_LOGGER", logs::ENV_LOGGER.clone()),
+        ("logs::TRACING_SUBSCRIBER", logs::TRACING_SUBSCRIBER.clone()),
+        ("money::CURRENCY_CODE", money::CURRENCY_CODE.clone()),
+        ("money::CURRENCY_SYMBOL", money::CURRENCY_SYMBOL.clone()),
+        ("money::AMOUNT_ENGLISH", money::AMOUNT_ENGLISH.clone()),
+        ("money::AMOUNT_GERMAN", money::AMOUNT_GERMAN.clone()),
+        ("money::AMOUNT_FRENCH", money::AMOUNT_FRENCH.clone()),
+        ("money::AMOUNT_SWISS", money::AMOUNT_SWISS.clone()),
+        ("money::ACCOUNTING_AMOUNT", money::ACCOUNTING_AMOUNT.clone()),
+        ("network::IPV4_ADDRESS", network::IPV4_ADDRESS.clone()),
+        ("network::IPV6_ADDRESS", network::IPV6_ADDRESS.clone()),
+        (
+            "network::IPV6_ADDRESS_CANONICAL",
+            network::IPV6_ADDRESS_CANONICAL.clone(),
+        ),
+        ("network::IPV6_HOST_PORT", network::IPV6_HOST_PORT.clone()),
+        ("network::IPV4_CIDR", network::IPV4_CIDR.clone()),
+        ("network::IPV6_CIDR", network::IPV6_CIDR.clone()),
+        ("network::NETMASK", network::NETMASK.clone()),
+        ("network::PORT", network::PORT.clone()),
+        ("network::HOST_PORT", network::HOST_PORT.clone()),
+        ("network::SIMPLE_URL", network::SIMPLE_URL),
+        ("network::EMAIL", network::EMAIL),
+        ("network::SIMPLE_EMAIL", network::SIMPLE_EMAIL.clone()),
+        (
+            "numeric::POSITIVE_INTEGER",
+            numeric::POSITIVE_INTEGER.clone(),
+        ),
+        (
+            "numeric::NEGATIVE_INTEGER",
+            numeric::NEGATIVE_INTEGER.clone(),
+        ),
+        ("numeric::INTEGER", numeric::INTEGER.clone()),
+        (
+            "numeric::POSITIVE_DECIMAL",
+            numeric::POSITIVE_DECIMAL.clone(),
+        ),
+        (
+            "numeric::NEGATIVE_DECIMAL",
+            numeric::NEGATIVE_DECIMAL.clone(),
+        ),
+        ("numeric::DECIMAL", numeric::DECIMAL.clone()),
+        ("numeric::FRACTION", numeric::FRACTION.clone()),
+        ("numeric::SCIENTIFIC", numeric::SCIENTIFIC.clone()),
+        ("numeric::HEX_LITERAL", numeric::HEX_LITERAL.clone()),
+        ("numeric::OCTAL_LITERAL", numeric::OCTAL_LITERAL.clone()),
+        ("numeric::BINARY_LITERAL", numeric::BINARY_LITERAL.clone()),
+        ("numeric::JSON_NUMBER", numeric::JSON_NUMBER.clone()),
+        ("numeric::RUST_NUMBER", numeric::RUST_NUMBER.clone()),
+        ("numeric::C_NUMBER", numeric::C_NUMBER.clone()),
+        ("numeric::SIGNED_NUMBER", numeric::SIGNED_NUMBER.clone()),
+        ("numeric::PERCENTAGE", numeric::PERCENTAGE.clone()),
+        (
+            "numeric::GROUPED_NUMBER_ENGLISH",
+            numeric::GROUPED_NUMBER_ENGLISH.clone(),
+        ),
+        (
+            "numeric::GROUPED_NUMBER_GERMAN",
+            numeric::GROUPED_NUMBER_GERMAN.clone(),
+        ),
+        (
+            "numeric::GROUPED_NUMBER_FRENCH",
+            numeric::GROUPED_NUMBER_FRENCH.clone(),
+        ),
+        (
+            "numeric::GROUPED_NUMBER_SWISS",
+            numeric::GROUPED_NUMBER_SWISS.clone(),
+        ),
+        (
+            "path::POSIX_ABSOLUTE_PATH",
+            path::POSIX_ABSOLUTE_PATH.clone(),
+        ),
+        (
+            "path::POSIX_RELATIVE_PATH",
+            path::POSIX_RELATIVE_PATH.clone(),
+        ),
+        ("path::WINDOWS_PATH", path::WINDOWS_PATH.clone()),
+        ("path::UNC_PATH", path::UNC_PATH.clone()),
+        (
+            "path::EXTENDED_LENGTH_PATH",
+            path::EXTENDED_LENGTH_PATH.clone(),
+        ),
+        ("path::FILE_NAME_POSIX", path::FILE_NAME_POSIX.clone()),
+        ("path::FILE_NAME_WINDOWS", path::FILE_NAME_WINDOWS.clone()),
+        ("phone::E164", phone::E164.clone()),
+        ("phone::NANP", phone::NANP.clone()),
+        ("phone::PHONE_NUMBER_GB", phone::PHONE_NUMBER_GB.clone()),
+        ("phone::PHONE_NUMBER_DE", phone::PHONE_NUMBER_DE.clone()),
+        ("phone::PHONE_NUMBER_FR", phone::PHONE_NUMBER_FR.clone()),
+        ("phone::PHONE_NUMBER_JP", phone::PHONE_NUMBER_JP.clone()),
+        ("phone::PHONE_NUMBER_IN", phone::PHONE_NUMBER_IN.clone()),
+        ("phone::PHONE_NUMBER_AU", phone::PHONE_NUMBER_AU.clone()),
+        ("postal::POSTAL_CODE_US", postal::POSTAL_CODE_US.clone()),
+        ("postal::POSTAL_CODE_GB", postal::POSTAL_CODE_GB.clone()),
+        ("postal::POSTAL_CODE_CA", postal::POSTAL_CODE_CA.clone()),
+        ("postal::POSTAL_CODE_DE", postal::POSTAL_CODE_DE.clone()),
+        ("postal::POSTAL_CODE_ES", postal::POSTAL_CODE_ES.clone()),
+        ("postal::POSTAL_CODE_FR", postal::POSTAL_CODE_FR.clone()),
+        ("postal::POSTAL_CODE_NL", postal::POSTAL_CODE_NL.clone()),
+        ("postal::POSTAL_CODE_JP", postal::POSTAL_CODE_JP.clone()),
+        ("postal::SSN", postal::SSN.clone()),
+        (
+            "postal::NATIONAL_INSURANCE_NUMBER",
+            postal::NATIONAL_INSURANCE_NUMBER.clone(),
+        ),
+        ("postal::SIN", postal::SIN.clone()),
+        ("postal::DNI", postal::DNI.clone()),
+        ("postal::NIE", postal::NIE.clone()),
+        ("postal::BSN", postal::BSN.clone()),
+        ("uri::URI", uri::URI.clone()),
+        ("uri::ABSOLUTE_URI", uri::ABSOLUTE_URI.clone()),
+        ("uri::HTTP_URL", uri::HTTP_URL.clone()),
+        ("uri::RELATIVE_REFERENCE", uri::RELATIVE_REFERENCE.clone()),
+        ("uri::IRI", uri::IRI.clone()),
+        ("web::HEX_COLOR", web::HEX_COLOR.clone()),
+        ("web::RGB_COLOR", web::RGB_COLOR.clone()),
+        ("web::HSL_COLOR", web::HSL_COLOR.clone()),
+        ("web::CSS_LENGTH", web::CSS_LENGTH.clone()),
+        ("web::HTML_TAG_NAME", web::HTML_TAG_NAME.clone()),
+        ("web::XML_NAME", web::XML_NAME.clone()),
+        ("web::OPENING_TAG", web::OPENING_TAG.clone()),
+        ("web::CLOSING_TAG", web::CLOSING_TAG.clone()),
+        ("web::HTML_ENTITY", web::HTML_ENTITY.clone()),
+        ("web::MIME_TYPE", web::MIME_TYPE.clone()),
+        ("web::HTTP_HEADER", web::HTTP_HEADER.clone()),
+    ]
+}
+
+/// Find a preset by its `module::NAME` path, e.g. `"network::EMAIL"`
+pub fn by_name(name: &str) -> Option<ReadableRe<'static>> {
+    all()
+        .into_iter()
+        .find_map(|(preset, re)| (preset == name).then_some(re))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::presets::{all, by_name};
+
+    #[test]
+    fn presets_compile() {
+        for (name, re) in all() {
+            assert!(re.compile().is_ok(), "Failed to compile: {}", name);
+        }
+        assert!(by_name("network::EMAIL").is_some());
+        assert!(by_name("network::NOPE").is_none());
+    }
+}
@@ -0,0 +1,302 @@
+//! Currencies and monetary amounts
+use crate::presets::numeric::{grouped_number, ungrouped, Separators};
+use crate::solvers::Trie;
+use crate::ReadableRe::{self, Raw};
+use crate::{chars, either, named_group, non_capture_group, optional, starts_and_ends_with};
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// ISO 4217 codes embedded from `currencies.dat`
+const CURRENCY_LIST: &str = include_str!("currencies.dat");
+
+/// Common currency symbols, the dollar ones with their country prefixes
+pub const CURRENCY_SYMBOLS: [&str; 30] = [
+    "$", "US$", "C$", "A$", "NZ$", "HK$", "S$", "R$", "MX$", "€", "£", "¥", "₹", "₽", "₩", "₺",
+    "₪", "₫", "฿", "₴", "₦", "₱", "₲", "₵", "₡", "₸", "₼", "₾", "zł", "Kč",
+];
+
+/// Active [ISO 4217](https://www.iso.org/iso-4217-currency-codes.html) currency codes
+pub fn currency_codes() -> Vec<&'static str> {
+    CURRENCY_LIST
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with("//"))
+        .collect()
+}
+
+/// ISO 4217 code or currency symbol, without capture groups
+pub fn currency() -> ReadableRe<'static> {
+    non_capture_group(either([
+        ReadableRe::Trie(Trie::new(currency_codes()).word_boundaries(true)),
+        ReadableRe::Trie(Trie::new(CURRENCY_SYMBOLS)),
+    ]))
+}
+
+/// Where the currency is written around the amount
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CurrencyPosition {
+    /// `$1,234.56`, `CHF 1'234.50`
+    Prefix,
+    /// `1.234,56 €`
+    Suffix,
+}
+
+/// Layout of the amounts built by [`amount`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AmountFormat {
+    pub(crate) separators: Separators,
+    pub(crate) position: CurrencyPosition,
+    pub(crate) accounting: bool,
+}
+
+impl AmountFormat {
+    pub const fn new(separators: Separators, position: CurrencyPosition) -> Self {
+        Self {
+            separators,
+            position,
+            accounting: false,
+        }
+    }
+
+    /// Match negative amounts written in parentheses, `($1,234.56)`, instead of the amounts
+    /// with an optional minus sign. Defaults to `false`.
+    pub fn accounting(mut self, accounting: bool) -> Self {
+        self.accounting = accounting;
+        self
+    }
+}
+
+/// Monetary amount in the `format`, without anchors, with `currency` and `amount` named groups
+pub fn amount(format: AmountFormat) -> ReadableRe<'static> {
+    let currency = || named_group("currency", currency());
+    let space = || optional(chars(" \u{A0}\u{202F}"));
+    let amount = named_group("amount", grouped_number(format.separators));
+    let re = match format.position {
+        CurrencyPosition::Prefix => currency() + space() + amount,
+        CurrencyPosition::Suffix => amount + space() + currency(),
+    };
+    if format.accounting {
+        Raw(r"\(") + re + Raw(r"\)")
+    } else {
+        optional(Raw("-")) + re
+    }
+}
+
+const ENGLISH: AmountFormat = AmountFormat::new(Separators::ENGLISH, CurrencyPosition::Prefix);
+
+/// Amount with the currency first, grouped as in [`Separators::ENGLISH`], `$1,234.56` or
+/// `-USD 5`, with `currency` and `amount` named groups
+pub const AMOUNT_ENGLISH: Lazy<ReadableRe> = Lazy::new(|| starts_and_ends_with(amount(ENGLISH)));
+
+/// Amount with the currency last, grouped as in [`Separators::GERMAN`], `1.234,56 €`, with
+/// `currency` and `amount` named groups
+pub const AMOUNT_GERMAN: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(amount(AmountFormat::new(
+        Separators::GERMAN,
+        CurrencyPosition::Suffix,
+    )))
+});
+
+/// Amount with the currency last, grouped as in [`Separators::FRENCH`], `1 234,56 €`, with
+/// `currency` and `amount` named groups
+pub const AMOUNT_FRENCH: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(amount(AmountFormat::new(
+        Separators::FRENCH,
+        CurrencyPosition::Suffix,
+    )))
+});
+
+/// Amount with the currency first, grouped as in [`Separators::SWISS`], `CHF 1'234.50`, with
+/// `currency` and `amount` named groups
+pub const AMOUNT_SWISS: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(amount(AmountFormat::new(
+        Separators::SWISS,
+        CurrencyPosition::Prefix,
+    )))
+});
+
+/// Negative amount in accounting style `($1,234.56)`, with `currency` and `amount` named groups
+pub const ACCOUNTING_AMOUNT: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(amount(ENGLISH.accounting(true))));
+
+/// ISO 4217 currency code, `EUR`
+pub const CURRENCY_CODE: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(ReadableRe::Trie(Trie::new(currency_codes()))));
+
+/// Currency symbol, `€`, see [`CURRENCY_SYMBOLS`]
+pub const CURRENCY_SYMBOL: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(ReadableRe::Trie(Trie::new(CURRENCY_SYMBOLS))));
+
+/// Monetary amount found by [`find_amounts`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Money {
+    /// Code or symbol, as written
+    pub currency: String,
+    /// Decimal amount as written, without group separators and with a `.` decimal separator,
+    /// `-1234.50`. Negative for the amounts with a minus sign or in parentheses.
+    pub value: String,
+    /// Byte offset of the amount in the text
+    pub start: usize,
+}
+
+/// Compiled [`amount`] expressions of the `separators`, the accounting formats first so their
+/// parentheses are part of the match
+fn amount_formats(separators: Separators) -> [Regex; 4] {
+    [
+        (true, CurrencyPosition::Prefix),
+        (true, CurrencyPosition::Suffix),
+        (false, CurrencyPosition::Prefix),
+        (false, CurrencyPosition::Suffix),
+    ]
+    .map(|(accounting, position)| {
+        let format = AmountFormat::new(separators, position).accounting(accounting);
+        amount(format).compile().unwrap()
+    })
+}
+
+/// [`amount_formats`] of the [`Separators`] constants
+static FORMATS: Lazy<[[Regex; 4]; 4]> = Lazy::new(|| Separators::PRESETS.map(amount_formats));
+
+/// Amounts of the text grouped by the `separators`, with the currency written first or last,
+/// and negative ones with a minus sign or in parentheses, in the order they are found. The
+/// expressions are compiled once for the [`Separators`] constants, but on every call for other
+/// separators.
+///
+/// ```
+/// use readable_regex::presets::money::find_amounts;
+/// use readable_regex::presets::numeric::Separators;
+/// let amounts = find_amounts("Total 1.234,50 € (Rabatt: (EUR 10,00))", Separators::GERMAN);
+/// assert_eq!(amounts[0].value, "1234.50");
+/// assert_eq!(amounts[1].currency, "EUR");
+/// assert_eq!(amounts[1].value, "-10.00");
+/// ```
+pub fn find_amounts(text: &str, separators: Separators) -> Vec<Money> {
+    let compiled;
+    let formats = match Separators::PRESETS.iter().position(|s| *s == separators) {
+        Some(i) => &FORMATS[i],
+        None => {
+            compiled = amount_formats(separators);
+            &compiled
+        }
+    };
+    let mut found: Vec<Money> = Vec::new();
+    let mut spans: Vec<std::ops::Range<usize>> = Vec::new();
+    for (i, query) in formats.iter().enumerate() {
+        let accounting = i < 2;
+        for captures in query.captures_iter(text) {
+            let matched = captures.get(0).unwrap();
+            if spans
+                .iter()
+                .any(|span| span.start < matched.end() && matched.start() < span.end)
+            {
+                continue;
+            }
+            let amount = ungrouped(&captures["amount"], separators);
+            let negative =
+                accounting || matched.as_str().starts_with('-') || amount.starts_with('-');
+            let digits = amount.trim_start_matches(['+', '-']);
+            spans.push(matched.range());
+            found.push(Money {
+                currency: captures["currency"].to_string(),
+                value: if negative {
+                    format!("-{digits}")
+                } else {
+                    digits.to_string()
+                },
+                start: matched.start(),
+            });
+        }
+    }
+    found.sort_by_key(|money| money.start);
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::presets::money::{
+        currency_codes, find_amounts, ACCOUNTING_AMOUNT, AMOUNT_ENGLISH, AMOUNT_FRENCH,
+        AMOUNT_GERMAN, AMOUNT_SWISS, CURRENCY_CODE, CURRENCY_SYMBOL,
+    };
+    use crate::presets::numeric::Separators;
+
+    #[test]
+    fn currencies() {
+        assert!(currency_codes().contains(&"EUR"));
+        let query = CURRENCY_CODE.compile().unwrap();
+        assert!(query.is_match("USD"));
+        assert!(query.is_match("XAU"));
+        assert!(!query.is_match("ABC"));
+        assert!(!query.is_match("usd"));
+        let query = CURRENCY_SYMBOL.compile().unwrap();
+        for v in ["$", "€", "R$", "zł"] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        assert!(!query.is_match("E"));
+    }
+
+    #[test]
+    fn amounts() {
+        let query = AMOUNT_ENGLISH.compile().unwrap();
+        let captures = query.captures("$1,234.56").unwrap();
+        assert_eq!(&captures["currency"], "$");
+        assert_eq!(&captures["amount"], "1,234.56");
+        for v in ["USD 5", "-$12.30", "US$ 1,000,000", "£0.99"] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in ["1,234.56", "$1.234,56", "$", "ABC 5"] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        let query = AMOUNT_GERMAN.compile().unwrap();
+        let captures = query.captures("1.234,56 €").unwrap();
+        assert_eq!(&captures["amount"], "1.234,56");
+        assert_eq!(&captures["currency"], "€");
+        assert!(query.is_match("-5,00 EUR"));
+        let query = AMOUNT_FRENCH.compile().unwrap();
+        for v in ["1 234,56 €", "1 234,56\u{A0}€", "1\u{202F}234,56\u{202F}€"] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        let captures = AMOUNT_SWISS
+            .compile()
+            .unwrap()
+            .captures("CHF 1'234.50")
+            .unwrap();
+        assert_eq!(&captures["currency"], "CHF");
+        assert_eq!(&captures["amount"], "1'234.50");
+    }
+
+    #[test]
+    fn accounting() {
+        let query = ACCOUNTING_AMOUNT.compile().unwrap();
+        let captures = query.captures("($1,234.56)").unwrap();
+        assert_eq!(&captures["amount"], "1,234.56");
+        assert!(!query.is_match("$1,234.56"));
+        assert!(!query.is_match("($1,234.56"));
+    }
+
+    #[test]
+    fn find() {
+        let amounts = find_amounts(
+            "Paid $1,200.00 of USD 2,000; refund ($50.25) and -$3 fee",
+            Separators::ENGLISH,
+        );
+        let values: Vec<(&str, &str)> = amounts
+            .iter()
+            .map(|money| (money.currency.as_str(), money.value.as_str()))
+            .collect();
+        assert_eq!(
+            values,
+            [
+                ("$", "1200.00"),
+                ("USD", "2000"),
+                ("$", "-50.25"),
+                ("$", "-3")
+            ]
+        );
+        let amounts = find_amounts("1 234,56\u{A0}€ et 0,1 €", Separators::FRENCH);
+        assert_eq!(amounts[0].value, "1234.56");
+        assert_eq!(amounts[1].value, "0.1");
+        let underscores = Separators::new(&['_'], '.');
+        assert_eq!(find_amounts("$1_000.5", underscores)[0].value, "1000.5");
+        assert!(find_amounts("nothing to pay", Separators::ENGLISH).is_empty());
+    }
+}
@@ -1,29 +1,178 @@
-use crate::ReadableRe::{Period, Raw};
-use crate::{chars, either, exactly, group, one_or_more, ranged, starts_and_ends_with, ReadableRe};
+use crate::presets::domain::{hostname, top_level_domain};
+use crate::ReadableRe::{Hexadecimal, Period, Raw};
+use crate::{
+    chars, either, exactly, group, named_group, non_capture_group, numeric_range, one_or_more,
+    optional, ranged, starts_and_ends_with, RangeOptions, ReadableRe,
+};
 use once_cell::sync::Lazy;
 
-const U8: Lazy<ReadableRe> = Lazy::new(|| {
-    group(either([
-        chars("0-9"),
-        chars("1-9") + chars("0-9"),
-        Raw("1") + exactly(2, chars("0-9")),
-        Raw("2") + chars("0-4") + chars("0-9"),
-        Raw("25") + chars("0-5"),
-    ]))
-});
+/// `0`-`255`, without leading zeros
+fn octet() -> ReadableRe<'static> {
+    numeric_range(0, 255, RangeOptions::new())
+}
+
+const U8: Lazy<ReadableRe> = Lazy::new(|| group(octet()));
+
+/// `0`-`65535`, without leading zeros
+const U16: Lazy<ReadableRe> = Lazy::new(|| numeric_range(0, 65535, RangeOptions::new()));
 
 /// IPV4 address
 pub const IPV4_ADDRESS: Lazy<ReadableRe> = Lazy::new(|| {
     starts_and_ends_with(group(
-        U8.clone() + ".".into() + U8.clone() + ".".into() + U8.clone() + ".".into() + U8.clone(),
+        U8.clone() + Period + U8.clone() + Period + U8.clone() + Period + U8.clone(),
     ))
 });
 
-/// IPV6 address, found [here](https://regex101.com/r/uwPxJf/1)
-pub const IPV6_ADDRESS: Lazy<ReadableRe> = Lazy::new(|| {
-    // well... [`Raw`] is there to be used too...
-    Raw(
-        r"(?i)(?:[\da-f]{0,4}:){1,7}(?:(?P<ipv4>(?:(?:25[0-5]|2[0-4]\d|1?\d\d?)\.){3}(?:25[0-5]|2[0-4]\d|1?\d\d?))|[\da-f]{0,4})",
+/// Dotted IPv4 address without capture groups
+pub(crate) fn dotted_quad() -> ReadableRe<'static> {
+    exactly(3, non_capture_group(octet() + Period)) + octet()
+}
+
+/// [RFC 4291](https://www.rfc-editor.org/rfc/rfc4291#section-2.2) IPv6 address, without zone
+/// nor capture groups, to build other expressions with.
+///
+/// The `::` compression is accepted in every valid position, and the last 32 bits can be written
+/// as an embedded IPv4 address. When `canonical`, only the
+/// [RFC 5952](https://www.rfc-editor.org/rfc/rfc5952#section-4) forms are accepted: lowercase
+/// hexadecimal without leading zeros and `::` standing for two or more groups. The rule of
+/// compressing the longest run of zeros can not be checked by a regex, use
+/// [`is_canonical_ipv6`] for that.
+pub fn ipv6_address(canonical: bool) -> ReadableRe<'static> {
+    let h16 = || {
+        non_capture_group(if canonical {
+            either([Raw("0"), chars("1-9a-f") + ranged(0..=3, chars("0-9a-f"))])
+        } else {
+            ranged(1..=4, Hexadecimal)
+        })
+    };
+    let h16_colon = || non_capture_group(h16() + Raw(":"));
+    let ls32 = || non_capture_group(either([h16_colon() + h16(), dotted_quad()]));
+    // groups written after the `::`, as (h16 groups, whether the last 32 bits follow)
+    let rights = [
+        (5, true),
+        (4, true),
+        (3, true),
+        (2, true),
+        (1, true),
+        (0, true),
+        (1, false),
+        (0, false),
+    ];
+    let mut rows = vec![exactly(6, h16_colon()) + ls32()];
+    for (left, (right, last)) in rights.into_iter().enumerate() {
+        let written = right + 2 * last as usize;
+        // a canonical `::` stands for two or more groups, so at most 6 groups are written
+        if canonical && written > 6 {
+            continue;
+        }
+        let left = match (canonical, left) {
+            (true, left) => left.min(6 - written),
+            (false, left) => left,
+        };
+        let left = match left {
+            0 => Raw(""),
+            1 => optional(h16()),
+            n => optional(non_capture_group(ranged(0..=n - 1, h16_colon()) + h16())),
+        };
+        let right = match (right, last) {
+            (0, true) => ls32(),
+            (n, true) => exactly(n, h16_colon()) + ls32(),
+            (0, false) => Raw(""),
+            (_, false) => h16(),
+        };
+        rows.push(left + Raw("::") + right);
+    }
+    non_capture_group(either(rows))
+}
+
+/// Interface zone of scoped addresses, `%eth0`
+fn zone() -> ReadableRe<'static> {
+    Raw("%") + one_or_more(chars(r"\w.~-"))
+}
+
+/// IPv6 address, with an optional zone `%eth0`
+pub const IPV6_ADDRESS: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(ipv6_address(false) + optional(non_capture_group(zone()))));
+
+/// IPv6 address in the RFC 5952 canonical text form, see [`ipv6_address`]
+pub const IPV6_ADDRESS_CANONICAL: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(ipv6_address(true)));
+
+/// Bracketed IPv6 address and port, `[::1]:8080`, with `address` and `port` named groups
+pub const IPV6_HOST_PORT: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(
+        Raw(r"\[")
+            + named_group(
+                "address",
+                ipv6_address(false) + optional(non_capture_group(zone())),
+            )
+            + Raw(r"\]:")
+            + named_group("port", U16.clone()),
+    )
+});
+
+/// Whether the text is an IPv6 address written in the RFC 5952 canonical form
+///
+/// ```
+/// use readable_regex::presets::network::is_canonical_ipv6;
+/// assert!(is_canonical_ipv6("2001:db8::1"));
+/// assert!(!is_canonical_ipv6("2001:db8:0:0:0:0:0:1"));
+/// assert!(!is_canonical_ipv6("2001:DB8::1"));
+/// ```
+pub fn is_canonical_ipv6(text: &str) -> bool {
+    text.parse::<std::net::Ipv6Addr>()
+        .is_ok_and(|address| address.to_string() == text)
+}
+
+/// IPv4 network in CIDR notation `10.0.0.0/8`, with `address` and `prefix` named groups
+pub const IPV4_CIDR: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(
+        named_group("address", dotted_quad())
+            + Raw("/")
+            + named_group("prefix", numeric_range(0, 32, RangeOptions::new())),
+    )
+});
+
+/// IPv6 network in CIDR notation `2001:db8::/32`, with `address` and `prefix` named groups
+pub const IPV6_CIDR: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(
+        named_group("address", ipv6_address(false))
+            + Raw("/")
+            + named_group("prefix", numeric_range(0, 128, RangeOptions::new())),
+    )
+});
+
+/// Dotted IPv4 netmask with contiguous ones, from `0.0.0.0` to `255.255.255.255`
+pub const NETMASK: Lazy<ReadableRe> = Lazy::new(|| {
+    let mask = || {
+        non_capture_group(either(
+            ["255", "254", "252", "248", "240", "224", "192", "128", "0"].map(Raw),
+        ))
+    };
+    starts_and_ends_with(non_capture_group(either([
+        Raw(r"255\.255\.255\.") + mask(),
+        Raw(r"255\.255\.") + mask() + Raw(r"\.0"),
+        Raw(r"255\.") + mask() + Raw(r"\.0\.0"),
+        mask() + Raw(r"\.0\.0\.0"),
+    ])))
+});
+
+/// TCP or UDP port number, `0`-`65535`
+pub const PORT: Lazy<ReadableRe> = Lazy::new(|| starts_and_ends_with(U16.clone()));
+
+/// Host and port `example.com:443`, `10.0.0.1:8080` or `[::1]:8080`, with `host` and `port`
+/// named groups (IPv6 hosts keep their brackets)
+pub const HOST_PORT: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(
+        named_group(
+            "host",
+            either([
+                Raw(r"\[") + ipv6_address(false) + optional(non_capture_group(zone())) + Raw(r"\]"),
+                dotted_quad(),
+                hostname(),
+            ]),
+        ) + Raw(":")
+            + named_group("port", U16.clone()),
     )
 });
 
@@ -43,16 +192,18 @@ pub const SIMPLE_EMAIL: Lazy<ReadableRe> = Lazy::new(|| {
     starts_and_ends_with(
         group(one_or_more(chars(r"a-z0-9_\.\+-")))
             + "@".into()
-            + group(one_or_more(chars(r"\da-z\.-")))
+            + group(hostname())
             + Period
-            + group(ranged(2..6, chars(r"a-z\."))),
+            + group(top_level_domain(false)),
     )
 });
 
 #[cfg(test)]
 mod tests {
     use crate::presets::network::{
-        EMAIL, IPV4_ADDRESS, IPV6_ADDRESS, SIMPLE_EMAIL, SIMPLE_URL, U8,
+        is_canonical_ipv6, EMAIL, HOST_PORT, IPV4_ADDRESS, IPV4_CIDR, IPV6_ADDRESS,
+        IPV6_ADDRESS_CANONICAL, IPV6_CIDR, IPV6_HOST_PORT, NETMASK, PORT, SIMPLE_EMAIL, SIMPLE_URL,
+        U16, U8,
     };
     use crate::starts_and_ends_with;
 
@@ -72,6 +223,84 @@ mod tests {
             let s = format!("{0}.{0}.{0}.{0}", v);
             assert!(query.is_match(&s), "Failed to match: {}", s);
         }
+        assert!(!query.is_match("1a2b3c4"));
+    }
+
+    #[test]
+    fn cidr() {
+        let query = IPV4_CIDR.compile().unwrap();
+        let captures = query.captures("10.0.0.0/8").unwrap();
+        assert_eq!(
+            (&captures["address"], &captures["prefix"]),
+            ("10.0.0.0", "8")
+        );
+        for v in ["0.0.0.0/0", "192.168.1.0/24", "1.2.3.4/32"] {
+            assert!(query.is_match(v), "Failed to match: {}", v);
+        }
+        for v in ["10.0.0.0/33", "10.0.0.0", "10.0.0/8", "10.0.0.0/08"] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        let query = IPV6_CIDR.compile().unwrap();
+        let captures = query.captures("2001:db8::/32").unwrap();
+        assert_eq!(
+            (&captures["address"], &captures["prefix"]),
+            ("2001:db8::", "32")
+        );
+        for v in ["::/0", "fe80::/10", "::1/128", "2001:db8::/119"] {
+            assert!(query.is_match(v), "Failed to match: {}", v);
+        }
+        for v in ["::1/129", "2001:db8::", "2001:db8:/32"] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+    }
+
+    #[test]
+    fn netmask() {
+        let query = NETMASK.compile().unwrap();
+        for prefix in 0..=32u32 {
+            let mask = std::net::Ipv4Addr::from(u32::MAX.checked_shl(32 - prefix).unwrap_or(0));
+            assert!(
+                query.is_match(&mask.to_string()),
+                "Failed to match: {}",
+                mask
+            );
+        }
+        for v in [
+            "255.0.255.0",
+            "255.255.255.1",
+            "0.255.255.255",
+            "255.255.0.128",
+            "256.0.0.0",
+        ] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+    }
+
+    #[test]
+    fn port_and_host_port() {
+        let query = PORT.compile().unwrap();
+        assert!(query.is_match("0"));
+        assert!(query.is_match("65535"));
+        assert!(!query.is_match("65536"));
+        assert!(!query.is_match("080"));
+        let query = HOST_PORT.compile().unwrap();
+        for (v, host, port) in [
+            ("example.com:443", "example.com", "443"),
+            ("10.0.0.1:8080", "10.0.0.1", "8080"),
+            ("[::1]:8080", "[::1]", "8080"),
+            ("localhost:22", "localhost", "22"),
+        ] {
+            let captures = query.captures(v).unwrap();
+            assert_eq!((&captures["host"], &captures["port"]), (host, port));
+        }
+        for v in [
+            "example.com",
+            "::1:8080",
+            "-bad.com:80",
+            "example.com:70000",
+        ] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
     }
 
     #[test]
@@ -79,17 +308,11 @@ mod tests {
         let ips = [
             "2001:db8:3333:4444:5555:6666:7777:8888",
             "2001:db8:3333:4444:CCCC:DDDD:EEEE:FFFF",
-            "2001:0db8:0001:0000:0000:0ab9:C0A8:",
-            "fffe:3465:efab:23fe:2235:6565",
-            "2001:0000:6dcd:8c74:::ac32:6a1",
-            "2345:0425:2CA1:::5673:23b5",
             "2001:db8:1::ab9:C0A8:102",
             "2001:db8::1234:5678",
             "FF02:0:0:0:0:0:0:2",
             "fdf8:f53b:82e4::53",
             "fe80::200:5aee:feaa:20a2",
-            "2001:0000:4136:e378:",
-            "8000:63bf:3fff:fdd2",
             "2001:db8::",
             "::1234:5678",
             "2000::",
@@ -113,7 +336,6 @@ mod tests {
             "::8",
             "::",
             "::1",
-            ":1",
             "::ffff:192.0.2.47",
             "::255.255.255.255",
             "::ffff:255.255.255.255",
@@ -133,7 +355,76 @@ mod tests {
         ];
         let query = IPV6_ADDRESS.compile().unwrap();
         for ip in ips {
-            assert!(query.is_match(ip));
+            assert!(query.is_match(ip), "Failed to match: {}", ip);
+            assert!(ip.parse::<std::net::Ipv6Addr>().is_ok());
+        }
+        let invalid = [
+            "2001:0db8:0001:0000:0000:0ab9:C0A8:",
+            "fffe:3465:efab:23fe:2235:6565",
+            "2001:0000:6dcd:8c74:::ac32:6a1",
+            "2345:0425:2CA1:::5673:23b5",
+            "2001:0000:4136:e378:",
+            "8000:63bf:3fff:fdd2",
+            ":1",
+            "1::2::3",
+            "1:2:3:4:5:6:7:8:9",
+            "1:2:3:4:5:6:7::8",
+            "12345::",
+            "::256.1.1.1",
+            "::1.2.3",
+            "g::1",
+        ];
+        for ip in invalid {
+            assert!(!query.is_match(ip), "Wrongly matched: {}", ip);
+            assert!(ip.parse::<std::net::Ipv6Addr>().is_err());
+        }
+        assert!(query.is_match("fe80::1%eth0"));
+    }
+
+    #[test]
+    fn ipv6_canonical() {
+        let query = IPV6_ADDRESS_CANONICAL.compile().unwrap();
+        for ip in [
+            "2001:db8::1",
+            "::",
+            "::1",
+            "fe80::200:5aee:feaa:20a2",
+            "2001:db8:0:1:1:1:1:1",
+            "::ffff:192.0.2.47",
+        ] {
+            assert!(query.is_match(ip), "Failed to match: {}", ip);
+            assert!(is_canonical_ipv6(ip), "Not canonical: {}", ip);
+        }
+        for ip in ["2001:DB8::1", "2001:0db8::1", "2001:db8::1:1:1:1:1:1"] {
+            assert!(!query.is_match(ip), "Wrongly matched: {}", ip);
+            assert!(!is_canonical_ipv6(ip), "Canonical: {}", ip);
+        }
+        // runs of zeros that should be compressed, only the function notices
+        for ip in ["2001:0:0:0:1::1", "2001:db8:0:0:0:0:0:1"] {
+            assert!(query.is_match(ip), "Failed to match: {}", ip);
+            assert!(!is_canonical_ipv6(ip), "Canonical: {}", ip);
+        }
+    }
+
+    #[test]
+    fn ipv6_host_port() {
+        let query = IPV6_HOST_PORT.compile().unwrap();
+        let captures = query.captures("[::1]:8080").unwrap();
+        assert_eq!(&captures["address"], "::1");
+        assert_eq!(&captures["port"], "8080");
+        assert!(query.is_match("[fe80::1%25eth0]:443"));
+        assert!(!query.is_match("::1:8080"));
+        assert!(!query.is_match("[::1]:65536"));
+    }
+
+    #[test]
+    fn u16_re() {
+        let query = starts_and_ends_with(U16.clone()).compile().unwrap();
+        for i in 0..=65535 {
+            assert!(query.is_match(&i.to_string()), "Failed to match: {}", i);
+        }
+        for v in ["65536", "99999", "01", "100000"] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
         }
     }
 
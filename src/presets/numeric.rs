@@ -1,6 +1,7 @@
 use crate::ReadableRe::*;
 use crate::*;
 use once_cell::sync::Lazy;
+use regex::Regex;
 
 pub const POSITIVE_INTEGER: Lazy<ReadableRe> =
     Lazy::new(|| starts_and_ends_with(one_or_more(Digit)));
@@ -33,11 +34,420 @@ pub const FRACTION: Lazy<ReadableRe> = Lazy::new(|| {
     ))
 });
 
+/// ASCII digits, with single `_` between them
+pub(crate) fn digits_of(class: &'static str) -> ReadableRe<'static> {
+    chars(class) + zero_or_more(non_capture_group(optional(Raw("_")) + chars(class)))
+}
+
+/// Exponent `e-3`, digits separated by `_` when `underscores`
+pub(crate) fn exponent(underscores: bool) -> ReadableRe<'static> {
+    let digits = if underscores {
+        digits_of("0-9")
+    } else {
+        one_or_more(chars("0-9"))
+    };
+    chars("eE") + optional(chars("+-")) + digits
+}
+
+/// Number in scientific notation `-1.5e-3`, with `mantissa` and `exponent` named groups
+pub const SCIENTIFIC: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(
+        named_group(
+            "mantissa",
+            optional(chars("+-"))
+                + non_capture_group(either([
+                    one_or_more(chars("0-9"))
+                        + optional(non_capture_group(Raw(r"\.") + zero_or_more(chars("0-9")))),
+                    Raw(r"\.") + one_or_more(chars("0-9")),
+                ])),
+        ) + chars("eE")
+            + named_group(
+                "exponent",
+                optional(chars("+-")) + one_or_more(chars("0-9")),
+            ),
+    )
+});
+
+/// Hexadecimal literal `0xFF_FF`, optionally signed
+pub const HEX_LITERAL: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(optional(chars("+-")) + Raw("0") + chars("xX") + digits_of("0-9a-fA-F"))
+});
+
+/// Octal literal `0o755`, optionally signed
+pub const OCTAL_LITERAL: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(optional(chars("+-")) + Raw("0") + chars("oO") + digits_of("0-7"))
+});
+
+/// Binary literal `0b1010_0101`, optionally signed
+pub const BINARY_LITERAL: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(optional(chars("+-")) + Raw("0") + chars("bB") + digits_of("01"))
+});
+
+/// [JSON](https://www.rfc-editor.org/rfc/rfc8259#section-6) number `-0.5e+10`
+pub const JSON_NUMBER: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(
+        optional(Raw("-"))
+            + non_capture_group(either([
+                Raw("0"),
+                chars("1-9") + zero_or_more(chars("0-9")),
+            ]))
+            + optional(non_capture_group(Raw(r"\.") + one_or_more(chars("0-9"))))
+            + optional(non_capture_group(exponent(false))),
+    )
+});
+
+/// Rust integer literal with its optional type suffix, `0xFFu8`, `1_000i64`
+pub(crate) fn rust_integer() -> ReadableRe<'static> {
+    let integer_suffix =
+        chars("iu") + non_capture_group(either(["8", "16", "32", "64", "128", "size"].map(Raw)));
+    non_capture_group(either([
+        Raw("0x") + digits_of("0-9a-fA-F"),
+        Raw("0o") + digits_of("0-7"),
+        Raw("0b") + digits_of("01"),
+        digits_of("0-9"),
+    ])) + optional(non_capture_group(integer_suffix))
+}
+
+/// Rust float literal with its optional type suffix, `2.5e-3f32`, `1.`
+pub(crate) fn rust_float() -> ReadableRe<'static> {
+    let decimal = || digits_of("0-9");
+    let float_suffix = || Raw("f") + non_capture_group(either([Raw("32"), Raw("64")]));
+    decimal()
+        + non_capture_group(either([
+            Raw(r"\.")
+                + decimal()
+                + optional(non_capture_group(exponent(true)))
+                + optional(non_capture_group(float_suffix())),
+            exponent(true) + optional(non_capture_group(float_suffix())),
+            float_suffix(),
+            Raw(r"\."),
+        ]))
+}
+
+/// [Rust](https://doc.rust-lang.org/reference/tokens.html#number-literals) integer or float
+/// literal, with `_` separators and type suffixes, `0xFFu8`, `1_000i64`, `2.5e-3f32`
+pub const RUST_NUMBER: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(non_capture_group(either([rust_float(), rust_integer()]))));
+
+/// C integer constant with its suffix, `0x1Fu`, `0755`, `10UL`
+pub(crate) fn c_integer() -> ReadableRe<'static> {
+    let long = || non_capture_group(either(["ll", "LL", "l", "L"].map(Raw)));
+    let integer_suffix = either([
+        chars("uU") + optional(long()),
+        long() + optional(chars("uU")),
+    ]);
+    non_capture_group(either([
+        Raw("0") + chars("xX") + one_or_more(chars("0-9a-fA-F")),
+        Raw("0") + chars("bB") + one_or_more(chars("01")),
+        Raw("0") + zero_or_more(chars("0-7")),
+        chars("1-9") + zero_or_more(chars("0-9")),
+    ])) + optional(non_capture_group(integer_suffix))
+}
+
+/// C floating constant with its suffix, `.5e3f`, `1.0L`
+pub(crate) fn c_float() -> ReadableRe<'static> {
+    non_capture_group(either([
+        non_capture_group(either([
+            one_or_more(chars("0-9")) + Raw(r"\.") + zero_or_more(chars("0-9")),
+            Raw(r"\.") + one_or_more(chars("0-9")),
+        ])) + optional(non_capture_group(exponent(false))),
+        one_or_more(chars("0-9")) + exponent(false),
+    ])) + optional(chars("fFlL"))
+}
+
+/// C integer or floating constant, with its suffixes, `0x1Fu`, `0755`, `10UL`, `.5e3f`
+pub const C_NUMBER: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(non_capture_group(either([c_float(), c_integer()]))));
+
+/// Explicitly signed number, `+5`, `-0.25`
+pub const SIGNED_NUMBER: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(
+        chars("+-")
+            + one_or_more(chars("0-9"))
+            + optional(non_capture_group(Raw(r"\.") + one_or_more(chars("0-9")))),
+    )
+});
+
+/// Percentage `12.5%`, `-3 %` or `+0.1%`, with a `value` named group
+pub const PERCENTAGE: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(
+        named_group(
+            "value",
+            optional(chars("+-"))
+                + one_or_more(chars("0-9"))
+                + optional(non_capture_group(Raw(r"\.") + one_or_more(chars("0-9")))),
+        ) + optional(Raw(" "))
+            + Raw("%"),
+    )
+});
+
+/// Thousands group and decimal separators of a number
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Separators {
+    pub(crate) group: &'static [char],
+    pub(crate) decimal: char,
+}
+
+impl Separators {
+    /// `1,234.56`
+    pub const ENGLISH: Separators = Separators::new(&[','], '.');
+    /// `1.234,56`
+    pub const GERMAN: Separators = Separators::new(&['.'], ',');
+    /// `1 234,56`, with a space, a no-break space or a narrow no-break space
+    pub const FRENCH: Separators = Separators::new(&[' ', '\u{A0}', '\u{202F}'], ',');
+    /// `1'234.56`, with an apostrophe or a right single quotation mark
+    pub const SWISS: Separators = Separators::new(&['\'', '\u{2019}'], '.');
+
+    /// The separators with a preset, whose expressions are compiled once
+    pub(crate) const PRESETS: [Separators; 4] = [
+        Separators::ENGLISH,
+        Separators::GERMAN,
+        Separators::FRENCH,
+        Separators::SWISS,
+    ];
+
+    /// Any of the `group` characters between thousands, and the `decimal` one before decimals
+    pub const fn new(group: &'static [char], decimal: char) -> Self {
+        Self { group, decimal }
+    }
+}
+
+/// Number with its thousands grouped by the `separators`, optionally signed, without capture
+/// groups. Numbers can also be written without grouping, `1234,56` for [`Separators::GERMAN`].
+pub fn grouped_number(separators: Separators) -> ReadableRe<'static> {
+    let group: std::string::String = separators
+        .group
+        .iter()
+        .map(|c| regex::escape(&c.to_string()))
+        .collect();
+    let group = Chars(solvers::Chars::new(&group));
+    let decimal = string_regex(regex::escape(&separators.decimal.to_string()));
+    optional(chars("+-"))
+        + non_capture_group(either([
+            chars("1-9")
+                + ranged(0..=2, chars("0-9"))
+                + one_or_more(non_capture_group(group + exactly(3, chars("0-9")))),
+            one_or_more(chars("0-9")),
+        ]))
+        + optional(non_capture_group(decimal + one_or_more(chars("0-9"))))
+}
+
+/// Number grouped as in [`Separators::ENGLISH`], `1,234.56`
+pub const GROUPED_NUMBER_ENGLISH: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(grouped_number(Separators::ENGLISH)));
+
+/// Number grouped as in [`Separators::GERMAN`], `1.234,56`
+pub const GROUPED_NUMBER_GERMAN: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(grouped_number(Separators::GERMAN)));
+
+/// Number grouped as in [`Separators::FRENCH`], `1 234,56`
+pub const GROUPED_NUMBER_FRENCH: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(grouped_number(Separators::FRENCH)));
+
+/// Number grouped as in [`Separators::SWISS`], `1'234.56`
+pub const GROUPED_NUMBER_SWISS: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(grouped_number(Separators::SWISS)));
+
+/// Value of a parsed [`RUST_NUMBER`] or [`C_NUMBER`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Number {
+    Integer(i128),
+    Float(f64),
+}
+
+/// Compiled presets used by the parse functions
+struct Formats {
+    scientific: Regex,
+    literals: [Regex; 3],
+    json: Regex,
+    rust: Regex,
+    c: Regex,
+    signed: Regex,
+    percentage: Regex,
+    grouped: [Regex; 4],
+}
+
+static FORMATS: Lazy<Formats> = Lazy::new(|| Formats {
+    scientific: SCIENTIFIC.compile().unwrap(),
+    literals: [&HEX_LITERAL, &OCTAL_LITERAL, &BINARY_LITERAL].map(|re| re.compile().unwrap()),
+    json: JSON_NUMBER.compile().unwrap(),
+    rust: RUST_NUMBER.compile().unwrap(),
+    c: C_NUMBER.compile().unwrap(),
+    signed: SIGNED_NUMBER.compile().unwrap(),
+    percentage: PERCENTAGE.compile().unwrap(),
+    grouped: Separators::PRESETS.map(|separators| {
+        starts_and_ends_with(grouped_number(separators))
+            .compile()
+            .unwrap()
+    }),
+});
+
+/// Integer of the `digits` in the `radix`, negated when `negative`, ignoring `_` separators
+fn integer(digits: &str, radix: u32, negative: bool) -> Option<i128> {
+    let digits: std::string::String = digits.chars().filter(|&c| c != '_').collect();
+    let value = i128::from_str_radix(&digits, radix).ok()?;
+    Some(if negative { -value } else { value })
+}
+
+/// Value of a [`SCIENTIFIC`] number
+///
+/// ```
+/// use readable_regex::presets::numeric::parse_scientific;
+/// assert_eq!(parse_scientific("-1.5e-3"), Some(-0.0015));
+/// assert_eq!(parse_scientific("1.5"), None);
+/// ```
+pub fn parse_scientific(text: &str) -> Option<f64> {
+    FORMATS
+        .scientific
+        .is_match(text)
+        .then(|| text.parse().ok())?
+}
+
+/// Value of a [`HEX_LITERAL`], [`OCTAL_LITERAL`] or [`BINARY_LITERAL`]
+///
+/// ```
+/// use readable_regex::presets::numeric::parse_integer_literal;
+/// assert_eq!(parse_integer_literal("0xFF_FF"), Some(0xFFFF));
+/// assert_eq!(parse_integer_literal("-0b101"), Some(-5));
+/// ```
+pub fn parse_integer_literal(text: &str) -> Option<i128> {
+    let radix = [16, 8, 2]
+        .into_iter()
+        .zip(&FORMATS.literals)
+        .find_map(|(radix, format)| format.is_match(text).then_some(radix))?;
+    let negative = text.starts_with('-');
+    let digits = text.trim_start_matches(['+', '-']);
+    integer(&digits[2..], radix, negative)
+}
+
+/// Value of a [`JSON_NUMBER`]
+pub fn parse_json_number(text: &str) -> Option<f64> {
+    FORMATS.json.is_match(text).then(|| text.parse().ok())?
+}
+
+/// Value of a [`RUST_NUMBER`], its type suffix being ignored
+///
+/// ```
+/// use readable_regex::presets::numeric::{parse_rust_number, Number};
+/// assert_eq!(parse_rust_number("0xFFu8"), Some(Number::Integer(255)));
+/// assert_eq!(parse_rust_number("1_000.5f32"), Some(Number::Float(1000.5)));
+/// ```
+pub fn parse_rust_number(text: &str) -> Option<Number> {
+    if !FORMATS.rust.is_match(text) {
+        return None;
+    }
+    for (prefix, radix) in [("0x", 16), ("0o", 8), ("0b", 2)] {
+        if let Some(digits) = text.strip_prefix(prefix) {
+            let digits = digits.split(['i', 'u']).next()?;
+            return integer(digits, radix, false).map(Number::Integer);
+        }
+    }
+    if let Some(digits) = text
+        .split(['i', 'u'])
+        .next()
+        .filter(|d| d.len() < text.len())
+    {
+        return integer(digits, 10, false).map(Number::Integer);
+    }
+    let number: std::string::String = text
+        .trim_end_matches("f32")
+        .trim_end_matches("f64")
+        .chars()
+        .filter(|&c| c != '_')
+        .collect();
+    if number.contains(['.', 'e', 'E']) || number.len() < text.len() {
+        number.parse().ok().map(Number::Float)
+    } else {
+        integer(&number, 10, false).map(Number::Integer)
+    }
+}
+
+/// Value of a [`C_NUMBER`], its suffix being ignored
+///
+/// ```
+/// use readable_regex::presets::numeric::{parse_c_number, Number};
+/// assert_eq!(parse_c_number("0755"), Some(Number::Integer(493)));
+/// assert_eq!(parse_c_number(".5e1f"), Some(Number::Float(5.0)));
+/// ```
+pub fn parse_c_number(text: &str) -> Option<Number> {
+    if !FORMATS.c.is_match(text) {
+        return None;
+    }
+    let lower = text.to_ascii_lowercase();
+    if let Some(digits) = lower.strip_prefix("0x") {
+        return integer(digits.trim_end_matches(['u', 'l']), 16, false).map(Number::Integer);
+    }
+    if let Some(digits) = lower.strip_prefix("0b") {
+        return integer(digits.trim_end_matches(['u', 'l']), 2, false).map(Number::Integer);
+    }
+    if lower.contains(['.', 'e']) {
+        return lower
+            .trim_end_matches(['f', 'l'])
+            .parse()
+            .ok()
+            .map(Number::Float);
+    }
+    let digits = lower.trim_end_matches(['u', 'l']);
+    match digits.strip_prefix('0') {
+        Some(octal) if !octal.is_empty() => integer(octal, 8, false),
+        _ => integer(digits, 10, false),
+    }
+    .map(Number::Integer)
+}
+
+/// Value of a [`SIGNED_NUMBER`]
+pub fn parse_signed_number(text: &str) -> Option<f64> {
+    FORMATS.signed.is_match(text).then(|| text.parse().ok())?
+}
+
+/// Ratio of a [`PERCENTAGE`], `0.125` for `12.5%`
+pub fn parse_percentage(text: &str) -> Option<f64> {
+    let captures = FORMATS.percentage.captures(text)?;
+    captures["value"]
+        .parse::<f64>()
+        .ok()
+        .map(|value| value / 100.0)
+}
+
+/// Value of a [`grouped_number`] with the `separators`. The expression is compiled once for the
+/// [`Separators`] constants, but on every call for other separators.
+///
+/// ```
+/// use readable_regex::presets::numeric::{parse_grouped_number, Separators};
+/// assert_eq!(parse_grouped_number("1.234,56", Separators::GERMAN), Some(1234.56));
+/// assert_eq!(parse_grouped_number("1,234.56", Separators::GERMAN), None);
+/// ```
+pub fn parse_grouped_number(text: &str, separators: Separators) -> Option<f64> {
+    let matched = match Separators::PRESETS.iter().position(|s| *s == separators) {
+        Some(i) => FORMATS.grouped[i].is_match(text),
+        None => starts_and_ends_with(grouped_number(separators))
+            .compile()
+            .ok()?
+            .is_match(text),
+    };
+    if !matched {
+        return None;
+    }
+    ungrouped(text, separators).parse().ok()
+}
+
+/// The [`grouped_number`] `text` without its group separators and with a `.` decimal separator
+pub(crate) fn ungrouped(text: &str, separators: Separators) -> std::string::String {
+    text.chars()
+        .filter(|c| !separators.group.contains(c))
+        .map(|c| if c == separators.decimal { '.' } else { c })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use crate::presets::numeric::{
-        DECIMAL, FRACTION, INTEGER, NEGATIVE_DECIMAL, NEGATIVE_INTEGER, POSITIVE_DECIMAL,
-        POSITIVE_INTEGER,
+        parse_c_number, parse_grouped_number, parse_integer_literal, parse_json_number,
+        parse_percentage, parse_rust_number, parse_scientific, parse_signed_number, Number,
+        Separators, BINARY_LITERAL, C_NUMBER, DECIMAL, FRACTION, GROUPED_NUMBER_ENGLISH,
+        GROUPED_NUMBER_FRENCH, GROUPED_NUMBER_GERMAN, GROUPED_NUMBER_SWISS, HEX_LITERAL, INTEGER,
+        JSON_NUMBER, NEGATIVE_DECIMAL, NEGATIVE_INTEGER, OCTAL_LITERAL, PERCENTAGE,
+        POSITIVE_DECIMAL, POSITIVE_INTEGER, RUST_NUMBER, SCIENTIFIC, SIGNED_NUMBER,
     };
 
     #[test]
@@ -91,4 +501,130 @@ mod tests {
         assert!(query.is_match("9\\6"));
         assert!(!query.is_match("9/a"));
     }
+
+    #[test]
+    fn scientific() {
+        let query = SCIENTIFIC.compile().unwrap();
+        let captures = query.captures("-1.5e-3").unwrap();
+        assert_eq!(&captures["mantissa"], "-1.5");
+        assert_eq!(&captures["exponent"], "-3");
+        for v in ["1e10", "+.5E3", "6.022e23", "1.e2"] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in ["1.5", "e3", "1e", "1e+"] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        assert_eq!(parse_scientific("6.022e23"), Some(6.022e23));
+    }
+
+    #[test]
+    fn integer_literals() {
+        assert!(HEX_LITERAL.compile().unwrap().is_match("0xDEAD_beef"));
+        assert!(!HEX_LITERAL.compile().unwrap().is_match("0xG1"));
+        assert!(OCTAL_LITERAL.compile().unwrap().is_match("0o755"));
+        assert!(!OCTAL_LITERAL.compile().unwrap().is_match("0o8"));
+        assert!(BINARY_LITERAL.compile().unwrap().is_match("-0b1010_0101"));
+        assert!(!BINARY_LITERAL.compile().unwrap().is_match("0b1__0"));
+        assert_eq!(parse_integer_literal("0xDEAD_beef"), Some(0xDEAD_BEEF));
+        assert_eq!(parse_integer_literal("+0o755"), Some(0o755));
+        assert_eq!(parse_integer_literal("1234"), None);
+    }
+
+    #[test]
+    fn json_number() {
+        let query = JSON_NUMBER.compile().unwrap();
+        for v in ["0", "-0", "12", "-0.5", "1e10", "1.5E+3"] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in ["01", "+1", ".5", "1.", "0x10", "1e"] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        assert_eq!(parse_json_number("-0.5"), Some(-0.5));
+    }
+
+    #[test]
+    fn rust_number() {
+        let query = RUST_NUMBER.compile().unwrap();
+        for v in [
+            "1_000",
+            "0xFFu8",
+            "0o7",
+            "0b1i32",
+            "2.5e-3f32",
+            "1.",
+            "1f64",
+            "1e10",
+        ] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in ["-1", "1.f32", "0x", "1u7", "_1"] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        assert_eq!(parse_rust_number("1_000i64"), Some(Number::Integer(1000)));
+        assert_eq!(parse_rust_number("0b1i32"), Some(Number::Integer(1)));
+        assert_eq!(parse_rust_number("1."), Some(Number::Float(1.0)));
+        assert_eq!(parse_rust_number("1f64"), Some(Number::Float(1.0)));
+        assert_eq!(parse_rust_number("2.5e-3f32"), Some(Number::Float(2.5e-3)));
+    }
+
+    #[test]
+    fn c_number() {
+        let query = C_NUMBER.compile().unwrap();
+        for v in [
+            "0", "0755", "0x1Fu", "10UL", "42llu", ".5e3f", "1.", "1e-3L",
+        ] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in ["089", "1uu", "0x", "1e", "1_000"] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        assert_eq!(parse_c_number("0x1Fu"), Some(Number::Integer(31)));
+        assert_eq!(parse_c_number("10UL"), Some(Number::Integer(10)));
+        assert_eq!(parse_c_number("0"), Some(Number::Integer(0)));
+        assert_eq!(parse_c_number("1e-3L"), Some(Number::Float(1e-3)));
+    }
+
+    #[test]
+    fn signed_and_percentage() {
+        let query = SIGNED_NUMBER.compile().unwrap();
+        assert!(query.is_match("+5"));
+        assert!(query.is_match("-0.25"));
+        assert!(!query.is_match("5"));
+        assert_eq!(parse_signed_number("+5"), Some(5.0));
+        let query = PERCENTAGE.compile().unwrap();
+        for v in ["12.5%", "-3 %", "+0.1%", "100%"] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        assert!(!query.is_match("12.5"));
+        assert_eq!(parse_percentage("12.5%"), Some(0.125));
+    }
+
+    #[test]
+    fn grouped_numbers() {
+        for (re, separators, text) in [
+            (GROUPED_NUMBER_ENGLISH, Separators::ENGLISH, "1,234,567.89"),
+            (GROUPED_NUMBER_GERMAN, Separators::GERMAN, "1.234.567,89"),
+            (GROUPED_NUMBER_FRENCH, Separators::FRENCH, "1 234 567,89"),
+            (GROUPED_NUMBER_SWISS, Separators::SWISS, "1'234'567.89"),
+        ] {
+            let query = re.compile().unwrap();
+            assert!(query.is_match(text), "Failed matching: {}", text);
+            assert_eq!(parse_grouped_number(text, separators), Some(1234567.89));
+        }
+        let query = GROUPED_NUMBER_ENGLISH.compile().unwrap();
+        for v in ["0", "-12", "1234.5", "999,999"] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in ["1,23", "1,2345", ",123", "1.234,56", "0,123"] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        assert!(GROUPED_NUMBER_FRENCH
+            .compile()
+            .unwrap()
+            .is_match("1\u{202F}234,5"));
+        assert_eq!(parse_grouped_number("1.234,56", Separators::ENGLISH), None);
+        let underscores = Separators::new(&['_'], '.');
+        assert_eq!(parse_grouped_number("1_234.5", underscores), Some(1234.5));
+        assert_eq!(parse_grouped_number("1_23.5", underscores), None);
+    }
 }
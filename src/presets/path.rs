@@ -0,0 +1,296 @@
+//! File system paths and file names, for POSIX and Windows
+//!
+//! The path presets name their parts as [`std::path::Path`] does: `parent` is everything before
+//! the last component, with its separators, and `file_name` the last component, `stem` and
+//! `extension` being its parts around the last dot. A trailing separator is allowed, so the
+//! `file_name` of `/usr/bin/` is `bin`.
+use crate::ReadableRe::{self, BackSlash, Raw};
+use crate::{
+    chars, either, named_group, non_capture_group, not_chars, one_or_more, one_or_more_lazy,
+    optional, starts_and_ends_with, zero_or_more_lazy,
+};
+use once_cell::sync::Lazy;
+
+/// Characters that can't be part of a file name on Windows, besides the separators
+const WINDOWS_FORBIDDEN: &str = r#"<>:"|?*\x00-\x1F"#;
+
+/// Names reserved for devices on Windows, whatever their extension
+const WINDOWS_RESERVED: [&str; 22] = [
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+/// File system flavour of the paths
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Platform {
+    /// `/` separated, any character but `/` and NUL in file names
+    Posix,
+    /// `\` or `/` separated, without `<>:"|?*` nor control characters in file names
+    Windows,
+}
+
+impl Platform {
+    /// Characters excluded from file names, separators included
+    fn excluded(self) -> String {
+        match self {
+            Platform::Posix => r"/\x00".to_string(),
+            Platform::Windows => format!(r"\\/{WINDOWS_FORBIDDEN}"),
+        }
+    }
+
+    fn separator(self) -> ReadableRe<'static> {
+        match self {
+            Platform::Posix => Raw("/"),
+            Platform::Windows => chars(r"\\/"),
+        }
+    }
+}
+
+/// A file name character of the platform
+fn name_char(platform: Platform) -> ReadableRe<'static> {
+    not_chars(&platform.excluded())
+}
+
+/// Path component, without capture groups
+pub fn component(platform: Platform) -> ReadableRe<'static> {
+    one_or_more(name_char(platform))
+}
+
+/// File name with the `stem` and the optional `extension` (after the last dot) named groups.
+/// Only valid at the end of an expression, as the stem is lazy.
+fn file_name_parts(platform: Platform) -> ReadableRe<'static> {
+    let extension = not_chars(&format!(r"\.{}", platform.excluded()));
+    named_group("stem", one_or_more_lazy(name_char(platform)))
+        + optional(non_capture_group(
+            Raw(r"\.") + named_group("extension", one_or_more(extension)),
+        ))
+}
+
+/// Components after a root: `parent` and the optional `file_name` named groups and a trailing
+/// separator
+fn tail(platform: Platform, root: ReadableRe<'static>) -> ReadableRe<'static> {
+    let separator = || platform.separator();
+    named_group(
+        "parent",
+        root + zero_or_more_lazy(non_capture_group(component(platform) + separator())),
+    ) + optional(non_capture_group(
+        named_group("file_name", file_name_parts(platform)) + optional(separator()),
+    ))
+}
+
+/// POSIX absolute path `/usr/lib/libc.so.6`, see the [module](self) documentation for the named
+/// groups
+pub const POSIX_ABSOLUTE_PATH: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(tail(Platform::Posix, Raw("/"))));
+
+/// POSIX relative path `src/main.rs`, `../README.md`, see the [module](self) documentation for
+/// the named groups
+pub const POSIX_RELATIVE_PATH: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(
+        named_group(
+            "parent",
+            zero_or_more_lazy(non_capture_group(component(Platform::Posix) + Raw("/"))),
+        ) + named_group("file_name", file_name_parts(Platform::Posix))
+            + optional(Raw("/")),
+    )
+});
+
+/// Windows drive path `C:\Windows\System32\notepad.exe`, with a `drive` named group, see the
+/// [module](self) documentation for the others
+pub const WINDOWS_PATH: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(
+        named_group("drive", chars("A-Za-z") + Raw(":"))
+            + tail(Platform::Windows, Platform::Windows.separator()),
+    )
+});
+
+/// `\\server\share` of a UNC path, with `server` and `share` named groups
+fn unc_root() -> ReadableRe<'static> {
+    named_group("server", component(Platform::Windows))
+        + BackSlash
+        + named_group("share", component(Platform::Windows))
+}
+
+/// Windows UNC path `\\server\share\dir\file.txt`, with `server` and `share` named groups, see
+/// the [module](self) documentation for the others
+pub const UNC_PATH: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(
+        BackSlash
+            + BackSlash
+            + unc_root()
+            + optional(non_capture_group(tail(Platform::Windows, BackSlash))),
+    )
+});
+
+/// Windows extended-length path `\\?\C:\very\long\path` or `\\?\UNC\server\share\path`, with
+/// a `volume` named group (`C:` or `UNC\server\share`), see the [module](self) documentation
+/// for the others. Only `\` separates their components.
+pub const EXTENDED_LENGTH_PATH: Lazy<ReadableRe> = Lazy::new(|| {
+    let component = || one_or_more(not_chars(&format!(r"\\{WINDOWS_FORBIDDEN}")));
+    let volume = either([
+        chars("A-Za-z") + Raw(":"),
+        Raw("UNC") + BackSlash + component() + BackSlash + component(),
+    ]);
+    starts_and_ends_with(
+        Raw(r"\\\\\?\\")
+            + named_group("volume", volume)
+            + optional(non_capture_group(
+                named_group(
+                    "parent",
+                    BackSlash + zero_or_more_lazy(non_capture_group(component() + BackSlash)),
+                ) + optional(named_group("file_name", file_name_parts(Platform::Windows))),
+            )),
+    )
+});
+
+/// POSIX file name `archive.tar.gz`, with `stem` and `extension` named groups, see
+/// [`is_valid_file_name`] for the rules a pattern can't check
+pub const FILE_NAME_POSIX: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(file_name_parts(Platform::Posix)));
+
+/// Windows file name `report.docx`, with `stem` and `extension` named groups, see
+/// [`is_valid_file_name`] for the rules a pattern can't check
+pub const FILE_NAME_WINDOWS: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(file_name_parts(Platform::Windows)));
+
+/// Whether the name can be given to a file on the platform: at most 255 bytes on POSIX (255
+/// characters on Windows), neither `.` nor `..`, without separators nor forbidden characters,
+/// and on Windows, neither ending with a space or a dot nor a reserved device name (`CON`,
+/// `NUL.txt`, `com1`...)
+///
+/// ```
+/// use readable_regex::presets::path::{is_valid_file_name, Platform};
+/// assert!(is_valid_file_name("notes:draft.txt", Platform::Posix));
+/// assert!(!is_valid_file_name("notes:draft.txt", Platform::Windows));
+/// assert!(!is_valid_file_name("aux.log", Platform::Windows));
+/// ```
+pub fn is_valid_file_name(name: &str, platform: Platform) -> bool {
+    if matches!(name, "" | "." | "..") {
+        return false;
+    }
+    match platform {
+        Platform::Posix => name.len() <= 255 && !name.contains(['/', '\0']),
+        Platform::Windows => {
+            let stem = name.split('.').next().unwrap_or(name);
+            name.chars().count() <= 255
+                && !name
+                    .chars()
+                    .any(|c| c.is_ascii_control() || r#"<>:"|?*\/"#.contains(c))
+                && !name.ends_with([' ', '.'])
+                && !WINDOWS_RESERVED
+                    .iter()
+                    .any(|reserved| reserved.eq_ignore_ascii_case(stem.trim_end()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::presets::path::{
+        is_valid_file_name, Platform, EXTENDED_LENGTH_PATH, FILE_NAME_POSIX, FILE_NAME_WINDOWS,
+        POSIX_ABSOLUTE_PATH, POSIX_RELATIVE_PATH, UNC_PATH, WINDOWS_PATH,
+    };
+
+    #[test]
+    fn posix() {
+        let query = POSIX_ABSOLUTE_PATH.compile().unwrap();
+        let captures = query.captures("/usr/lib/libc.so.6").unwrap();
+        assert_eq!(&captures["parent"], "/usr/lib/");
+        assert_eq!(&captures["file_name"], "libc.so.6");
+        assert_eq!(&captures["stem"], "libc.so");
+        assert_eq!(&captures["extension"], "6");
+        let captures = query.captures("/usr/bin/").unwrap();
+        assert_eq!(&captures["parent"], "/usr/");
+        assert_eq!(&captures["file_name"], "bin");
+        assert!(captures.name("extension").is_none());
+        let captures = query.captures("/").unwrap();
+        assert!(captures.name("file_name").is_none());
+        for v in ["usr/bin", "", "/usr//bin", "/usr/\0"] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        let query = POSIX_RELATIVE_PATH.compile().unwrap();
+        let captures = query.captures("../src/main.rs").unwrap();
+        assert_eq!(&captures["parent"], "../src/");
+        assert_eq!(&captures["stem"], "main");
+        assert_eq!(&captures["extension"], "rs");
+        for v in ["README", "src/", "./.bashrc"] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in ["/etc/hosts", "", "src//main.rs"] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+    }
+
+    #[test]
+    fn windows() {
+        let query = WINDOWS_PATH.compile().unwrap();
+        let captures = query.captures(r"C:\Windows\System32\notepad.exe").unwrap();
+        assert_eq!(&captures["drive"], "C:");
+        assert_eq!(&captures["parent"], r"\Windows\System32\");
+        assert_eq!(&captures["stem"], "notepad");
+        assert_eq!(&captures["extension"], "exe");
+        for v in [r"C:\", "d:/Users/me/", r"C:\Program Files (x86)\app"] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in [r"C:Windows", r"\Windows", r"C:\a|b", r"CD:\"] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        let query = UNC_PATH.compile().unwrap();
+        let captures = query.captures(r"\\server\share\dir\file.txt").unwrap();
+        assert_eq!(&captures["server"], "server");
+        assert_eq!(&captures["share"], "share");
+        assert_eq!(&captures["parent"], r"\dir\");
+        assert_eq!(&captures["file_name"], "file.txt");
+        assert!(query.is_match(r"\\server\share"));
+        assert!(!query.is_match(r"\\server"));
+        assert!(!query.is_match(r"\server\share"));
+        let query = EXTENDED_LENGTH_PATH.compile().unwrap();
+        let captures = query.captures(r"\\?\C:\very\long\path.txt").unwrap();
+        assert_eq!(&captures["volume"], "C:");
+        assert_eq!(&captures["parent"], r"\very\long\");
+        assert_eq!(&captures["extension"], "txt");
+        let captures = query.captures(r"\\?\UNC\server\share\dir").unwrap();
+        assert_eq!(&captures["volume"], r"UNC\server\share");
+        assert_eq!(&captures["file_name"], "dir");
+        assert!(!query.is_match(r"\\?\C:/very/long"));
+        assert!(!query.is_match(r"C:\very\long"));
+    }
+
+    #[test]
+    fn file_names() {
+        let query = FILE_NAME_POSIX.compile().unwrap();
+        let captures = query.captures("archive.tar.gz").unwrap();
+        assert_eq!(&captures["stem"], "archive.tar");
+        assert_eq!(&captures["extension"], "gz");
+        let captures = query.captures(".bashrc").unwrap();
+        assert_eq!(&captures["stem"], ".bashrc");
+        assert!(captures.name("extension").is_none());
+        assert!(query.is_match("a:b"));
+        assert!(!query.is_match("a/b"));
+        let query = FILE_NAME_WINDOWS.compile().unwrap();
+        assert!(query.is_match("report.docx"));
+        assert!(!query.is_match("a:b"));
+        assert!(!query.is_match(r"a\b"));
+        for (name, posix, windows) in [
+            ("report.docx", true, true),
+            ("a:b", true, false),
+            ("trailing.", true, false),
+            ("trailing ", true, false),
+            ("NUL", true, false),
+            ("nul.txt", true, false),
+            ("com10", true, true),
+            ("..", false, false),
+            ("", false, false),
+            ("a/b", false, false),
+            (&"a".repeat(256), false, false),
+        ] {
+            assert_eq!(is_valid_file_name(name, Platform::Posix), posix, "{}", name);
+            assert_eq!(
+                is_valid_file_name(name, Platform::Windows),
+                windows,
+                "{}",
+                name
+            );
+        }
+    }
+}
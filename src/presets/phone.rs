@@ -0,0 +1,352 @@
+//! Telephone numbers: E.164, North American and national formats
+use crate::solvers::{Chars, Trie};
+use crate::ReadableRe::{self, Raw};
+use crate::{
+    chars, either, exactly, named_group, non_capture_group, optional, ranged, starts_and_ends_with,
+    zero_or_more,
+};
+use once_cell::sync::Lazy;
+
+/// National numbering of a country, see [`NATIONAL_FORMATS`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NationalFormat {
+    pub(crate) country: &'static str,
+    pub(crate) calling_code: &'static str,
+    pub(crate) trunk_prefix: Option<&'static str>,
+    pub(crate) area_code_start: &'static str,
+    pub(crate) area_code: (usize, usize),
+    pub(crate) subscriber: (usize, usize),
+}
+
+impl NationalFormat {
+    const fn new(
+        country: &'static str,
+        calling_code: &'static str,
+        trunk_prefix: Option<&'static str>,
+        area_code_start: &'static str,
+        area_code: (usize, usize),
+        subscriber: (usize, usize),
+    ) -> Self {
+        Self {
+            country,
+            calling_code,
+            trunk_prefix,
+            area_code_start,
+            area_code,
+            subscriber,
+        }
+    }
+
+    /// ISO 3166-1 alpha-2 code of the country
+    pub fn country(&self) -> &'static str {
+        self.country
+    }
+
+    /// International calling code, without the `+`
+    pub fn calling_code(&self) -> &'static str {
+        self.calling_code
+    }
+}
+
+/// Numbering of major countries, out of the North American Numbering Plan (see [`NANP`]):
+/// country, calling code, trunk prefix, first digit and length range of the area codes
+/// (`(0, 0)` for closed numbering plans), and length range of the subscriber numbers
+pub const NATIONAL_FORMATS: [NationalFormat; 18] = [
+    NationalFormat::new("AT", "43", Some("0"), "1-9", (1, 4), (4, 9)),
+    NationalFormat::new("AU", "61", Some("0"), "2-478", (1, 1), (8, 8)),
+    NationalFormat::new("BR", "55", Some("0"), "1-9", (2, 2), (8, 9)),
+    NationalFormat::new("CH", "41", Some("0"), "1-9", (2, 2), (7, 7)),
+    NationalFormat::new("CN", "86", Some("0"), "1-9", (2, 3), (7, 8)),
+    NationalFormat::new("DE", "49", Some("0"), "1-9", (2, 5), (3, 8)),
+    NationalFormat::new("ES", "34", None, "", (0, 0), (9, 9)),
+    NationalFormat::new("FR", "33", Some("0"), "1-9", (1, 1), (8, 8)),
+    NationalFormat::new("GB", "44", Some("0"), "1-9", (2, 4), (6, 8)),
+    NationalFormat::new("IN", "91", Some("0"), "1-9", (2, 4), (6, 8)),
+    NationalFormat::new("IT", "39", None, "03", (1, 4), (6, 8)),
+    NationalFormat::new("JP", "81", Some("0"), "1-9", (1, 4), (5, 8)),
+    NationalFormat::new("KR", "82", Some("0"), "1-9", (1, 2), (7, 8)),
+    NationalFormat::new("MX", "52", None, "1-9", (2, 3), (7, 8)),
+    NationalFormat::new("NL", "31", Some("0"), "1-9", (1, 3), (6, 8)),
+    NationalFormat::new("PL", "48", None, "", (0, 0), (9, 9)),
+    NationalFormat::new("RU", "7", Some("8"), "3-9", (3, 5), (5, 7)),
+    NationalFormat::new("SE", "46", Some("0"), "1-9", (1, 3), (5, 8)),
+];
+
+/// Format of the country, by its ISO 3166-1 alpha-2 code
+pub fn national_format(country: &str) -> Option<NationalFormat> {
+    NATIONAL_FORMATS
+        .into_iter()
+        .find(|format| format.country.eq_ignore_ascii_case(country))
+}
+
+/// Optional space, dot or hyphen between the parts of a number
+fn separator() -> ReadableRe<'static> {
+    optional(chars(" .-"))
+}
+
+/// `count` digits, any of them but the first optionally preceded by a [`separator`]
+fn spaced_digits(count: (usize, usize)) -> ReadableRe<'static> {
+    chars("0-9")
+        + ranged(
+            count.0 - 1..=count.1 - 1,
+            non_capture_group(separator() + chars("0-9")),
+        )
+}
+
+/// `(?:\+|00)` international prefix followed by the calling code in the `country_code` group
+fn international(calling_code: &'static str) -> ReadableRe<'static> {
+    non_capture_group(either([Raw(r"\+"), Raw("00")]))
+        + named_group("country_code", Raw(calling_code))
+        + separator()
+}
+
+/// Optional extension `ext. 123`, `x123` or `#123`, with an `extension` named group
+fn extension() -> ReadableRe<'static> {
+    let marker = Trie::new(["ext.", "ext", "x", "#"]).case_insensitive(true);
+    optional(non_capture_group(
+        zero_or_more(chars(" "))
+            + ReadableRe::Trie(marker)
+            + zero_or_more(chars(" "))
+            + named_group("extension", ranged(1..=6, chars("0-9"))),
+    ))
+}
+
+/// Number of the North American Numbering Plan, `+1 (555) 234-5678 ext. 90`, with the
+/// optional `country_code`, and the `area_code`, `subscriber` and optional `extension` named
+/// groups. The parentheses around the area code are optional and not checked to be balanced,
+/// see [`parse_phone_number`].
+pub fn nanp() -> ReadableRe<'static> {
+    optional(non_capture_group(
+        optional(non_capture_group(either([Raw(r"\+"), Raw("00")])))
+            + named_group("country_code", Raw("1"))
+            + separator(),
+    )) + optional(Raw(r"\("))
+        + named_group("area_code", chars("2-9") + exactly(2, chars("0-9")))
+        + optional(Raw(r"\)"))
+        + separator()
+        + named_group(
+            "subscriber",
+            chars("2-9") + exactly(2, chars("0-9")) + separator() + exactly(4, chars("0-9")),
+        )
+        + extension()
+}
+
+/// Number written as in the country of the `format`, either nationally with its trunk prefix,
+/// `020 7946 0958`, or internationally, `+44 20 7946 0958` or `0044 (0)20 7946 0958`, with the
+/// optional `country_code` and `extension`, and the `area_code` (unless the plan is closed) and
+/// `subscriber` named groups. With no separator, the longest possible area code is captured.
+pub fn national_number(format: NationalFormat) -> ReadableRe<'static> {
+    let has_area_code = format.area_code.1 > 0;
+    let opening = || {
+        if has_area_code {
+            optional(Raw(r"\("))
+        } else {
+            Raw("")
+        }
+    };
+    let mut international = international(format.calling_code);
+    if let Some(trunk) = format.trunk_prefix {
+        let trunk = ReadableRe::String(format!(r"\({trunk}\)"));
+        international = international + optional(non_capture_group(trunk + separator()));
+    }
+    let national = opening() + Raw(format.trunk_prefix.unwrap_or(""));
+    let mut re = non_capture_group(either([international + opening(), national]));
+    if has_area_code {
+        let (min, max) = format.area_code;
+        let area_code = ReadableRe::Chars(Chars::new(format.area_code_start))
+            + ranged(min - 1..=max - 1, chars("0-9"));
+        re = re + named_group("area_code", area_code) + optional(Raw(r"\)")) + separator();
+    }
+    re + named_group("subscriber", spaced_digits(format.subscriber)) + extension()
+}
+
+/// [E.164](https://www.itu.int/rec/T-REC-E.164) number, `+` and up to 15 digits without
+/// separators, `+14155552671`. Calling codes have 1 to 3 digits, so they are not captured: use
+/// [`parse_phone_number`] to split a known country's number.
+pub const E164: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(Raw(r"\+") + chars("1-9") + ranged(1..=14, chars("0-9"))));
+
+/// North American number, `(555) 234-5678`, `+1 555.234.5678 x12`, see [`nanp`]
+pub const NANP: Lazy<ReadableRe> = Lazy::new(|| starts_and_ends_with(nanp()));
+
+/// British number, `020 7946 0958`, `+44 (0)20 7946 0958`, see [`national_number`]
+pub const PHONE_NUMBER_GB: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(national_number(national_format("GB").unwrap())));
+
+/// German number, `030 1234567`, `+49 30 1234567`, see [`national_number`]
+pub const PHONE_NUMBER_DE: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(national_number(national_format("DE").unwrap())));
+
+/// French number, `01 23 45 67 89`, `+33 1 23 45 67 89`, see [`national_number`]
+pub const PHONE_NUMBER_FR: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(national_number(national_format("FR").unwrap())));
+
+/// Japanese number, `03-1234-5678`, `+81 3-1234-5678`, see [`national_number`]
+pub const PHONE_NUMBER_JP: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(national_number(national_format("JP").unwrap())));
+
+/// Indian number, `011 2345 6789`, `+91 11 2345 6789`, see [`national_number`]
+pub const PHONE_NUMBER_IN: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(national_number(national_format("IN").unwrap())));
+
+/// Australian number, `(02) 1234 5678`, `+61 2 1234 5678`, see [`national_number`]
+pub const PHONE_NUMBER_AU: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(national_number(national_format("AU").unwrap())));
+
+/// Parts of a number parsed by [`parse_phone_number`], digits only
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhoneNumber {
+    pub country_code: Option<String>,
+    pub area_code: Option<String>,
+    pub subscriber: String,
+    pub extension: Option<String>,
+}
+
+/// Parts of a number of the country, by its ISO 3166-1 alpha-2 code (`US` and `CA` for the
+/// [`NANP`]), checking the parentheses are balanced. `None` for any other text or country.
+///
+/// ```
+/// use readable_regex::presets::phone::parse_phone_number;
+/// let number = parse_phone_number("+44 (0)20 7946-0958", "GB").unwrap();
+/// assert_eq!(number.country_code.as_deref(), Some("44"));
+/// assert_eq!(number.area_code.as_deref(), Some("20"));
+/// assert_eq!(number.subscriber, "79460958");
+/// assert!(parse_phone_number("(555 234-5678", "US").is_none());
+/// ```
+pub fn parse_phone_number(text: &str, country: &str) -> Option<PhoneNumber> {
+    let re = match country.to_ascii_uppercase().as_str() {
+        "US" | "CA" => nanp(),
+        _ => national_number(national_format(country)?),
+    };
+    if text.matches('(').count() != text.matches(')').count() {
+        return None;
+    }
+    let query = starts_and_ends_with(re).compile().unwrap();
+    let captures = query.captures(text)?;
+    let digits = |name: &str| {
+        captures.name(name).map(|part| {
+            part.as_str()
+                .chars()
+                .filter(char::is_ascii_digit)
+                .collect::<String>()
+        })
+    };
+    Some(PhoneNumber {
+        country_code: digits("country_code"),
+        area_code: digits("area_code"),
+        subscriber: digits("subscriber")?,
+        extension: digits("extension"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::presets::phone::{
+        national_format, national_number, parse_phone_number, PhoneNumber, E164, NANP,
+        NATIONAL_FORMATS, PHONE_NUMBER_AU, PHONE_NUMBER_DE, PHONE_NUMBER_FR, PHONE_NUMBER_GB,
+        PHONE_NUMBER_IN, PHONE_NUMBER_JP,
+    };
+    use crate::starts_and_ends_with;
+
+    #[test]
+    fn e164() {
+        let query = E164.compile().unwrap();
+        for v in ["+14155552671", "+442079460958", "+861"] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in [
+            "14155552671",
+            "+04155552671",
+            "+1 415 555 2671",
+            "+1234567890123456",
+        ] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+    }
+
+    #[test]
+    fn nanp() {
+        let query = NANP.compile().unwrap();
+        for v in [
+            "555-234-5678",
+            "(555) 234-5678",
+            "555.234.5678",
+            "5552345678",
+            "+1 555 234 5678",
+            "1-555-234-5678",
+            "555-234-5678 ext. 90",
+            "555-234-5678x1234",
+        ] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in ["155-234-5678", "555-134-5678", "234-5678", "555-234-567"] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        let captures = query.captures("+1 (555) 234-5678 Ext 90").unwrap();
+        assert_eq!(&captures["country_code"], "1");
+        assert_eq!(&captures["area_code"], "555");
+        assert_eq!(&captures["subscriber"], "234-5678");
+        assert_eq!(&captures["extension"], "90");
+    }
+
+    #[test]
+    fn national() {
+        let query = PHONE_NUMBER_GB.compile().unwrap();
+        for v in [
+            "020 7946 0958",
+            "(020) 7946 0958",
+            "+44 20 7946 0958",
+            "0044 (0)20 7946 0958",
+            "01632 960123",
+        ] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in ["20 7946 0958", "+44 020 7946 0958", "+33 20 7946 0958"] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        assert!(PHONE_NUMBER_DE
+            .compile()
+            .unwrap()
+            .is_match("+49 30 1234567"));
+        assert!(PHONE_NUMBER_FR
+            .compile()
+            .unwrap()
+            .is_match("01 23 45 67 89"));
+        assert!(PHONE_NUMBER_JP.compile().unwrap().is_match("03-1234-5678"));
+        assert!(PHONE_NUMBER_IN
+            .compile()
+            .unwrap()
+            .is_match("+91 11 2345 6789"));
+        assert!(PHONE_NUMBER_AU
+            .compile()
+            .unwrap()
+            .is_match("(02) 1234 5678"));
+        let query = starts_and_ends_with(national_number(national_format("es").unwrap()))
+            .compile()
+            .unwrap();
+        let captures = query.captures("+34 912 345 678").unwrap();
+        assert!(captures.name("area_code").is_none());
+        assert_eq!(&captures["subscriber"], "912 345 678");
+        for format in NATIONAL_FORMATS {
+            assert!(national_number(format).compile().is_ok());
+        }
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            parse_phone_number("(555) 234-5678 #12", "us"),
+            Some(PhoneNumber {
+                country_code: None,
+                area_code: Some("555".to_string()),
+                subscriber: "2345678".to_string(),
+                extension: Some("12".to_string()),
+            })
+        );
+        let number = parse_phone_number("+81 3-1234-5678", "JP").unwrap();
+        assert_eq!(number.country_code.as_deref(), Some("81"));
+        assert_eq!(number.area_code.as_deref(), Some("3"));
+        assert!(parse_phone_number("020 7946 0958", "ZZ").is_none());
+        assert!(parse_phone_number("(020 7946 0958", "GB").is_none());
+    }
+}
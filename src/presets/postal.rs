@@ -0,0 +1,429 @@
+//! Postal codes and national identity numbers, by [`Country`]
+use crate::presets::checksum::{self, Validator};
+use crate::ReadableRe::{self, Raw};
+use crate::{
+    chars, either, exactly, named_group, non_capture_group, numeric_range, optional, ranged,
+    starts_and_ends_with, LeadingZeros, RangeOptions,
+};
+use once_cell::sync::Lazy;
+
+/// Countries with known postal code or identity number formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Country {
+    Australia,
+    Brazil,
+    Canada,
+    France,
+    Germany,
+    India,
+    Italy,
+    Japan,
+    Netherlands,
+    Poland,
+    Spain,
+    Sweden,
+    Switzerland,
+    UnitedKingdom,
+    UnitedStates,
+}
+
+impl Country {
+    pub const ALL: [Country; 15] = [
+        Country::Australia,
+        Country::Brazil,
+        Country::Canada,
+        Country::France,
+        Country::Germany,
+        Country::India,
+        Country::Italy,
+        Country::Japan,
+        Country::Netherlands,
+        Country::Poland,
+        Country::Spain,
+        Country::Sweden,
+        Country::Switzerland,
+        Country::UnitedKingdom,
+        Country::UnitedStates,
+    ];
+
+    /// ISO 3166-1 alpha-2 code
+    pub fn code(self) -> &'static str {
+        match self {
+            Country::Australia => "AU",
+            Country::Brazil => "BR",
+            Country::Canada => "CA",
+            Country::France => "FR",
+            Country::Germany => "DE",
+            Country::India => "IN",
+            Country::Italy => "IT",
+            Country::Japan => "JP",
+            Country::Netherlands => "NL",
+            Country::Poland => "PL",
+            Country::Spain => "ES",
+            Country::Sweden => "SE",
+            Country::Switzerland => "CH",
+            Country::UnitedKingdom => "GB",
+            Country::UnitedStates => "US",
+        }
+    }
+
+    /// Country of the ISO 3166-1 alpha-2 code, in any case
+    pub fn from_code(code: &str) -> Option<Country> {
+        Country::ALL
+            .into_iter()
+            .find(|country| country.code().eq_ignore_ascii_case(code))
+    }
+}
+
+fn digits(n: usize) -> ReadableRe<'static> {
+    exactly(n, chars("0-9"))
+}
+
+/// Area and district of a UK postcode, `SW1A`
+fn uk_outward() -> ReadableRe<'static> {
+    ranged(1..=2, chars("A-Z")) + chars("0-9") + optional(chars("A-Z0-9"))
+}
+
+/// Sector and unit of a UK postcode, `1AA`
+fn uk_inward() -> ReadableRe<'static> {
+    chars("0-9") + exactly(2, chars("ABD-HJLNP-UW-Z"))
+}
+
+fn padded() -> RangeOptions {
+    RangeOptions::new().leading_zeros(LeadingZeros::Required)
+}
+
+/// Postal code of the country, in uppercase, without anchors nor capture groups
+///
+/// | Country | Format |
+/// |---|---|
+/// | Australia | `2000` |
+/// | Brazil | `01310-100` |
+/// | Canada | `K1A 0B1` |
+/// | France | `75008`, departments `01` to `98` |
+/// | Germany | `10115` |
+/// | India | `110001` or `110 001` |
+/// | Italy | `00144` |
+/// | Japan | `100-0001` or `〒100-0001` |
+/// | Netherlands | `1012 AB` |
+/// | Poland | `00-950` |
+/// | Spain | `28013`, provinces `01` to `52` |
+/// | Sweden | `114 55` |
+/// | Switzerland | `8001` |
+/// | United Kingdom | `SW1A 1AA` |
+/// | United States | ZIP `20500` or ZIP+4 `20500-0003` |
+pub fn postal_code(country: Country) -> ReadableRe<'static> {
+    let space = || optional(Raw(" "));
+    let re = match country {
+        Country::Australia => digits(4),
+        Country::Brazil => digits(5) + optional(Raw("-")) + digits(3),
+        Country::Canada => {
+            chars("ABCEGHJ-NPRSTVXY")
+                + chars("0-9")
+                + chars("ABCEGHJ-NPRSTV-Z")
+                + space()
+                + chars("0-9")
+                + chars("ABCEGHJ-NPRSTV-Z")
+                + chars("0-9")
+        }
+        Country::France => numeric_range(1, 98, padded()) + digits(3),
+        Country::Germany => numeric_range(1, 99, padded()) + digits(3),
+        Country::India => chars("1-9") + digits(2) + space() + digits(3),
+        Country::Italy => digits(5),
+        Country::Japan => optional(Raw("〒")) + digits(3) + Raw("-") + digits(4),
+        Country::Netherlands => chars("1-9") + digits(3) + space() + exactly(2, chars("A-Z")),
+        Country::Poland => digits(2) + Raw("-") + digits(3),
+        Country::Spain => numeric_range(1, 52, padded()) + digits(3),
+        Country::Sweden => chars("1-9") + digits(2) + space() + digits(2),
+        Country::Switzerland => chars("1-9") + digits(3),
+        Country::UnitedKingdom => uk_outward() + space() + uk_inward(),
+        Country::UnitedStates => digits(5) + optional(non_capture_group(Raw("-") + digits(4))),
+    };
+    non_capture_group(re)
+}
+
+/// Postal code of any of the countries, see [`postal_code`]
+pub fn postal_codes(countries: impl IntoIterator<Item = Country>) -> ReadableRe<'static> {
+    non_capture_group(either(countries.into_iter().map(postal_code)))
+}
+
+/// US ZIP or ZIP+4 code `20500-0003`, with `zip` and the optional `plus4` named groups
+pub const POSTAL_CODE_US: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(
+        named_group("zip", digits(5))
+            + optional(non_capture_group(
+                Raw("-") + named_group("plus4", digits(4)),
+            )),
+    )
+});
+
+/// UK postcode `SW1A 1AA`, with `outward` and `inward` named groups
+pub const POSTAL_CODE_GB: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(
+        named_group("outward", uk_outward())
+            + optional(Raw(" "))
+            + named_group("inward", uk_inward()),
+    )
+});
+
+/// Canadian postal code `K1A 0B1`, see [`postal_code`]
+pub const POSTAL_CODE_CA: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(postal_code(Country::Canada)));
+
+/// German postal code `10115`, see [`postal_code`]
+pub const POSTAL_CODE_DE: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(postal_code(Country::Germany)));
+
+/// Spanish postal code `28013`, see [`postal_code`]
+pub const POSTAL_CODE_ES: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(postal_code(Country::Spain)));
+
+/// French postal code `75008`, see [`postal_code`]
+pub const POSTAL_CODE_FR: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(postal_code(Country::France)));
+
+/// Dutch postal code `1012 AB`, see [`postal_code`]
+pub const POSTAL_CODE_NL: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(postal_code(Country::Netherlands)));
+
+/// Japanese postal code `100-0001`, see [`postal_code`]
+pub const POSTAL_CODE_JP: Lazy<ReadableRe> =
+    Lazy::new(|| starts_and_ends_with(postal_code(Country::Japan)));
+
+/// Optional hyphen or space between the parts of an identity number
+fn separator() -> ReadableRe<'static> {
+    optional(chars("- "))
+}
+
+/// US Social Security Number `123-45-6789`, with `area`, `group` and `serial` named groups.
+/// The never issued numbers are excluded: area `000`, `666` and `900` to `999`, group `00` and
+/// serial `0000`.
+pub const SSN: Lazy<ReadableRe> = Lazy::new(|| {
+    let width = |n: usize| padded().width(n);
+    starts_and_ends_with(
+        named_group(
+            "area",
+            non_capture_group(either([
+                numeric_range(1, 665, width(3)),
+                numeric_range(667, 899, width(3)),
+            ])),
+        ) + separator()
+            + named_group("group", numeric_range(1, 99, width(2)))
+            + separator()
+            + named_group("serial", numeric_range(1, 9999, width(4))),
+    )
+});
+
+/// UK National Insurance number `AB 12 34 56 C`, with `prefix`, `number` and `suffix` named
+/// groups. The excluded prefixes (`BG`, `GB`, `NK`, `KN`, `TN`, `NT` and `ZZ`) are not checked.
+pub const NATIONAL_INSURANCE_NUMBER: Lazy<ReadableRe> = Lazy::new(|| {
+    let pair = || optional(Raw(" ")) + digits(2);
+    starts_and_ends_with(
+        named_group(
+            "prefix",
+            chars("A-CEGHJ-PR-TW-Z") + chars("A-CEGHJ-NPR-TW-Z"),
+        ) + named_group("number", pair() + pair() + pair())
+            + optional(Raw(" "))
+            + named_group("suffix", chars("A-D")),
+    )
+});
+
+/// [`SSN`], which has no check digit
+pub static SSN_VALIDATOR: Validator = Validator::new(|| SSN.compile().unwrap(), |_| true);
+
+/// [`NATIONAL_INSURANCE_NUMBER`], which has no check digit
+pub static NATIONAL_INSURANCE_NUMBER_VALIDATOR: Validator =
+    Validator::new(|| NATIONAL_INSURANCE_NUMBER.compile().unwrap(), |_| true);
+
+/// Canadian Social Insurance Number `130 692 544`, see [`SIN_VALIDATOR`]
+pub const SIN: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(
+        chars("1-79") + digits(2) + separator() + digits(3) + separator() + digits(3),
+    )
+});
+
+/// [`SIN`] with a valid Luhn check digit
+pub static SIN_VALIDATOR: Validator = Validator::new(|| SIN.compile().unwrap(), checksum::luhn);
+
+/// Spanish DNI `12345678-Z`, with `number` and `letter` named groups, see [`DNI_VALIDATOR`]
+pub const DNI: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(
+        named_group("number", digits(8))
+            + optional(Raw("-"))
+            + named_group("letter", chars("A-HJ-NP-TV-Z")),
+    )
+});
+
+/// [`DNI`] with a valid control letter
+pub static DNI_VALIDATOR: Validator = Validator::new(|| DNI.compile().unwrap(), checksum::dni);
+
+/// Spanish NIE (foreigner identity number) `X-1234567-L`, with `prefix`, `number` and `letter`
+/// named groups, see [`NIE_VALIDATOR`]
+pub const NIE: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(
+        named_group("prefix", chars("XYZ"))
+            + optional(Raw("-"))
+            + named_group("number", digits(7))
+            + optional(Raw("-"))
+            + named_group("letter", chars("A-HJ-NP-TV-Z")),
+    )
+});
+
+/// [`NIE`] with a valid control letter
+pub static NIE_VALIDATOR: Validator = Validator::new(|| NIE.compile().unwrap(), checksum::nie);
+
+/// Dutch citizen service number (BSN) `111222333`, see [`BSN_VALIDATOR`]
+pub const BSN: Lazy<ReadableRe> = Lazy::new(|| starts_and_ends_with(digits(9)));
+
+/// [`BSN`] passing the eleven test
+pub static BSN_VALIDATOR: Validator =
+    Validator::new(|| BSN.compile().unwrap(), checksum::eleven_test);
+
+/// Whether the text is an identity number of the country, with a valid check digit or letter
+/// when it has one: [`SSN`], [`NATIONAL_INSURANCE_NUMBER`], [`SIN`], [`DNI`] or [`NIE`], and
+/// [`BSN`]. Always `false` for the other countries.
+///
+/// ```
+/// use readable_regex::presets::postal::{is_valid_national_id, Country};
+/// assert!(is_valid_national_id("123-45-6789", Country::UnitedStates));
+/// assert!(!is_valid_national_id("666-45-6789", Country::UnitedStates));
+/// assert!(is_valid_national_id("X1234567L", Country::Spain));
+/// assert!(!is_valid_national_id("12345678A", Country::Spain));
+/// ```
+pub fn is_valid_national_id(text: &str, country: Country) -> bool {
+    match country {
+        Country::UnitedStates => SSN_VALIDATOR.is_valid(text),
+        Country::UnitedKingdom => NATIONAL_INSURANCE_NUMBER_VALIDATOR.is_valid(text),
+        Country::Canada => SIN_VALIDATOR.is_valid(text),
+        Country::Spain => DNI_VALIDATOR.is_valid(text) || NIE_VALIDATOR.is_valid(text),
+        Country::Netherlands => BSN_VALIDATOR.is_valid(text),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::presets::postal::{
+        is_valid_national_id, postal_code, postal_codes, Country, BSN_VALIDATOR, DNI,
+        DNI_VALIDATOR, NATIONAL_INSURANCE_NUMBER, NIE, NIE_VALIDATOR, POSTAL_CODE_CA,
+        POSTAL_CODE_DE, POSTAL_CODE_ES, POSTAL_CODE_FR, POSTAL_CODE_GB, POSTAL_CODE_JP,
+        POSTAL_CODE_NL, POSTAL_CODE_US, SIN_VALIDATOR, SSN,
+    };
+    use crate::starts_and_ends_with;
+
+    #[test]
+    fn countries() {
+        for country in Country::ALL {
+            assert_eq!(Country::from_code(country.code()), Some(country));
+        }
+        assert_eq!(Country::from_code("gb"), Some(Country::UnitedKingdom));
+        assert_eq!(Country::from_code("UK"), None);
+    }
+
+    #[test]
+    fn postal_code_formats() {
+        for (country, valid, invalid) in [
+            (Country::Australia, "2000", "200"),
+            (Country::Brazil, "01310-100", "01310-10"),
+            (Country::Canada, "K1A 0B1", "D1A 0B1"),
+            (Country::France, "75008", "99000"),
+            (Country::Germany, "10115", "00115"),
+            (Country::India, "110 001", "010001"),
+            (Country::Italy, "00144", "0014"),
+            (Country::Japan, "〒100-0001", "1000001"),
+            (Country::Netherlands, "1012 AB", "0123 AB"),
+            (Country::Poland, "00-950", "00950"),
+            (Country::Spain, "28013", "53001"),
+            (Country::Sweden, "114 55", "014 55"),
+            (Country::Switzerland, "8001", "0800"),
+            (Country::UnitedKingdom, "SW1A 1AA", "SW1A 1AC"),
+            (Country::UnitedStates, "20500-0003", "20500-03"),
+        ] {
+            let query = starts_and_ends_with(postal_code(country))
+                .compile()
+                .unwrap();
+            assert!(query.is_match(valid), "Failed matching: {}", valid);
+            assert!(!query.is_match(invalid), "Wrongly matched: {}", invalid);
+        }
+        let query = starts_and_ends_with(postal_codes([Country::Canada, Country::UnitedStates]))
+            .compile()
+            .unwrap();
+        assert!(query.is_match("K1A0B1"));
+        assert!(query.is_match("20500"));
+        assert!(!query.is_match("SW1A 1AA"));
+    }
+
+    #[test]
+    fn postal_code_presets() {
+        let captures = POSTAL_CODE_US
+            .compile()
+            .unwrap()
+            .captures("20500-0003")
+            .unwrap();
+        assert_eq!(&captures["zip"], "20500");
+        assert_eq!(&captures["plus4"], "0003");
+        assert!(POSTAL_CODE_US.compile().unwrap().is_match("20500"));
+        let query = POSTAL_CODE_GB.compile().unwrap();
+        let captures = query.captures("SW1A 1AA").unwrap();
+        assert_eq!(&captures["outward"], "SW1A");
+        assert_eq!(&captures["inward"], "1AA");
+        for v in ["M1 1AE", "B33 8TH", "CR2 6XH", "DN55 1PT", "EC1A1BB"] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        assert!(!query.is_match("sw1a 1aa"));
+        assert!(POSTAL_CODE_CA.compile().unwrap().is_match("K1A 0B1"));
+        assert!(POSTAL_CODE_DE.compile().unwrap().is_match("01067"));
+        assert!(POSTAL_CODE_ES.compile().unwrap().is_match("52001"));
+        assert!(POSTAL_CODE_FR.compile().unwrap().is_match("97400"));
+        assert!(!POSTAL_CODE_FR.compile().unwrap().is_match("99400"));
+        assert!(POSTAL_CODE_NL.compile().unwrap().is_match("1012AB"));
+        assert!(POSTAL_CODE_JP.compile().unwrap().is_match("100-0001"));
+    }
+
+    #[test]
+    fn national_ids() {
+        let query = SSN.compile().unwrap();
+        let captures = query.captures("123-45-6789").unwrap();
+        assert_eq!(&captures["area"], "123");
+        assert_eq!(&captures["group"], "45");
+        assert_eq!(&captures["serial"], "6789");
+        assert!(query.is_match("123456789"));
+        for v in [
+            "000-45-6789",
+            "666-45-6789",
+            "900-45-6789",
+            "123-00-6789",
+            "123-45-0000",
+        ] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        let query = NATIONAL_INSURANCE_NUMBER.compile().unwrap();
+        assert!(query.is_match("AB 12 34 56 C"));
+        assert!(query.is_match("AB123456C"));
+        assert!(!query.is_match("DA123456C"));
+        assert!(!query.is_match("AB123456E"));
+        assert!(SIN_VALIDATOR.is_valid("130 692 544"));
+        assert!(!SIN_VALIDATOR.is_valid("130 692 545"));
+        let captures = DNI.compile().unwrap().captures("12345678-Z").unwrap();
+        assert_eq!(&captures["number"], "12345678");
+        assert_eq!(&captures["letter"], "Z");
+        assert!(DNI_VALIDATOR.is_valid("12345678Z"));
+        assert!(!DNI_VALIDATOR.is_valid("12345678I"));
+        let captures = NIE.compile().unwrap().captures("Y-1234567-X").unwrap();
+        assert_eq!(&captures["prefix"], "Y");
+        assert!(NIE_VALIDATOR.is_valid("Y-1234567-X"));
+        assert!(!NIE_VALIDATOR.is_valid("Y-1234567-L"));
+        assert!(BSN_VALIDATOR.is_valid("111222333"));
+        assert!(!BSN_VALIDATOR.is_valid("111222334"));
+        assert!(is_valid_national_id("111222333", Country::Netherlands));
+        assert!(is_valid_national_id(
+            "AB 12 34 56 C",
+            Country::UnitedKingdom
+        ));
+        assert!(!is_valid_national_id(
+            "DA 12 34 56 C",
+            Country::UnitedKingdom
+        ));
+        assert!(!is_valid_national_id("111222333", Country::Japan));
+    }
+}
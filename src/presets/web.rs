@@ -0,0 +1,620 @@
+//! Web and markup: CSS colors and lengths, HTML/XML tags and entities, MIME types and HTTP headers
+use crate::solvers::Trie;
+use crate::ReadableRe::{
+    self, AnyChar, BackSlash, CloseParenthesis, DoubleQuote, Hexadecimal, OpenParenthesis, Period,
+    PlusSign, Quote, Raw, Whitespace,
+};
+use crate::{
+    boundary, chars, either, escape_str, exactly, named_group, non_capture_group, not_chars,
+    numeric_range, one_or_more, optional, ranged, starts_and_ends_with, zero_or_more,
+    zero_or_more_lazy, RangeOptions,
+};
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// CSS hex color `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`, with the digits in the `hex` named
+/// group
+pub fn hex_color() -> ReadableRe<'static> {
+    Raw("#")
+        + named_group(
+            "hex",
+            either([8, 6, 4, 3].map(|digits| exactly(digits, Hexadecimal))),
+        )
+        + boundary()
+}
+
+/// Fraction of a number, `.5`
+fn fraction() -> ReadableRe<'static> {
+    Period + one_or_more(chars("0-9"))
+}
+
+/// Number with an optional fraction, `12`, `12.5` or `.5`
+fn number() -> ReadableRe<'static> {
+    non_capture_group(either([
+        one_or_more(chars("0-9")) + optional(non_capture_group(fraction())),
+        fraction(),
+    ]))
+}
+
+/// The `integer` with an optional fraction of zeros, `1` or `1.00`
+fn whole(integer: &str) -> ReadableRe<'_> {
+    Raw(integer) + optional(non_capture_group(Period + one_or_more(Raw("0"))))
+}
+
+/// The `separator` with optional whitespace around it
+fn spaced(separator: ReadableRe) -> ReadableRe {
+    zero_or_more(Whitespace) + separator + zero_or_more(Whitespace)
+}
+
+/// Name of a CSS color function, with the optional `a` suffix, and its opening parenthesis
+fn color_function(name: &str) -> ReadableRe<'_> {
+    Raw(name) + optional(Raw("a")) + OpenParenthesis + zero_or_more(Whitespace)
+}
+
+/// Closing parenthesis of a CSS color function
+fn color_function_end() -> ReadableRe<'static> {
+    zero_or_more(Whitespace) + CloseParenthesis
+}
+
+/// Percentage from `0%` to `100%`, with an optional fraction below `100`
+fn percentage() -> ReadableRe<'static> {
+    non_capture_group(either([
+        whole("100"),
+        numeric_range(0, 99, RangeOptions::new()) + optional(non_capture_group(fraction())),
+    ])) + Raw("%")
+}
+
+/// Alpha channel from `0` to `1`, or as a percentage
+fn alpha() -> ReadableRe<'static> {
+    non_capture_group(either([
+        percentage(),
+        whole("1"),
+        optional(Raw("0")) + fraction(),
+        Raw("0"),
+    ]))
+}
+
+/// Separator of the color function arguments, `,` in the legacy syntax or spaces
+fn argument_separator() -> ReadableRe<'static> {
+    non_capture_group(either([spaced(Raw(",")), one_or_more(Whitespace)]))
+}
+
+/// Optional alpha argument, after `,` in the legacy syntax or `/`
+fn alpha_argument() -> ReadableRe<'static> {
+    optional(non_capture_group(
+        non_capture_group(either([spaced(Raw(",")), spaced(Raw("/"))]))
+            + named_group("alpha", alpha()),
+    ))
+}
+
+/// CSS `rgb()` or `rgba()` function, with `red`, `green`, `blue` and the optional `alpha` named
+/// groups. Channels are integers from `0` to `255` or percentages.
+///
+/// Both the legacy `rgb(255, 0, 0)` and the modern `rgb(255 0 0 / 50%)` syntaxes are matched, but
+/// mixing their separators is not rejected.
+pub fn rgb_color() -> ReadableRe<'static> {
+    let channel = || {
+        non_capture_group(either([
+            percentage(),
+            numeric_range(0, 255, RangeOptions::new()),
+        ]))
+    };
+    color_function("rgb")
+        + named_group("red", channel())
+        + argument_separator()
+        + named_group("green", channel())
+        + argument_separator()
+        + named_group("blue", channel())
+        + alpha_argument()
+        + color_function_end()
+}
+
+/// CSS `hsl()` or `hsla()` function, with `hue` (`0` to `360`, optionally in `deg`),
+/// `saturation` and `lightness` percentages and the optional `alpha` named groups
+pub fn hsl_color() -> ReadableRe<'static> {
+    color_function("hsl")
+        + named_group(
+            "hue",
+            numeric_range(0, 360, RangeOptions::new()) + optional(non_capture_group(Raw("deg"))),
+        )
+        + argument_separator()
+        + named_group("saturation", percentage())
+        + argument_separator()
+        + named_group("lightness", percentage())
+        + alpha_argument()
+        + color_function_end()
+}
+
+pub const HEX_COLOR: Lazy<ReadableRe> = Lazy::new(|| starts_and_ends_with(hex_color()));
+
+pub const RGB_COLOR: Lazy<ReadableRe> = Lazy::new(|| starts_and_ends_with(rgb_color()));
+
+pub const HSL_COLOR: Lazy<ReadableRe> = Lazy::new(|| starts_and_ends_with(hsl_color()));
+
+/// CSS length units, from the absolute, font relative, viewport and container ones, and `%`
+pub const CSS_LENGTH_UNITS: [&str; 38] = [
+    "cm", "mm", "Q", "in", "pc", "pt", "px", "em", "rem", "ex", "rex", "cap", "rcap", "ch", "rch",
+    "ic", "ric", "lh", "rlh", "vw", "vh", "vi", "vb", "vmin", "vmax", "svw", "svh", "lvw", "lvh",
+    "dvw", "dvh", "cqw", "cqh", "cqi", "cqb", "cqmin", "cqmax", "%",
+];
+
+/// CSS length `12px`, `-1.5em`, `.5rem` or `100%`, with `value` and `unit` named groups.
+/// The unitless `0` is matched too, without any of the groups.
+pub const CSS_LENGTH: Lazy<ReadableRe> = Lazy::new(|| {
+    let units = Trie::new(CSS_LENGTH_UNITS).case_insensitive(true);
+    starts_and_ends_with(non_capture_group(either([
+        named_group("value", optional(chars("+-")) + number())
+            + named_group("unit", ReadableRe::Trie(units)),
+        Raw("0"),
+    ])))
+});
+
+/// HTML tag name, `div`, `h1` or the custom element `my-widget`
+pub fn html_tag_name() -> ReadableRe<'static> {
+    chars("A-Za-z") + zero_or_more(chars("A-Za-z0-9-"))
+}
+
+/// XML name, restricted to ASCII, `svg:rect` or `xml-stylesheet`
+pub fn xml_name() -> ReadableRe<'static> {
+    chars("A-Za-z_:") + zero_or_more(chars("A-Za-z0-9_:.-"))
+}
+
+pub const HTML_TAG_NAME: Lazy<ReadableRe> = Lazy::new(|| starts_and_ends_with(html_tag_name()));
+
+pub const XML_NAME: Lazy<ReadableRe> = Lazy::new(|| starts_and_ends_with(xml_name()));
+
+/// Tag attribute, `checked`, `id=main`, `class="a b"` or `title='x'`
+fn attribute() -> ReadableRe<'static> {
+    one_or_more(not_chars(r#"\s"'>/="#))
+        + optional(non_capture_group(
+            spaced(Raw("="))
+                + non_capture_group(either([
+                    DoubleQuote + zero_or_more(not_chars("\"")) + DoubleQuote,
+                    Quote + zero_or_more(not_chars("'")) + Quote,
+                    one_or_more(not_chars(r#"\s"'=<>`"#)),
+                ])),
+        ))
+}
+
+/// End `>` of a tag, after optional whitespace
+fn closing_bracket() -> ReadableRe<'static> {
+    zero_or_more(Whitespace) + Raw(">")
+}
+
+/// Opening tag with the `name`, `attributes` and `self_closing` named groups
+fn opening_tag() -> ReadableRe<'static> {
+    Raw("<")
+        + named_group("name", xml_name())
+        + named_group(
+            "attributes",
+            zero_or_more(non_capture_group(one_or_more(Whitespace) + attribute())),
+        )
+        + zero_or_more(Whitespace)
+        + optional(named_group("self_closing", Raw("/")))
+        + Raw(">")
+}
+
+/// Opening or self closing tag, `<a href="/">` or `<br/>`, with `name`, `attributes` and the
+/// optional `self_closing` named groups
+pub const OPENING_TAG: Lazy<ReadableRe> = Lazy::new(|| starts_and_ends_with(opening_tag()));
+
+/// Closing tag `</div>`, with a `name` named group
+pub const CLOSING_TAG: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(Raw("</") + named_group("name", xml_name()) + closing_bracket())
+});
+
+/// Element `<name ...>content</name>` of the given tag name, with `attributes` and `content`
+/// named groups. The content is matched lazily, so nested elements of the same name end at the
+/// first closing tag.
+pub fn element(name: &str) -> ReadableRe<'_> {
+    Raw("<")
+        + escape_str(name)
+        + named_group(
+            "attributes",
+            zero_or_more(non_capture_group(one_or_more(Whitespace) + attribute())),
+        )
+        + closing_bracket()
+        + named_group("content", zero_or_more_lazy(chars(r"\s\S")))
+        + Raw("</")
+        + escape_str(name)
+        + closing_bracket()
+}
+
+/// Element of any name, with `name`, `attributes` and `content` named groups. The closing tag
+/// must repeat the name, through the `\k<name>` back reference of `fancy_regex`.
+#[cfg(feature = "re-fancy")]
+pub const ELEMENT: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(
+        Raw("<")
+            + named_group("name", xml_name())
+            + named_group(
+                "attributes",
+                zero_or_more(non_capture_group(one_or_more(Whitespace) + attribute())),
+            )
+            + closing_bracket()
+            + named_group("content", zero_or_more_lazy(chars(r"\s\S")))
+            + Raw("</")
+            + Raw(r"\k<name>")
+            + closing_bracket(),
+    )
+});
+
+/// HTML character reference, `&amp;`, `&#169;` or `&#xA9;`, with the `name`, `decimal` or `hex`
+/// named group
+pub const HTML_ENTITY: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(
+        Raw("&")
+            + non_capture_group(either([
+                named_group("name", chars("A-Za-z") + zero_or_more(chars("A-Za-z0-9"))),
+                Raw("#") + named_group("decimal", ranged(1..=7, chars("0-9"))),
+                Raw("#") + chars("xX") + named_group("hex", ranged(1..=6, Hexadecimal)),
+            ]))
+            + Raw(";"),
+    )
+});
+
+/// Named character references decoded by [`decode_entity`]: the XML ones and `&nbsp;`
+const NAMED_ENTITIES: [(&str, char); 6] = [
+    ("amp", '&'),
+    ("lt", '<'),
+    ("gt", '>'),
+    ("quot", '"'),
+    ("apos", '\''),
+    ("nbsp", '\u{a0}'),
+];
+
+/// MIME type token, without `+` so that the structured syntax suffix can be told apart
+fn mime_name() -> ReadableRe<'static> {
+    chars("A-Za-z0-9") + zero_or_more(chars("A-Za-z0-9!#$&^_.-"))
+}
+
+/// HTTP token, as used for header names and parameters
+fn token() -> ReadableRe<'static> {
+    one_or_more(chars("!#$%&'*+.^_`|~0-9A-Za-z-"))
+}
+
+/// MIME type `text/html; charset=utf-8` or `application/vnd.api+json`, with `type`, `subtype`,
+/// the optional `suffix` (inside `subtype`) and `parameters` named groups
+pub const MIME_TYPE: Lazy<ReadableRe> = Lazy::new(|| {
+    let quoted = DoubleQuote
+        + zero_or_more(non_capture_group(either([
+            not_chars(r#""\\"#),
+            BackSlash + AnyChar,
+        ])))
+        + DoubleQuote;
+    let parameter =
+        spaced(Raw(";")) + token() + Raw("=") + non_capture_group(either([token(), quoted]));
+    starts_and_ends_with(
+        named_group("type", mime_name())
+            + Raw("/")
+            + named_group(
+                "subtype",
+                mime_name()
+                    + zero_or_more_lazy(non_capture_group(PlusSign + mime_name()))
+                    + optional(non_capture_group(
+                        PlusSign + named_group("suffix", mime_name()),
+                    )),
+            )
+            + named_group("parameters", zero_or_more(non_capture_group(parameter))),
+    )
+});
+
+/// HTTP/1.1 header line `Content-Type: text/html`, with `name` and `value` named groups, the
+/// optional whitespace around the value left out
+pub const HTTP_HEADER: Lazy<ReadableRe> = Lazy::new(|| {
+    starts_and_ends_with(
+        named_group("name", token())
+            + Raw(":")
+            + zero_or_more(chars(r" \t"))
+            + named_group("value", zero_or_more_lazy(not_chars(r"\r\n")))
+            + zero_or_more(chars(r" \t")),
+    )
+});
+
+struct Formats {
+    hex: Regex,
+    rgb: Regex,
+    entity: Regex,
+    header: Regex,
+}
+
+static FORMATS: Lazy<Formats> = Lazy::new(|| Formats {
+    hex: HEX_COLOR.compile().unwrap(),
+    rgb: RGB_COLOR.compile().unwrap(),
+    entity: HTML_ENTITY.compile().unwrap(),
+    header: HTTP_HEADER.compile().unwrap(),
+});
+
+/// Color with 8 bits channels and an alpha from `0.0` to `1.0`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rgba {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+    pub alpha: f64,
+}
+
+/// Parse a [`HEX_COLOR`] or [`RGB_COLOR`]
+///
+/// ## Example
+///
+/// ```
+/// use readable_regex::presets::web::{parse_color, Rgba};
+/// let color = Rgba { red: 255, green: 136, blue: 0, alpha: 0.5 };
+/// assert_eq!(parse_color("rgb(255 136 0 / 50%)"), Some(color));
+/// assert_eq!(parse_color("rgb(256, 0, 0)"), None);
+/// ```
+pub fn parse_color(text: &str) -> Option<Rgba> {
+    if let Some(captures) = FORMATS.hex.captures(text) {
+        let hex = &captures["hex"];
+        let digits = if hex.len() <= 4 {
+            hex.chars()
+                .map(|c| Some(c.to_digit(16)? as u8 * 17))
+                .collect::<Option<Vec<u8>>>()?
+        } else {
+            (0..hex.len())
+                .step_by(2)
+                .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+                .collect::<Option<Vec<u8>>>()?
+        };
+        return Some(Rgba {
+            red: digits[0],
+            green: digits[1],
+            blue: digits[2],
+            alpha: digits.get(3).map_or(1.0, |&alpha| alpha as f64 / 255.0),
+        });
+    }
+    let captures = FORMATS.rgb.captures(text)?;
+    let fraction = |value: &str| match value.strip_suffix('%') {
+        Some(percentage) => Some(percentage.parse::<f64>().ok()? / 100.0),
+        None => value.parse::<f64>().ok(),
+    };
+    let channel = |name: &str| {
+        let value = &captures[name];
+        if value.ends_with('%') {
+            Some((fraction(value)? * 255.0).round() as u8)
+        } else {
+            value.parse().ok()
+        }
+    };
+    Some(Rgba {
+        red: channel("red")?,
+        green: channel("green")?,
+        blue: channel("blue")?,
+        alpha: match captures.name("alpha") {
+            Some(alpha) => fraction(alpha.as_str())?,
+            None => 1.0,
+        },
+    })
+}
+
+/// Decode an [`HTML_ENTITY`]: numeric references to any scalar value, named ones among the XML
+/// entities and `&nbsp;`
+///
+/// ## Example
+///
+/// ```
+/// use readable_regex::presets::web::decode_entity;
+/// assert_eq!(decode_entity("&#xA9;"), Some('©'));
+/// assert_eq!(decode_entity("&lt;"), Some('<'));
+/// assert_eq!(decode_entity("&#xD800;"), None);
+/// ```
+pub fn decode_entity(text: &str) -> Option<char> {
+    let captures = FORMATS.entity.captures(text)?;
+    if let Some(name) = captures.name("name") {
+        return NAMED_ENTITIES
+            .iter()
+            .find(|(entity, _)| *entity == name.as_str())
+            .map(|&(_, c)| c);
+    }
+    let code = match captures.name("decimal") {
+        Some(decimal) => decimal.as_str().parse().ok()?,
+        None => u32::from_str_radix(&captures["hex"], 16).ok()?,
+    };
+    char::from_u32(code)
+}
+
+/// Split an [`HTTP_HEADER`] line into its name and value
+///
+/// ## Example
+///
+/// ```
+/// use readable_regex::presets::web::parse_header;
+/// assert_eq!(parse_header("Content-Type:  text/html \t"), Some(("Content-Type", "text/html")));
+/// assert_eq!(parse_header("Bad Name: x"), None);
+/// ```
+pub fn parse_header(line: &str) -> Option<(&str, &str)> {
+    let captures = FORMATS.header.captures(line)?;
+    Some((
+        captures.name("name")?.as_str(),
+        captures.name("value")?.as_str(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::presets::web::{
+        parse_color, Rgba, CLOSING_TAG, CSS_LENGTH, HEX_COLOR, HSL_COLOR, HTML_ENTITY,
+        HTML_TAG_NAME, HTTP_HEADER, MIME_TYPE, OPENING_TAG, RGB_COLOR, XML_NAME,
+    };
+
+    #[test]
+    fn colors() {
+        let query = HEX_COLOR.compile().unwrap();
+        for v in ["#fff", "#FFFA", "#ff8800", "#ff880080"] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in ["fff", "#ff", "#fffff", "#ggg", "#ff8800800"] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        let query = RGB_COLOR.compile().unwrap();
+        for v in [
+            "rgb(255, 0, 0)",
+            "rgba(255,0,0,0.5)",
+            "rgb(255 0 0 / 50%)",
+            "rgb( 100% 50.5% 0% )",
+            "rgba(0, 0, 0, .25)",
+        ] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in [
+            "rgb(256, 0, 0)",
+            "rgb(255, 0)",
+            "rgb(101%, 0%, 0%)",
+            "rgba(0, 0, 0, 1.5)",
+            "rgb(-1, 0, 0)",
+        ] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        let captures = query.captures("rgb(12 34 56 / 0.5)").unwrap();
+        assert_eq!(&captures["red"], "12");
+        assert_eq!(&captures["green"], "34");
+        assert_eq!(&captures["blue"], "56");
+        assert_eq!(&captures["alpha"], "0.5");
+        let query = HSL_COLOR.compile().unwrap();
+        for v in ["hsl(120, 100%, 50%)", "hsla(360deg 0% 100% / 0.1)"] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in ["hsl(361, 100%, 50%)", "hsl(120, 100, 50)"] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        assert_eq!(
+            parse_color("#f80"),
+            Some(Rgba {
+                red: 255,
+                green: 136,
+                blue: 0,
+                alpha: 1.0
+            })
+        );
+        assert_eq!(parse_color("rgb(0%, 50%, 100%, 0)").unwrap().green, 128);
+        assert_eq!(parse_color("hsl(0, 0%, 0%)"), None);
+        assert_eq!(parse_color("rgb(50.\u{665}%, 0, 0)"), None);
+        assert!(!query.is_match("hsl(0, 50.\u{665}%, 0%)"));
+    }
+
+    #[test]
+    fn css_length() {
+        let query = CSS_LENGTH.compile().unwrap();
+        for v in [
+            "12px", "-1.5em", ".5rem", "100%", "0", "2VMIN", "3Q", "+4cqi",
+        ] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in ["12", "px", "1.5.em", "12 px", "1furlong"] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        let captures = query.captures("-0.75rem").unwrap();
+        assert_eq!(&captures["value"], "-0.75");
+        assert_eq!(&captures["unit"], "rem");
+    }
+
+    #[test]
+    fn tags() {
+        let query = HTML_TAG_NAME.compile().unwrap();
+        for v in ["div", "h1", "my-widget"] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in ["1h", "-x", "svg:rect"] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        assert!(XML_NAME.compile().unwrap().is_match("svg:rect"));
+        let query = OPENING_TAG.compile().unwrap();
+        let captures = query
+            .captures(r#"<a href="/x" class='y z' data-id=3 hidden>"#)
+            .unwrap();
+        assert_eq!(&captures["name"], "a");
+        assert_eq!(
+            &captures["attributes"],
+            r#" href="/x" class='y z' data-id=3 hidden"#
+        );
+        assert!(captures.name("self_closing").is_none());
+        let captures = query.captures("<br />").unwrap();
+        assert_eq!(&captures["self_closing"], "/");
+        for v in ["< a>", "<a href=\"x>", "</a>"] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        let query = CLOSING_TAG.compile().unwrap();
+        assert_eq!(&query.captures("</svg:g >").unwrap()["name"], "svg:g");
+        assert!(!query.is_match("</ a>"));
+    }
+
+    #[test]
+    fn element() {
+        let query = crate::presets::web::element("b").compile().unwrap();
+        let captures = query
+            .captures(r#"x <b class="k">bold</b> <b>y</b>"#)
+            .unwrap();
+        assert_eq!(&captures["attributes"], r#" class="k""#);
+        assert_eq!(&captures["content"], "bold");
+        assert!(!query.is_match("<b>bold</i>"));
+        assert!(!query.is_match("<br>x</b>"));
+    }
+
+    #[cfg(feature = "re-fancy")]
+    #[test]
+    fn element_back_reference() {
+        use crate::presets::web::ELEMENT;
+        let query = fancy_regex::Regex::new(&ELEMENT.to_string()).unwrap();
+        assert!(query.is_match("<em>a\nb</em>").unwrap());
+        assert!(query.is_match(r#"<p id="x"><b>y</b></p>"#).unwrap());
+        assert!(!query.is_match("<em>a</i>").unwrap());
+    }
+
+    #[test]
+    fn entities() {
+        let query = HTML_ENTITY.compile().unwrap();
+        for v in ["&amp;", "&#169;", "&#xA9;", "&frac12;"] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in ["&amp", "&#;", "&#x;", "&#12345678;", "& amp;"] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        let captures = query.captures("&#x1F600;").unwrap();
+        assert_eq!(&captures["hex"], "1F600");
+        assert!(captures.name("decimal").is_none());
+    }
+
+    #[test]
+    fn mime_types() {
+        let query = MIME_TYPE.compile().unwrap();
+        for v in [
+            "text/html",
+            "text/html; charset=utf-8",
+            "application/vnd.api+json",
+            r#"multipart/form-data; boundary="a b""#,
+            "image/svg+xml",
+        ] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in ["text", "text/", "/html", "text/html;", "text html"] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        let captures = query.captures("application/vnd.a+b+json;q=0.9").unwrap();
+        assert_eq!(&captures["type"], "application");
+        assert_eq!(&captures["subtype"], "vnd.a+b+json");
+        assert_eq!(&captures["suffix"], "json");
+        assert_eq!(&captures["parameters"], ";q=0.9");
+        assert!(query
+            .captures("text/plain")
+            .unwrap()
+            .name("suffix")
+            .is_none());
+    }
+
+    #[test]
+    fn http_headers() {
+        let query = HTTP_HEADER.compile().unwrap();
+        for v in ["Host: example.com", "X-Empty:", "accept:*/*"] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        for v in [": x", "Bad Name: x", "Host example.com", "A: b\r\nC: d"] {
+            assert!(!query.is_match(v), "Wrongly matched: {}", v);
+        }
+        let captures = query
+            .captures("Cache-Control: \tno-cache, max-age=0 ")
+            .unwrap();
+        assert_eq!(&captures["name"], "Cache-Control");
+        assert_eq!(&captures["value"], "no-cache, max-age=0");
+    }
+}
@@ -0,0 +1,268 @@
+//! Generate expressions matching the integers of a range, see [`crate::numeric_range`].
+//!
+//! ```
+//! use readable_regex::numeric_range;
+//! use readable_regex::{LeadingZeros, RangeOptions};
+//! assert_eq!(
+//!     numeric_range(0, 255, RangeOptions::new()).to_string(),
+//!     "(?:1[0-9]{2}|2[0-4][0-9]|25[0-5]|[1-9][0-9]|[0-9])"
+//! );
+//! let hours = numeric_range(0, 23, RangeOptions::new().leading_zeros(LeadingZeros::Required));
+//! assert_eq!(hours.to_string(), "(?:1[0-9]|2[0-3]|0[0-9])");
+//! ```
+
+use crate::{either, exactly, non_capture_group, ranged, solvers, ReadableRe};
+
+/// How the numbers shorter than the width are written
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LeadingZeros {
+    /// `7`
+    #[default]
+    Forbidden,
+    /// `7`, `07` or `007` for a width of `3`
+    Optional,
+    /// `007` for a width of `3`
+    Required,
+}
+
+/// Options of [`crate::numeric_range`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RangeOptions {
+    pub(crate) leading_zeros: LeadingZeros,
+    pub(crate) width: Option<usize>,
+}
+
+impl RangeOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// How the numbers are padded with zeros up to the width. Defaults to [`LeadingZeros::Forbidden`]
+    pub fn leading_zeros(mut self, leading_zeros: LeadingZeros) -> Self {
+        self.leading_zeros = leading_zeros;
+        self
+    }
+
+    /// Width of the padded numbers, without the sign. Defaults to the number of digits of the
+    /// largest magnitude of the range, and is never less than it.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+}
+
+fn digits(n: u128) -> usize {
+    n.to_string().len()
+}
+
+fn class(from: u8, to: u8) -> ReadableRe<'static> {
+    if from == to {
+        ReadableRe::String(from.to_string())
+    } else {
+        ReadableRe::Chars(solvers::Chars::new(&format!("{from}-{to}")))
+    }
+}
+
+fn any_digits(count: usize) -> ReadableRe<'static> {
+    match count {
+        1 => class(0, 9),
+        n => exactly(n, class(0, 9)),
+    }
+}
+
+/// Alternatives matching the numbers of `[min, max]`, all of them with `length` digits
+fn same_length(mut min: u128, max: u128, length: usize) -> Vec<ReadableRe<'static>> {
+    // aligned blocks `prefix` followed by `k` free digits, as (prefix, k)
+    let mut blocks: Vec<(String, usize)> = Vec::new();
+    while min <= max {
+        let mut k = 0;
+        while min.is_multiple_of(10u128.pow(k as u32 + 1))
+            && min + 10u128.pow(k as u32 + 1) - 1 <= max
+        {
+            k += 1;
+        }
+        let padded = format!("{min:0length$}");
+        blocks.push((padded[..length - k].to_string(), k));
+        min += 10u128.pow(k as u32);
+    }
+    // consecutive blocks that only differ in the last prefix digit are merged into a class
+    let mut alternatives = Vec::new();
+    let mut blocks = blocks.into_iter().peekable();
+    while let Some((prefix, k)) = blocks.next() {
+        if prefix.is_empty() {
+            alternatives.push(any_digits(k));
+            continue;
+        }
+        let (head, last) = prefix.split_at(prefix.len() - 1);
+        let first = last.parse::<u8>().unwrap();
+        let mut to = first;
+        while let Some((next, _)) = blocks.next_if(|(next, next_k)| {
+            *next_k == k && next.starts_with(head) && next.len() == prefix.len()
+        }) {
+            to = next[head.len()..].parse().unwrap();
+        }
+        let mut alternative = Vec::new();
+        if !head.is_empty() {
+            alternative.push(ReadableRe::String(head.to_string()));
+        }
+        alternative.push(class(first, to));
+        if k > 0 {
+            alternative.push(any_digits(k));
+        }
+        alternatives.push(crate::concat(alternative));
+    }
+    alternatives
+}
+
+/// Alternatives matching the magnitudes of `[min, max]`, longest numbers first
+fn magnitudes(min: u128, max: u128, width: usize, zeros: LeadingZeros) -> Vec<ReadableRe<'static>> {
+    let mut alternatives = Vec::new();
+    for length in (digits(min)..=digits(max)).rev() {
+        let from = if length == digits(min) {
+            min
+        } else {
+            10u128.pow(length as u32 - 1)
+        };
+        let to = if length == digits(max) {
+            max
+        } else {
+            10u128.pow(length as u32) - 1
+        };
+        let padding = width - length;
+        for alternative in same_length(from, to, length) {
+            alternatives.push(match (zeros, padding) {
+                (LeadingZeros::Forbidden, _) | (_, 0) => alternative,
+                (LeadingZeros::Required, n) => ReadableRe::String("0".repeat(n)) + alternative,
+                (LeadingZeros::Optional, n) => ranged(0..=n, ReadableRe::Raw("0")) + alternative,
+            });
+        }
+    }
+    alternatives
+}
+
+/// Whether a quantifier after the expression applies to all of it: a digit or a class
+fn is_atom(re: &ReadableRe) -> bool {
+    match re {
+        ReadableRe::Chars(_) => true,
+        ReadableRe::String(s) => s.len() == 1,
+        ReadableRe::Concat(concat) => concat.0.len() == 1 && is_atom(&concat.0[0]),
+        _ => false,
+    }
+}
+
+/// The alternatives grouped, so that the range can be quantified as a whole
+fn alternation(mut alternatives: Vec<ReadableRe<'static>>) -> ReadableRe<'static> {
+    match alternatives.len() {
+        1 if is_atom(&alternatives[0]) => alternatives.pop().unwrap(),
+        _ => non_capture_group(either(alternatives)),
+    }
+}
+
+pub(crate) fn numeric_range(min: i64, max: i64, options: RangeOptions) -> ReadableRe<'static> {
+    assert!(min <= max, "empty numeric range {min}..={max}");
+    let largest = min.unsigned_abs().max(max.unsigned_abs()) as u128;
+    let width = options.width.unwrap_or(0).max(digits(largest));
+    let zeros = options.leading_zeros;
+    let mut alternatives = Vec::new();
+    if min < 0 {
+        let smallest = if max < 0 { max.unsigned_abs() } else { 1 };
+        let negatives = magnitudes(smallest as u128, min.unsigned_abs() as u128, width, zeros);
+        alternatives.push(ReadableRe::Raw("-") + alternation(negatives));
+    }
+    if max >= 0 {
+        alternatives.extend(magnitudes(min.max(0) as u128, max as u128, width, zeros));
+    }
+    alternation(alternatives)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LeadingZeros, RangeOptions};
+    use crate::{exactly, numeric_range, optional, starts_and_ends_with};
+
+    fn check(min: i64, max: i64, options: RangeOptions, width: usize) {
+        let re = starts_and_ends_with(numeric_range(min, max, options));
+        let query = re.compile().unwrap();
+        for n in (min - 200).max(-99_999)..=(max + 200).min(99_999) {
+            let expected = (min..=max).contains(&n);
+            let plain = n.to_string();
+            let padded = format!(
+                "{}{:0width$}",
+                if n < 0 { "-" } else { "" },
+                n.unsigned_abs()
+            );
+            let (plain_ok, padded_ok) = match options.leading_zeros {
+                LeadingZeros::Forbidden => (expected, expected && plain == padded),
+                LeadingZeros::Optional => (expected, expected),
+                LeadingZeros::Required => (expected && plain == padded, expected),
+            };
+            assert_eq!(query.is_match(&plain), plain_ok, "{re} with {plain}");
+            assert_eq!(query.is_match(&padded), padded_ok, "{re} with {padded}");
+        }
+    }
+
+    #[test]
+    fn ranges() {
+        for (min, max) in [
+            (-5i64, 127i64),
+            (0, 0),
+            (0, 9),
+            (0, 255),
+            (1, 31),
+            (3, 7),
+            (17, 1234),
+            (999, 1001),
+            (0, 65535),
+            (-128, 127),
+            (-50, -7),
+        ] {
+            let width = min.unsigned_abs().max(max.unsigned_abs()).to_string().len();
+            for leading_zeros in [
+                LeadingZeros::Forbidden,
+                LeadingZeros::Optional,
+                LeadingZeros::Required,
+            ] {
+                let options = RangeOptions::new().leading_zeros(leading_zeros);
+                check(min, max, options, width);
+            }
+        }
+    }
+
+    #[test]
+    fn width() {
+        let options = RangeOptions::new()
+            .leading_zeros(LeadingZeros::Required)
+            .width(4);
+        check(5, 120, options, 4);
+        let query = starts_and_ends_with(numeric_range(5, 120, options))
+            .compile()
+            .unwrap();
+        assert!(query.is_match("0005"));
+        assert!(!query.is_match("005"));
+        let options = RangeOptions::new()
+            .leading_zeros(LeadingZeros::Optional)
+            .width(3);
+        let query = starts_and_ends_with(numeric_range(1, 12, options))
+            .compile()
+            .unwrap();
+        for v in ["1", "01", "001", "012", "12"] {
+            assert!(query.is_match(v), "Failed matching: {}", v);
+        }
+        assert!(!query.is_match("0001"));
+    }
+
+    #[test]
+    fn quantified() {
+        let re = optional(numeric_range(10, 12, RangeOptions::new()));
+        assert_eq!(re.to_string(), "(?:1[0-2])?");
+        let query = starts_and_ends_with(re).compile().unwrap();
+        assert!(query.is_match(""));
+        assert!(!query.is_match("1"));
+        let re = exactly(2, numeric_range(-9, -1, RangeOptions::new()));
+        assert_eq!(re.to_string(), "(?:-[1-9]){2}");
+        assert_eq!(
+            optional(numeric_range(0, 9, RangeOptions::new())).to_string(),
+            "[0-9]?"
+        );
+    }
+}
@@ -3,6 +3,7 @@ use std::ops::Add;
 
 use regex::Error;
 
+use crate::dialect::{self, Dialect, DialectError};
 use crate::{constants, solvers};
 
 #[cfg(feature = "re")]
@@ -195,8 +196,12 @@ pub enum ReadableRe<'a> {
     NonCaptureGroup(solvers::NonCaptureGroup<'a>),
     /// optional match, `"expr?"`, check [`solvers::Optional`]
     Optional(solvers::Optional<'a>),
+    /// optional, lazy match, `"expr??"`, check [`solvers::OptionalLazy`]
+    OptionalLazy(solvers::OptionalLazy<'a>),
     /// either match, `"expr1|expr2|..."`, check [`solvers::Either`]
     Either(solvers::Either<'a>),
+    /// either match of escaped words, factored by common prefixes, `"w(?:ord|ide)"`, check [`solvers::Trie`]
+    Trie(solvers::Trie),
     /// exact number (n) of occurrences match, `"expr{n}"`, check [`solvers::Exactly`]
     Exactly(solvers::Exactly<'a>),
     /// variance number (min, max) of occurrences match, `"expr{min, max}"`, check [`solvers::Ranged`]
@@ -307,7 +312,9 @@ impl Display for ReadableRe<'_> {
             ReadableRe::NamedGroup(named_group) => named_group,
             ReadableRe::NonCaptureGroup(non_capture_group) => non_capture_group,
             ReadableRe::Optional(optional) => optional,
+            ReadableRe::OptionalLazy(optional_lazy) => optional_lazy,
             ReadableRe::Either(either) => either,
+            ReadableRe::Trie(trie) => trie,
             ReadableRe::Exactly(exactly) => exactly,
             ReadableRe::Ranged(between) => between,
             ReadableRe::ZeroOrMore(zero_or_more) => zero_or_more,
@@ -330,6 +337,13 @@ impl<'a> ReadableRe<'a> {
     pub fn compile(&self) -> Result<Regex, Error> {
         Regex::new(&format!("{self}"))
     }
+
+    /// Render the expression in the syntax of the given [`Dialect`]
+    pub fn render(&self, dialect: Dialect) -> Result<String, DialectError> {
+        let mut out = String::new();
+        dialect::render(self, dialect, &mut out)?;
+        Ok(out)
+    }
 }
 
 impl<'a> Add<Self> for ReadableRe<'a> {
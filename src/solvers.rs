@@ -74,7 +74,7 @@ impl Display for BackReference {
 /// assert_eq!(scaped.to_string(), "!\\#\\$%\\&");
 /// ```
 #[derive(Clone)]
-pub struct Escape<'a>(Box<ReadableRe<'a>>);
+pub struct Escape<'a>(pub(crate) Box<ReadableRe<'a>>);
 
 impl Display for Escape<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -111,7 +111,7 @@ impl_builder_from_iter!(Escape);
 /// );
 /// ```
 #[derive(Clone)]
-pub struct Group<'a>(Box<ReadableRe<'a>>);
+pub struct Group<'a>(pub(crate) Box<ReadableRe<'a>>);
 
 impl<'a> Group<'a> {
     pub fn new(re: ReadableRe<'a>) -> Self {
@@ -153,7 +153,7 @@ impl_builder_from_iter!(Group);
 /// assert!(fancy_regex::Regex::new(&query.to_string()).unwrap().is_match("kittycat").unwrap());
 /// ```
 #[derive(Clone)]
-pub struct PositiveLookAhead<'a>(Box<ReadableRe<'a>>);
+pub struct PositiveLookAhead<'a>(pub(crate) Box<ReadableRe<'a>>);
 
 #[cfg(feature = "re-fancy")]
 impl<'a> PositiveLookAhead<'a> {
@@ -198,7 +198,7 @@ impl_builder_from_iter!(PositiveLookAhead);
 /// assert!(!fancy_regex::Regex::new(&query.to_string()).unwrap().is_match("kittycat").unwrap());
 /// ```
 #[derive(Clone)]
-pub struct NegativeLookAhead<'a>(Box<ReadableRe<'a>>);
+pub struct NegativeLookAhead<'a>(pub(crate) Box<ReadableRe<'a>>);
 
 #[cfg(feature = "re-fancy")]
 impl<'a> NegativeLookAhead<'a> {
@@ -241,7 +241,7 @@ impl_builder_from_iter!(NegativeLookAhead);
 /// assert!(!fancy_regex::Regex::new(&query.to_string()).unwrap().is_match("cat").unwrap());
 /// ```
 #[derive(Clone)]
-pub struct PositiveLookBehind<'a>(Box<ReadableRe<'a>>);
+pub struct PositiveLookBehind<'a>(pub(crate) Box<ReadableRe<'a>>);
 
 #[cfg(feature = "re-fancy")]
 impl<'a> PositiveLookBehind<'a> {
@@ -285,7 +285,7 @@ impl_builder_from_iter!(PositiveLookBehind);
 /// assert!(fancy_regex::Regex::new(&query.to_string()).unwrap().is_match("black cat").unwrap());
 /// ```
 #[derive(Clone)]
-pub struct NegativeLookBehind<'a>(Box<ReadableRe<'a>>);
+pub struct NegativeLookBehind<'a>(pub(crate) Box<ReadableRe<'a>>);
 
 #[cfg(feature = "re-fancy")]
 impl<'a> NegativeLookBehind<'a> {
@@ -324,8 +324,8 @@ impl_builder_from_iter!(NegativeLookBehind);
 /// ```
 #[derive(Clone)]
 pub struct NamedGroup<'a> {
-    name: &'a str,
-    regexes: Box<ReadableRe<'a>>,
+    pub(crate) name: &'a str,
+    pub(crate) regexes: Box<ReadableRe<'a>>,
 }
 
 impl<'a> NamedGroup<'a> {
@@ -364,7 +364,7 @@ impl<'a> Display for NamedGroup<'a> {
 /// );
 /// ```
 #[derive(Clone)]
-pub struct NonCaptureGroup<'a>(Box<ReadableRe<'a>>);
+pub struct NonCaptureGroup<'a>(pub(crate) Box<ReadableRe<'a>>);
 
 impl<'a> NonCaptureGroup<'a> {
     pub fn new(re: ReadableRe<'a>) -> Self {
@@ -393,7 +393,7 @@ impl_builder_from_iter!(NonCaptureGroup);
 /// );
 /// ```
 #[derive(Clone)]
-pub struct Optional<'a>(Box<ReadableRe<'a>>);
+pub struct Optional<'a>(pub(crate) Box<ReadableRe<'a>>);
 
 impl<'a> Optional<'a> {
     pub fn new(re: ReadableRe<'a>) -> Self {
@@ -409,6 +409,34 @@ impl<'a> Display for Optional<'a> {
 
 impl_builder_from_iter!(Optional);
 
+/// Regex syntax for an optional part of the pattern, lazy match, which tries to leave it out
+/// before matching it.
+///
+/// ## Example
+///
+/// ```
+/// use readable_regex::ReadableRe::Raw;
+/// use readable_regex::solvers::{OptionalLazy};
+/// let query = OptionalLazy::new(Raw("foo"));
+/// assert_eq!(query.to_string(), "foo??")
+/// ```
+#[derive(Clone)]
+pub struct OptionalLazy<'a>(pub(crate) Box<ReadableRe<'a>>);
+
+impl<'a> OptionalLazy<'a> {
+    pub fn new(re: ReadableRe<'a>) -> Self {
+        Self(Box::new(re))
+    }
+}
+
+impl<'a> Display for OptionalLazy<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}??", self.0)
+    }
+}
+
+impl_builder_from_iter!(OptionalLazy);
+
 /// Regex syntax for the alternation or "or" operator of the patterns in iterator input,
 /// and the alternation is placed in a group
 ///
@@ -420,12 +448,17 @@ impl_builder_from_iter!(Optional);
 /// assert_eq!(Either::new([Raw("a"), Raw("b"), Raw("c")]).to_string(), "a|b|c")
 /// ```
 #[derive(Clone)]
-pub struct Either<'a>(Concat<'a>);
+pub struct Either<'a>(pub(crate) Concat<'a>);
 
 impl<'a> Either<'a> {
     pub fn new(iter: impl IntoIterator<Item = ReadableRe<'a>>) -> Self {
         Self::from_iter(iter)
     }
+
+    /// Iterate over the alternatives, skipping the `"|"` separators
+    pub(crate) fn alternatives(&self) -> impl Iterator<Item = &ReadableRe<'a>> {
+        self.0 .0.iter().step_by(2)
+    }
 }
 
 impl<'a> Display for Either<'a> {
@@ -458,8 +491,8 @@ impl<'a> FromIterator<ReadableRe<'a>> for Either<'a> {
 /// ```
 #[derive(Clone)]
 pub struct Exactly<'a> {
-    quantity: usize,
-    re: Box<ReadableRe<'a>>,
+    pub(crate) quantity: usize,
+    pub(crate) re: Box<ReadableRe<'a>>,
 }
 
 impl<'a> Exactly<'a> {
@@ -496,8 +529,8 @@ impl<'a> Display for Exactly<'a> {
 /// assert_eq!(query.to_string(), "abc{,}");
 /// ```
 pub struct Ranged<'a> {
-    range: (Bound<usize>, Bound<usize>),
-    re: Box<ReadableRe<'a>>,
+    pub(crate) range: (Bound<usize>, Bound<usize>),
+    pub(crate) re: Box<ReadableRe<'a>>,
 }
 
 impl<'a> Clone for Ranged<'a> {
@@ -519,6 +552,19 @@ impl<'a> Ranged<'a> {
             re: Box::new(re),
         }
     }
+
+    /// Minimum and maximum (if bounded) number of occurrences
+    pub(crate) fn min_max(&self) -> (usize, Option<usize>) {
+        let min = match self.range.0 {
+            Bound::Included(min) | Bound::Excluded(min) => min,
+            Bound::Unbounded => 0,
+        };
+        let max = match self.range.1 {
+            Bound::Included(max) | Bound::Excluded(max) => Some(max),
+            Bound::Unbounded => None,
+        };
+        (min, max)
+    }
 }
 
 impl<'a> Display for Ranged<'a> {
@@ -552,7 +598,7 @@ impl<'a> Display for Ranged<'a> {
 /// assert_eq!(query.to_string(), "abc*")
 /// ```
 #[derive(Clone)]
-pub struct ZeroOrMore<'a>(Box<ReadableRe<'a>>);
+pub struct ZeroOrMore<'a>(pub(crate) Box<ReadableRe<'a>>);
 
 impl<'a> ZeroOrMore<'a> {
     pub fn new(re: ReadableRe<'a>) -> Self {
@@ -580,7 +626,7 @@ impl_builder_from_iter!(ZeroOrMore);
 /// assert_eq!(query.to_string(), "abc*?")
 /// ```
 #[derive(Clone)]
-pub struct ZeroOrMoreLazy<'a>(Box<ReadableRe<'a>>);
+pub struct ZeroOrMoreLazy<'a>(pub(crate) Box<ReadableRe<'a>>);
 
 impl<'a> ZeroOrMoreLazy<'a> {
     pub fn new(re: ReadableRe<'a>) -> Self {
@@ -608,7 +654,7 @@ impl_builder_from_iter!(ZeroOrMoreLazy);
 /// assert_eq!(query.to_string(), "abc+")
 /// ```
 #[derive(Clone)]
-pub struct OneOrMore<'a>(Box<ReadableRe<'a>>);
+pub struct OneOrMore<'a>(pub(crate) Box<ReadableRe<'a>>);
 
 impl<'a> OneOrMore<'a> {
     pub fn new(re: ReadableRe<'a>) -> Self {
@@ -636,7 +682,7 @@ impl_builder_from_iter!(OneOrMore);
 /// assert_eq!(query.to_string(), "abc+?")
 /// ```
 #[derive(Clone)]
-pub struct OneOrMoreLazy<'a>(Box<ReadableRe<'a>>);
+pub struct OneOrMoreLazy<'a>(pub(crate) Box<ReadableRe<'a>>);
 
 impl<'a> OneOrMoreLazy<'a> {
     pub fn new(re: ReadableRe<'a>) -> Self {
@@ -663,7 +709,7 @@ impl_builder_from_iter!(OneOrMoreLazy);
 /// assert_eq!(query.to_string(), "^abc");
 /// ```
 #[derive(Clone)]
-pub struct StartsWith<'a>(Box<ReadableRe<'a>>);
+pub struct StartsWith<'a>(pub(crate) Box<ReadableRe<'a>>);
 
 impl<'a> StartsWith<'a> {
     pub fn new(re: ReadableRe<'a>) -> Self {
@@ -690,7 +736,7 @@ impl_builder_from_iter!(StartsWith);
 /// assert_eq!(query.to_string(), "abc$");
 /// ```
 #[derive(Clone)]
-pub struct EndsWith<'a>(Box<ReadableRe<'a>>);
+pub struct EndsWith<'a>(pub(crate) Box<ReadableRe<'a>>);
 
 impl<'a> EndsWith<'a> {
     pub fn new(re: ReadableRe<'a>) -> Self {
@@ -718,7 +764,7 @@ impl_builder_from_iter!(EndsWith);
 /// assert_eq!(query.to_string(), "^abc$");
 /// ```
 #[derive(Clone)]
-pub struct StartsAndEndsWith<'a>(Box<ReadableRe<'a>>);
+pub struct StartsAndEndsWith<'a>(pub(crate) Box<ReadableRe<'a>>);
 
 impl<'a> StartsAndEndsWith<'a> {
     pub fn new(re: ReadableRe<'a>) -> Self {
@@ -746,7 +792,7 @@ impl_builder_from_iter!(StartsAndEndsWith);
 /// assert_eq!(query.to_string(), "[abc]");
 /// ```
 #[derive(Clone)]
-pub struct Chars(String);
+pub struct Chars(pub(crate) String);
 
 impl Chars {
     pub fn new(s: &str) -> Self {
@@ -770,7 +816,7 @@ impl Display for Chars {
 /// assert_eq!(query.to_string(), "[^abc]");
 /// ```
 #[derive(Clone)]
-pub struct NotChars(String);
+pub struct NotChars(pub(crate) String);
 
 impl NotChars {
     pub fn new(s: &str) -> Self {
@@ -796,7 +842,7 @@ impl Display for NotChars {
 /// assert_eq!(query.to_string(), "(?>foo)")
 /// ```
 #[derive(Clone)]
-pub struct AtomicGroup<'a>(Box<ReadableRe<'a>>);
+pub struct AtomicGroup<'a>(pub(crate) Box<ReadableRe<'a>>);
 
 #[cfg(feature = "re-fancy")]
 impl<'a> AtomicGroup<'a> {
@@ -814,3 +860,186 @@ impl<'a> Display for AtomicGroup<'a> {
 
 #[cfg(feature = "re-fancy")]
 impl_builder_from_iter!(AtomicGroup);
+
+/// Regex syntax for the alternation of a list of literal words, factored by their common prefixes.
+/// Each word is escaped, so it is always matched literally. Words that are a prefix of other words
+/// are matched as an optional suffix, greedily by default (see [`Trie::longest_first`]).
+///
+/// ## Example
+///
+/// ```
+/// use readable_regex::solvers::Trie;
+/// let query = Trie::new(["foo", "foobar", "fizz", "f.o"]);
+/// assert_eq!(query.to_string(), r"f(?:\.o|izz|oo(?:bar)?)");
+/// let query = Trie::new(["cat", "car", "Cow"])
+///     .word_boundaries(true)
+///     .case_insensitive(true);
+/// assert_eq!(query.to_string(), r"\b(?i:c(?:a[rt]|ow))\b");
+/// ```
+#[derive(Clone)]
+pub struct Trie {
+    pub(crate) words: Vec<String>,
+    pub(crate) word_boundaries: bool,
+    pub(crate) case_insensitive: bool,
+    pub(crate) longest_first: bool,
+}
+
+impl Trie {
+    pub fn new<S: AsRef<str>>(words: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            words: words.into_iter().map(|w| w.as_ref().to_string()).collect(),
+            word_boundaries: false,
+            case_insensitive: false,
+            longest_first: true,
+        }
+    }
+
+    /// Surround the alternation with word boundaries, `"\b...\b"`
+    pub fn word_boundaries(mut self, word_boundaries: bool) -> Self {
+        self.word_boundaries = word_boundaries;
+        self
+    }
+
+    /// Match words ignoring case, `"(?i:...)"`
+    pub fn case_insensitive(mut self, case_insensitive: bool) -> Self {
+        self.case_insensitive = case_insensitive;
+        self
+    }
+
+    /// When a word is a prefix of another one, prefer the longest (greedy, `"(?:...)?"`)
+    /// or the shortest (lazy, `"(?:...)??"`) of them. Defaults to `true`.
+    pub fn longest_first(mut self, longest_first: bool) -> Self {
+        self.longest_first = longest_first;
+        self
+    }
+
+    fn root(&self) -> TrieNode {
+        let mut root = TrieNode::default();
+        for word in self.words.iter().filter(|w| !w.is_empty()) {
+            if self.case_insensitive {
+                root.insert(&word.to_lowercase());
+            } else {
+                root.insert(word);
+            }
+        }
+        root
+    }
+}
+
+impl Trie {
+    /// Render the trie with the given char escaping function, which receives whether the char is
+    /// placed inside a character class. When `inline_flags` is `false`, case insensitivity is
+    /// expanded into classes (`"[aA]"`) instead of using `"(?i:...)"`.
+    pub(crate) fn render_with(
+        &self,
+        escape: &dyn Fn(char, bool) -> String,
+        boundary: &str,
+        inline_flags: bool,
+    ) -> String {
+        let syntax = TrieSyntax {
+            escape,
+            fold_case: self.case_insensitive && !inline_flags,
+            longest_first: self.longest_first,
+        };
+        let (mut re, mut atom) = self.root().render(&syntax);
+        if re.is_empty() {
+            return re;
+        }
+        if self.case_insensitive && inline_flags {
+            re = format!("(?i:{re})");
+            atom = true;
+        }
+        if self.word_boundaries {
+            if !atom {
+                re = format!("(?:{re})");
+            }
+            re = format!("{boundary}{re}{boundary}");
+        }
+        re
+    }
+}
+
+impl Display for Trie {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let escape = |c: char, _| regex::escape(&c.to_string());
+        write!(f, "{}", self.render_with(&escape, r"\b", true))
+    }
+}
+
+struct TrieSyntax<'s> {
+    escape: &'s dyn Fn(char, bool) -> String,
+    fold_case: bool,
+    longest_first: bool,
+}
+
+impl TrieSyntax<'_> {
+    /// All the chars that should match `c`
+    fn variants(&self, c: char) -> Vec<char> {
+        let mut upper = c.to_uppercase();
+        match (self.fold_case, upper.next(), upper.next()) {
+            (true, Some(u), None) if u != c => vec![c, u],
+            _ => vec![c],
+        }
+    }
+
+    fn class(&self, chars: &[char]) -> String {
+        let variants: Vec<char> = chars.iter().flat_map(|&c| self.variants(c)).collect();
+        match variants.as_slice() {
+            [c] => (self.escape)(*c, false),
+            _ => {
+                let members: String = variants.iter().map(|&c| (self.escape)(c, true)).collect();
+                format!("[{members}]")
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct TrieNode {
+    children: std::collections::BTreeMap<char, TrieNode>,
+    terminal: bool,
+}
+
+impl TrieNode {
+    fn insert(&mut self, word: &str) {
+        let node = word
+            .chars()
+            .fold(self, |node, c| node.children.entry(c).or_default());
+        node.terminal = true;
+    }
+
+    /// Render the alternation of every suffix below this node, returning whether the result
+    /// is a single atom that can be quantified without grouping it.
+    fn render(&self, syntax: &TrieSyntax) -> (String, bool) {
+        let mut alternatives = Vec::new();
+        let mut leaves = Vec::new();
+        for (&c, child) in &self.children {
+            if child.children.is_empty() {
+                leaves.push(c);
+                continue;
+            }
+            let (suffix, atom) = child.render(syntax);
+            let suffix = match (child.terminal, atom) {
+                (false, _) => suffix,
+                (true, true) => format!("{suffix}?"),
+                (true, false) => format!("(?:{suffix})?"),
+            };
+            let lazy = if child.terminal && !syntax.longest_first {
+                "?"
+            } else {
+                ""
+            };
+            alternatives.push(format!("{}{suffix}{lazy}", syntax.class(&[c])));
+        }
+        let leaves_atom = (!leaves.is_empty()).then(|| syntax.class(&leaves));
+        match (alternatives.len(), leaves_atom) {
+            (0, Some(leaves)) => (leaves, true),
+            (0, None) => (String::new(), false),
+            (1, None) => (alternatives.pop().unwrap(), false),
+            (_, leaves) => {
+                alternatives.extend(leaves);
+                (format!("(?:{})", alternatives.join("|")), true)
+            }
+        }
+    }
+}
@@ -0,0 +1,89 @@
+#![cfg(feature = "cli")]
+
+use std::process::Command;
+
+fn run(args: &[&str]) -> (i32, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_readable-regex"))
+        .args(args)
+        .output()
+        .unwrap();
+    (
+        output.status.code().unwrap(),
+        String::from_utf8(output.stdout).unwrap(),
+    )
+}
+
+#[test]
+fn explain() {
+    let (code, out) = run(&["explain", r"(\d{3})-x"]);
+    assert_eq!(code, 0);
+    assert_eq!(
+        out,
+        "(\\d{3})-x\n\nsequence\n  capture group #1\n    exactly 3 times\n      digit `\\d`\n  literal \"-x\"\n"
+    );
+}
+
+#[test]
+fn convert() {
+    let (code, out) = run(&["convert", r"^(?P<area>\d{3})[-.]\d{4}$"]);
+    assert_eq!(code, 0);
+    assert_eq!(
+        out,
+        "starts_and_ends_with(named_group(\"area\", exactly(3, Digit)) + chars(\"-.\") + exactly(4, Digit))\n"
+    );
+}
+
+#[test]
+fn test_matches() {
+    let (code, out) = run(&["test", r"(?P<area>\d{3})-(\d+)?", "555-1234", "nope"]);
+    assert_eq!(code, 0);
+    assert_eq!(
+        out,
+        "[555-1234]\n  match 0: \"555-1234\" at 0..8\n    #1 area: \"555\" at 0..3\n    #2: \"1234\" at 4..8\nnope\n"
+    );
+    let (code, _) = run(&["test", r"\d", "nope"]);
+    assert_eq!(code, 1);
+}
+
+#[test]
+fn grep_preset() {
+    let file = std::env::temp_dir().join("readable-regex-grep.txt");
+    std::fs::write(&file, "-12.5\nfoo\n3,14\n").unwrap();
+    let (code, out) = run(&["grep", "-p", "numeric::DECIMAL", file.to_str().unwrap()]);
+    assert_eq!(code, 0);
+    assert_eq!(out, "1:[-12.5]\n3:[3,14]\n");
+    let (code, _) = run(&["grep", "-p", "numeric::NOPE", file.to_str().unwrap()]);
+    assert_eq!(code, 2);
+}
+
+#[test]
+fn render() {
+    let (code, out) = run(&["render", "--dialect", "js", r"(?P<year>\d{4})"]);
+    assert_eq!(code, 0);
+    assert_eq!(out, "(?<year>\\d{4})\n");
+}
+
+#[test]
+fn repl() {
+    use std::io::Write;
+    use std::process::Stdio;
+    let mut child = Command::new(env!("CARGO_BIN_EXE_readable-regex"))
+        .arg("repl")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(
+            b":sample call 555-1234\ngroup(exactly(3, digit)) + \"-\"\nexactly(x, digit)\n:code\n",
+        )
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "regex: (\\d{3})\\-\nsequence\n  capture group #1\n    exactly 3 times\n      digit `\\d`\n  literal \"-\"\ngroups: #1\nsample: 1 of 1 lines match\n  1: call [555-]1234\n    #1: \"555\" at 5..8\nerror: unknown name `x` at column 9\ngroup(exactly(3, Digit)) + escape_str(\"-\")\n"
+    );
+}
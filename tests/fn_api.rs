@@ -162,21 +162,25 @@ fn test_group() {
     );
 }
 
+#[cfg(feature = "re-fancy")]
 #[test]
 fn test_positive_look_ahead() {
     assert_eq!(positive_look_ahead("cat".into()).to_string(), "(?=cat)");
 }
 
+#[cfg(feature = "re-fancy")]
 #[test]
 fn test_negative_look_ahead() {
     assert_eq!(negative_look_ahead("cat".into()).to_string(), "(?!cat)");
 }
 
+#[cfg(feature = "re-fancy")]
 #[test]
 fn test_positive_look_behind() {
     assert_eq!(positive_look_behind("cat".into()).to_string(), "(?<=cat)");
 }
 
+#[cfg(feature = "re-fancy")]
 #[test]
 fn test_negative_look_behind() {
     assert_eq!(negative_look_behind("cat".into()).to_string(), "(?<!cat)");
@@ -197,6 +201,11 @@ fn test_optional() {
     assert_eq!(optional("cat".into()).to_string(), "cat?");
 }
 
+#[test]
+fn test_optional_lazy() {
+    assert_eq!(optional_lazy("cat".into()).to_string(), "cat??");
+}
+
 #[test]
 fn test_either() {
     assert_eq!(
@@ -215,6 +224,25 @@ fn test_ranged() {
     assert_eq!(ranged(1..10, "cat".into()).to_string(), "cat{1,10}");
 }
 
+#[test]
+fn test_numeric_range() {
+    assert_eq!(
+        numeric_range(7, 42, RangeOptions::new()).to_string(),
+        "(?:[1-3][0-9]|4[0-2]|[7-9])"
+    );
+    assert_eq!(
+        numeric_range(-9, 5, RangeOptions::new()).to_string(),
+        "(?:-[1-9]|[0-5])"
+    );
+    let options = RangeOptions::new()
+        .leading_zeros(LeadingZeros::Optional)
+        .width(3);
+    assert_eq!(
+        numeric_range(5, 12, options).to_string(),
+        "(?:0{0,1}1[0-2]|0{0,2}[5-9])"
+    );
+}
+
 #[test]
 fn test_at_least() {
     assert_eq!(at_least(1, "cat".into()).to_string(), "cat{1,}");
@@ -414,3 +442,85 @@ fn test_not_ascii_numeric() {
         }
     }
 }
+
+#[test]
+fn test_either_words() {
+    assert_eq!(
+        either_words(["cat", "dog", "moose"]).to_string(),
+        "(?:cat|dog|moose)"
+    );
+    assert_eq!(either_words(["a", "b", "c"]).to_string(), "[abc]");
+    assert_eq!(
+        either_words(["foo", "foobar", "foobaz"]).to_string(),
+        "foo(?:ba[rz])?"
+    );
+    assert_eq!(either_words(["1+1", "1.5"]).to_string(), r"1(?:\+1|\.5)");
+    assert_eq!(either_words(Vec::<&str>::new()).to_string(), "");
+
+    let re = ReadableRe::Trie(
+        solvers::Trie::new(["Jan", "June", "JUL"])
+            .case_insensitive(true)
+            .word_boundaries(true),
+    )
+    .compile()
+    .unwrap();
+    assert!(re.is_match("jul"));
+    assert!(re.is_match("the JUNE month"));
+    assert!(!re.is_match("junes"));
+
+    let shortest = ReadableRe::Trie(solvers::Trie::new(["foo", "foobar"]).longest_first(false));
+    let shortest = shortest.compile().unwrap();
+    let longest = either_words(["foo", "foobar"]).compile().unwrap();
+    assert_eq!(shortest.find("foobar").unwrap().as_str(), "foo");
+    assert_eq!(longest.find("foobar").unwrap().as_str(), "foobar");
+}
+
+#[test]
+fn test_render_dialects() {
+    let re = starts_and_ends_with(
+        named_group("user", one_or_more(chars(r"\w.-")))
+            + Raw("@")
+            + at_most(3, Digit)
+            + ReadableRe::Trie(solvers::Trie::new(["com", "co"]).case_insensitive(true)),
+    );
+    assert_eq!(
+        re.render(Dialect::Rust).unwrap(),
+        r"^(?P<user>[\w.-]+)@\d{0,3}(?i:com?)$"
+    );
+    assert!(regex::Regex::new(&re.render(Dialect::Rust).unwrap()).is_ok());
+    assert_eq!(
+        re.render(Dialect::JavaScript).unwrap(),
+        r"^(?<user>[\w.-]+)@\d{0,3}[cC][oO][mM]?$"
+    );
+    assert!(re.render(Dialect::PostgreSql).is_err());
+
+    let re = boundary() + escape_str("a-b/c") + MinusSign + Quote + boundary();
+    assert_eq!(re.render(Dialect::Pcre).unwrap(), r"\ba\-b/c\-\'\b");
+    assert_eq!(re.render(Dialect::JavaScript).unwrap(), r"\ba-b\/c-'\b");
+    assert_eq!(re.render(Dialect::PostgreSql).unwrap(), r"\ya\-b/c\-\'\y");
+}
+
+#[cfg(feature = "re-fancy")]
+#[test]
+fn test_render_unsupported() {
+    let re = atomic_group(Raw("a")) + positive_look_behind(Raw("b")) + Back1;
+    assert_eq!(re.render(Dialect::Pcre).unwrap(), re.to_string());
+    assert_eq!(re.render(Dialect::Python).unwrap(), re.to_string());
+    for dialect in [
+        Dialect::JavaScript,
+        Dialect::PostgreSql,
+        Dialect::Rust,
+        Dialect::Go,
+    ] {
+        assert_eq!(
+            re.render(dialect),
+            Err(dialect::DialectError::Unsupported {
+                construct: "atomic groups",
+                dialect
+            })
+        );
+    }
+    let re = positive_look_ahead(Raw("b")) + Back1;
+    assert!(re.render(Dialect::JavaScript).is_ok());
+    assert!(re.render(Dialect::Go).is_err());
+}